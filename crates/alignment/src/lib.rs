@@ -19,7 +19,9 @@ pub enum Attitude {
 }
 
 impl Attitude {
-    fn weight(self, influences: &[Self]) -> f64 {
+    /// Weight of choosing this attitude, which grows with each influence towards it
+    #[must_use]
+    pub fn weight(self, influences: &[Self]) -> f64 {
         exp_weight(influences.iter().filter(|&i| i == &self).count())
     }
 }
@@ -32,7 +34,9 @@ pub enum Morality {
 }
 
 impl Morality {
-    fn weight(self, influences: &[Self]) -> f64 {
+    /// Weight of choosing this morality, which grows with each influence towards it
+    #[must_use]
+    pub fn weight(self, influences: &[Self]) -> f64 {
         exp_weight(influences.iter().filter(|&i| i == &self).count())
     }
 }
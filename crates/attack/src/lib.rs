@@ -3,85 +3,236 @@
 
 use std::fmt;
 
+use citation::{Book, Citation};
 use dice_roller::RollCmd;
+pub use gear::damage::DamageType;
+use gear::weapons::{Weapon, WeaponClassification, WeaponProperty};
 use serde::{Deserialize, Serialize};
-use strum::Display;
-
-/// List of types of damage available
-#[allow(dead_code)]
-#[derive(Deserialize, Display, Serialize)]
-pub enum DamageType {
-    Acid,
-    Bludgeoning,
-    Cold,
-    Fire,
-    Force,
-    Lightning,
-    Necrotic,
-    Piercing,
-    Poison,
-    Psychic,
-    Radiant,
-    Slashing,
-    Thunder,
-}
+use stats::{
+    ability::{AbilityScoreType, AbilityScores},
+    proficiencies::{Proficiency, WeaponProficiency},
+};
 
 /// Damage information for a given object or attack
+#[derive(Deserialize, Serialize)]
 pub struct Damage {
     /// Type of damage the attack does
     pub damage_type: DamageType,
     /// Modifier to add to the roll
     pub modifier: i16,
-    /// Damage roll
-    pub roll: RollCmd,
+    /// Damage roll, if there is one. Otherwise the modifier is the total damage.
+    pub roll: Option<RollCmd>,
 }
 
 impl fmt::Display for Damage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{:+} {}", self.roll, self.modifier, self.damage_type)
+        match &self.roll {
+            Some(roll) if self.modifier == 0 => write!(f, "{roll} {}", self.damage_type),
+            Some(roll) => write!(f, "{roll}{:+} {}", self.modifier, self.damage_type),
+            None => write!(f, "{} {}", self.modifier.max(1), self.damage_type),
+        }
     }
 }
 
-// pub(crate) enum Hit {
-//     Roll(u8),
-//     DC(AbilityScoreType, i16),
-// }
-
-// impl fmt::Display for Hit {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         match self {
-//             Self::Roll(m) => write!(f, "{:+}", m),
-//             Self::DC(a, s) => write!(f, "{} {}", s, a),
-//         }
-//     }
-// }
-
-// pub(crate) struct Attack<'a> {
-//     pub(crate) citation: Citation,
-//     pub(crate) hit: Hit,
-//     pub(crate) damage: Damage,
-//     pub(crate) name: &'a str,
-//     pub(crate) range: &'a str,
-// }
-
-// impl fmt::Display for Attack<'_> {
-//     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//         writeln!(
-//             f,
-//             "{:20} {:20} {:20} {:20} {}",
-//             self.name, self.range, self.hit, self.damage, self.citation
-//         )
-//     }
-// }
-
-/// Trait to encapuslate resistances
-pub trait Resistances {
-    /// Return list of immunities for this object
-    fn immunities(&self) -> Vec<DamageType> {
-        vec![]
+/// How an attack determines whether it hits
+#[derive(Deserialize, Serialize)]
+pub enum Hit {
+    /// Bonus added to an attack roll
+    Roll(i16),
+    /// Saving throw the target makes against a given DC
+    DC(AbilityScoreType, i16),
+}
+
+impl fmt::Display for Hit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Roll(m) => write!(f, "{m:+}"),
+            Self::DC(a, s) => write!(f, "DC {s} {a}"),
+        }
+    }
+}
+
+/// An attack a character can make
+#[derive(Deserialize, Serialize)]
+pub struct Attack {
+    /// Where the rules for the attack can be found
+    pub citation: Citation,
+    /// Damage dealt on a hit, if any
+    pub damage: Option<Damage>,
+    /// Attack bonus or saving throw for the attack
+    pub hit: Hit,
+    /// Name of the attack
+    pub name: String,
+    /// Any special properties of the attack
+    pub properties: Vec<String>,
+    /// Range or area of the attack
+    pub range: String,
+}
+
+impl Attack {
+    /// Attack made with a weapon.
+    ///
+    /// Melee weapons use Strength and ranged weapons use Dexterity, while finesse weapons use whichever is
+    /// higher. The proficiency bonus is only added if the character is proficient with the weapon.
+    #[must_use]
+    pub fn weapon(
+        weapon: Weapon,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        proficiency_bonus: i16,
+    ) -> Self {
+        let properties = weapon.properties();
+        let strength = ability_scores.modifier(AbilityScoreType::Strength);
+        let dexterity = ability_scores.modifier(AbilityScoreType::Dexterity);
+        let modifier = if properties.contains(&WeaponProperty::Finesse) {
+            strength.max(dexterity)
+        } else {
+            match weapon.classification() {
+                WeaponClassification::Melee => strength,
+                WeaponClassification::Ranged => dexterity,
+            }
+        };
+        let proficient = proficiencies.iter().any(|p| {
+            matches!(
+                p,
+                Proficiency::Weapon(WeaponProficiency::Category(c)) if *c == weapon.category()
+            ) || *p == Proficiency::Weapon(WeaponProficiency::Specific(weapon))
+        });
+
+        Self {
+            citation: Citation(Book::Phb, 149),
+            damage: weapon.damage_type().map(|damage_type| Damage {
+                damage_type,
+                // Weapons without damage dice deal a flat 1 damage
+                modifier: modifier + i16::from(weapon.damage_dice().is_none()),
+                roll: weapon.damage_dice(),
+            }),
+            hit: Hit::Roll(modifier + if proficient { proficiency_bonus } else { 0 }),
+            name: weapon.to_string(),
+            properties: properties
+                .iter()
+                .filter_map(|p| match p {
+                    WeaponProperty::Ammunition(..) | WeaponProperty::Thrown(..) => None,
+                    WeaponProperty::Versatile(roll) => Some(format!("{p} ({roll})")),
+                    _ => Some(p.to_string()),
+                })
+                .collect(),
+            range: weapon_range(weapon.classification(), &properties),
+        }
+    }
+}
+
+/// Reach of a melee weapon and/or the normal and long range of a ranged or thrown weapon
+fn weapon_range(classification: WeaponClassification, properties: &[WeaponProperty]) -> String {
+    let reach = if properties.contains(&WeaponProperty::Reach) {
+        "10 ft."
+    } else {
+        "5 ft."
+    };
+    let range = properties.iter().find_map(|p| match p {
+        WeaponProperty::Ammunition(_, normal, long) | WeaponProperty::Thrown(normal, long) => {
+            Some(format!("{normal}/{long} ft."))
+        }
+        _ => None,
+    });
+    match (classification, range) {
+        (WeaponClassification::Melee, Some(range)) => format!("{reach} or {range}"),
+        (WeaponClassification::Melee, None) => reach.to_string(),
+        (WeaponClassification::Ranged, range) => range.unwrap_or_else(|| reach.to_string()),
+    }
+}
+
+impl fmt::Display for Attack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:20} {:20} {:16} {:20} {} ({})",
+            self.name,
+            self.range,
+            self.hit.to_string(),
+            self.damage
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            self.properties.join(", "),
+            self.citation
+        )
     }
-    /// Return list of resistances for this object
-    fn resistances(&self) -> Vec<DamageType> {
+}
+
+/// Trait for anything that provides attacks other than those from equipped weapons
+pub trait Attacks {
+    /// Return list of attacks available at the given level
+    fn attacks(&self, _: u8, _: &AbilityScores, _: i16) -> Vec<Attack> {
         vec![]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gear::weapons::WeaponCategory;
+
+    fn ability_scores() -> AbilityScores {
+        AbilityScores(
+            [
+                (AbilityScoreType::Strength, 16),
+                (AbilityScoreType::Dexterity, 14),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+
+    #[test]
+    fn test_melee_weapon() {
+        let attack = Attack::weapon(
+            Weapon::Longsword,
+            &ability_scores(),
+            &[Proficiency::Weapon(WeaponProficiency::Category(
+                WeaponCategory::Martial,
+            ))],
+            2,
+        );
+        assert_eq!(attack.hit.to_string(), "+5");
+        assert_eq!(attack.damage.unwrap().to_string(), "1d8+3 Slashing");
+        assert_eq!(attack.range, "5 ft.");
+        assert_eq!(attack.properties, vec!["Versatile (1d10)"]);
+    }
+
+    #[test]
+    fn test_unproficient_ranged_weapon() {
+        let attack = Attack::weapon(Weapon::Longbow, &ability_scores(), &[], 2);
+        assert_eq!(attack.hit.to_string(), "+2");
+        assert_eq!(attack.damage.unwrap().to_string(), "1d8+2 Piercing");
+        assert_eq!(attack.range, "150/600 ft.");
+    }
+
+    #[test]
+    fn test_finesse_thrown_weapon() {
+        let scores = AbilityScores(
+            [
+                (AbilityScoreType::Strength, 8),
+                (AbilityScoreType::Dexterity, 18),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let attack = Attack::weapon(
+            Weapon::Dagger,
+            &scores,
+            &[Proficiency::Weapon(WeaponProficiency::Specific(
+                Weapon::Dagger,
+            ))],
+            3,
+        );
+        assert_eq!(attack.hit.to_string(), "+7");
+        assert_eq!(attack.range, "5 ft. or 20/60 ft.");
+    }
+
+    #[test]
+    fn test_blowgun_damage() {
+        let attack = Attack::weapon(Weapon::Blowgun, &ability_scores(), &[], 2);
+        assert_eq!(attack.damage.unwrap().to_string(), "3 Piercing");
+    }
+}
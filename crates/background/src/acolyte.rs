@@ -44,12 +44,12 @@ impl Citations for Acolyte {
 }
 
 impl Features for Acolyte {
-    fn features(&self) -> Vec<Feature> {
-        // As an acolyte, you command the respect of those who share your faith, and you can perform the religious ceremonies of your deity. You and your adventuring companions can expect to receive free healing and care at a temple, shrine, or other established presence of your faith, though you must provide any material components needed for spells. Those who share your religion will support you (but only you) at a modest lifestyle.
-        // You might also have ties to a specific temple dedicated to your chosen deity or pantheon, and you have a residence there. This could be the temple where you used to serve, if you remain on good terms with it, or a temple where you have found a new home. While near your temple, you can call upon the priests for assistance, provided the assistance you ask for is not hazardous and you remain in good standing with your temple.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Shelter of the Faithful",
+            description: "As an acolyte, you command the respect of those who share your faith, and you can perform the religious ceremonies of your deity. You and your adventuring companions can expect to receive free healing and care at a temple, shrine, or other established presence of your faith, though you must provide any material components needed for spells. Those who share your religion will support you (but only you) at a modest lifestyle. You might also have ties to a specific temple dedicated to your chosen deity or pantheon, and you have a residence there. This could be the temple where you used to serve, if you remain on good terms with it, or a temple where you have found a new home. While near your temple, you can call upon the priests for assistance, provided the assistance you ask for is not hazardous and you remain in good standing with your temple.",
             citation: Citation(Book::Phb, 127),
+            scaling: None,
         }]
     }
 }
@@ -188,7 +188,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         let background = Acolyte;
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
@@ -59,6 +59,10 @@ impl Backstory for Charlatan {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Favorite Scheme/Scam: {}", self.scam)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.scam = Self::gen_scam(rng);
+    }
 }
 
 impl Citations for Charlatan {
@@ -68,11 +72,12 @@ impl Citations for Charlatan {
 }
 
 impl Features for Charlatan {
-    fn features(&self) -> Vec<Feature> {
-        // You have created a second identity that includes documentation, established acquaintances, and disguises that allow you to assume that persona. Additionally, you can forge documents including official papers and personal letters, as long as you have seen an example of the kind of document or the handwriting you are trying to copy.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "False Identity",
+            description: "You have created a second identity that includes documentation, established acquaintances, and disguises that allow you to assume that persona. Additionally, you can forge documents including official papers and personal letters, as long as you have seen an example of the kind of document or the handwriting you are trying to copy.",
             citation: Citation(Book::Phb, 128),
+            scaling: None,
         }]
     }
 }
@@ -117,11 +117,12 @@ impl Citations for CityWatch {
 }
 
 impl Features for CityWatch {
-    fn features(&self) -> Vec<Feature> {
-        // Your experience in enforcing the law, and dealing with lawbreakers, gives you a feel for local laws and criminals. You can easily find the local outpost of the watch or a similar organization, and just as easily pick out the dens of criminal activity in a community, although you're more likely to be welcome in the former locations rather than the latter.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Watcher's Eye",
+            description: "Your experience in enforcing the law, and dealing with lawbreakers, gives you a feel for local laws and criminals. You can easily find the local outpost of the watch or a similar organization, and just as easily pick out the dens of criminal activity in a community, although you're more likely to be welcome in the former locations rather than the latter.",
             citation: Citation(Book::Scag, 145),
+            scaling: None,
         }]
     }
 }
@@ -259,7 +260,7 @@ mod tests {
         let background = CityWatch {
             variant: Variant::CityWatch,
         };
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
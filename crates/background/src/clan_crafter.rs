@@ -47,11 +47,12 @@ impl Citations for ClanCrafter {
 }
 
 impl Features for ClanCrafter {
-    fn features(&self) -> Vec<Feature> {
-        // As well respected as clan crafters are among outsiders, no one esteems them quite so highly as dwarves do. You always have free room and board in any place where shield dwarves or gold dwarves dwell, and the individuals in such a settlement might vie among themselves to determine who can offer you (and possibly your compatriots) the finest accommodations and assistance.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Respect of the Stout Folk",
+            description: "As well respected as clan crafters are among outsiders, no one esteems them quite so highly as dwarves do. You always have free room and board in any place where shield dwarves or gold dwarves dwell, and the individuals in such a settlement might vie among themselves to determine who can offer you (and possibly your compatriots) the finest accommodations and assistance.",
             citation: Citation(Book::Scag, 145),
+            scaling: None,
         }]
     }
 }
@@ -195,7 +196,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         let background = ClanCrafter;
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
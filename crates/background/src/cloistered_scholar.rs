@@ -50,13 +50,12 @@ impl Citations for CloisteredScholar {
 }
 
 impl Features for CloisteredScholar {
-    fn features(&self) -> Vec<Feature> {
-        // Though others must often endure extensive interviews and significant fees to gain access to even the most common archives in your library, you have free and easy access to the majority of the library, though it might also have repositories of lore that are too valuable, magical, or secret to permit anyone immediate access.
-        // You have a working knowledge of your cloister's personnel and bureaucracy, and you know how to navigate those connections with some ease.
-        // Additionally, you are likely to gain preferential treatment at other libraries across the Realms, as professional courtesy shown to a fellow scholar.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Library Access",
+            description: "Though others must often endure extensive interviews and significant fees to gain access to even the most common archives in your library, you have free and easy access to the majority of the library, though it might also have repositories of lore that are too valuable, magical, or secret to permit anyone immediate access. You have a working knowledge of your cloister's personnel and bureaucracy, and you know how to navigate those connections with some ease. Additionally, you are likely to gain preferential treatment at other libraries across the Realms, as professional courtesy shown to a fellow scholar.",
             citation: Citation(Book::Scag, 146),
+            scaling: None,
         }]
     }
 }
@@ -180,7 +179,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         let background = CloisteredScholar;
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
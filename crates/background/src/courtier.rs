@@ -47,11 +47,12 @@ impl Citations for Courtier {
 }
 
 impl Features for Courtier {
-    fn features(&self) -> Vec<Feature> {
-        // Your knowledge of how bureaucracies function lets you gain access to the records and inner workings of any noble court or government you encounter. You know who the movers and shakers are, whom to go to for the favors you seek, and what the current intrigues of interest in the group are.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Court Functionary",
+            description: "Your knowledge of how bureaucracies function lets you gain access to the records and inner workings of any noble court or government you encounter. You know who the movers and shakers are, whom to go to for the favors you seek, and what the current intrigues of interest in the group are.",
             citation: Citation(Book::Scag, 147),
+            scaling: None,
         }]
     }
 }
@@ -169,7 +170,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         let background = Courtier;
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
@@ -147,11 +147,12 @@ impl Citations for Criminal {
 }
 
 impl Features for Criminal {
-    fn features(&self) -> Vec<Feature> {
-        // You have a reliable and trustworthy contact who acts as your liaison to a network of other criminals. You know how to get messages to and from your contact, even over great distances; specifically, you know the local messengers, corrupt caravan masters, and seedy sailors who can deliver messages for you.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Criminal Contact",
+            description: "You have a reliable and trustworthy contact who acts as your liaison to a network of other criminals. You know how to get messages to and from your contact, even over great distances; specifically, you know the local messengers, corrupt caravan masters, and seedy sailors who can deliver messages for you.",
             citation: Citation(Book::Phb, 129),
+            scaling: None,
         }]
     }
 }
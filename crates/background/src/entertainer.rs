@@ -122,6 +122,11 @@ impl Backstory for Entertainer {
                 .join(", ")
         )]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        let num_routines = rng.gen_range(1..=3);
+        self.routines = Routine::iter().choose_multiple(rng, num_routines);
+    }
 }
 
 impl Citations for Entertainer {
@@ -131,12 +136,12 @@ impl Citations for Entertainer {
 }
 
 impl Features for Entertainer {
-    fn features(&self) -> Vec<Feature> {
-        // You can always find a place to perform, usually in an inn or tavern but possibly with a circus, at a theater, or even in a noble’s court. At such a place, you receive free lodging and food of a modest or comfortable standard (depending on the quality of the establishment), as long as you perform each night. In addition, your performance makes you something of a local figure. When strangers recognize you in a town where you have performed, they typically take a liking to you.
-        // A gladiator is as much an entertainer as any minstrel or circus performer, trained to make the arts of combat into a spectacle the crowd can enjoy. This kind of flashy combat is your entertainer routine, though you might also have some skills as a tumbler or actor. Using your By Popular Demand feature, you can find a place to perform in any place that features combat for entertainment — perhaps a gladiatorial arena or secret pit fighting club. You can replace the musical instrument in your equipment package with an inexpensive but unusual weapon, such as a trident or net.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "By Popular Demand",
+            description: "You can always find a place to perform, usually in an inn or tavern but possibly with a circus, at a theater, or even in a noble’s court. At such a place, you receive free lodging and food of a modest or comfortable standard (depending on the quality of the establishment), as long as you perform each night. In addition, your performance makes you something of a local figure. When strangers recognize you in a town where you have performed, they typically take a liking to you. A gladiator is as much an entertainer as any minstrel or circus performer, trained to make the arts of combat into a spectacle the crowd can enjoy. This kind of flashy combat is your entertainer routine, though you might also have some skills as a tumbler or actor. Using your By Popular Demand feature, you can find a place to perform in any place that features combat for entertainment — perhaps a gladiatorial arena or secret pit fighting club. You can replace the musical instrument in your equipment package with an inexpensive but unusual weapon, such as a trident or net.",
             citation: Citation(Book::Phb, 130),
+            scaling: None,
         }]
     }
 }
@@ -120,11 +120,12 @@ impl Citations for FactionAgent {
 }
 
 impl Features for FactionAgent {
-    fn features(&self) -> Vec<Feature> {
-        // As a faction agent, you have access to a secret network of supporters and operatives who can provide assistance on your adventures. You know a set of secret signs and passwords you can use to identify such operatives, who can provide you with access to a hidden safe house, free room and board, or assistance in finding information. These agents never risk their lives for you or risk revealing their true identities.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Safe Haven",
+            description: "As a faction agent, you have access to a secret network of supporters and operatives who can provide assistance on your adventures. You know a set of secret signs and passwords you can use to identify such operatives, who can provide you with access to a hidden safe house, free room and board, or assistance in finding information. These agents never risk their lives for you or risk revealing their true identities.",
             citation: Citation(Book::Scag, 147),
+            scaling: None,
         }]
     }
 }
@@ -272,7 +273,7 @@ mod tests {
         let background = FactionAgent {
             faction: Faction::EmeraldEnclave,
         };
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
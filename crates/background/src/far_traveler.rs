@@ -114,6 +114,10 @@ impl Backstory for FarTraveler {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Homeland: {}", self.homeland)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.homeland = Homeland::iter().choose(rng).unwrap();
+    }
 }
 
 impl Citations for FarTraveler {
@@ -123,12 +127,12 @@ impl Citations for FarTraveler {
 }
 
 impl Features for FarTraveler {
-    fn features(&self) -> Vec<Feature> {
-        // Your accent, mannerisms, figures of speech, and perhaps even your appearance all mark you as foreign. Curious glances are directed your way wherever you go, which can be a nuisance, but you also gain the friendly interest of scholars and others intrigued by far-off lands, to say nothing of everyday folk who are eager to hear stories of your homeland.
-        // You can parley this attention into access to people and places you might not otherwise have, for you and your traveling companions. Noble lords, scholars, and merchant princes, to name a few, might be interested in hearing about your distant homeland and people.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "All Eyes on You",
+            description: "Your accent, mannerisms, figures of speech, and perhaps even your appearance all mark you as foreign. Curious glances are directed your way wherever you go, which can be a nuisance, but you also gain the friendly interest of scholars and others intrigued by far-off lands, to say nothing of everyday folk who are eager to hear stories of your homeland. You can parley this attention into access to people and places you might not otherwise have, for you and your traveling companions. Noble lords, scholars, and merchant princes, to name a few, might be interested in hearing about your distant homeland and people.",
             citation: Citation(Book::Scag, 149),
+            scaling: None,
         }]
     }
 }
@@ -281,7 +285,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let background = FarTraveler::gen(&mut rng, &AbilityScores::default(), &[], 2);
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
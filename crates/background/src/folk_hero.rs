@@ -97,6 +97,10 @@ impl Backstory for FolkHero {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Defining Event: {}", self.defining_event)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.defining_event = Self::gen_defining_event(rng);
+    }
 }
 
 impl Citations for FolkHero {
@@ -106,11 +110,12 @@ impl Citations for FolkHero {
 }
 
 impl Features for FolkHero {
-    fn features(&self) -> Vec<Feature> {
-        // Since you come from the ranks of the common folk, you fit in among them with ease. You can find a place to hide, rest, or recuperate among other commoners, unless you have shown yourself to be a danger to them. They will shield you from the law or anyone else searching for you, though they will not risk their lives for you.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Rustic Hospitality",
+            description: "Since you come from the ranks of the common folk, you fit in among them with ease. You can find a place to hide, rest, or recuperate among other commoners, unless you have shown yourself to be a danger to them. They will shield you from the law or anyone else searching for you, though they will not risk their lives for you.",
             citation: Citation(Book::Phb, 131),
+            scaling: None,
         }]
     }
 }
@@ -188,6 +188,13 @@ impl Backstory for GuildArtisan {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Guild Business: {}", self.business)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        // Artisans are proficient with their business's tools
+        if let Variant::Merchant = self.variant {
+            self.business = Business::iter().choose(rng).unwrap();
+        }
+    }
 }
 
 impl Citations for GuildArtisan {
@@ -197,13 +204,12 @@ impl Citations for GuildArtisan {
 }
 
 impl Features for GuildArtisan {
-    fn features(&self) -> Vec<Feature> {
-        // As an established and respected member of a guild, you can rely on certain benefits that membership provides. Your fellow guild members will provide you with lodging and food if necessary, and pay for your funeral if needed. In some cities and towns, a guildhall offers a central place to meet other members of your profession, which can be a good place to meet potential patrons, allies, or hirelings.
-        // Guilds often wield tremendous political power. If you are accused of a crime, your guild will support you if a good case can be made for your innocence or the crime is justifiable. You can also gain access to powerful political figures through the guild, if you are a member in good standing. Such connections might require the donation of money or magic items to the guild's coffers.
-        // You must pay dues of 5 gp per month to the guild. If you miss payments, you must make up back dues to remain in the guild's good graces.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Guild Membership",
+            description: "As an established and respected member of a guild, you can rely on certain benefits that membership provides. Your fellow guild members will provide you with lodging and food if necessary, and pay for your funeral if needed. In some cities and towns, a guildhall offers a central place to meet other members of your profession, which can be a good place to meet potential patrons, allies, or hirelings. Guilds often wield tremendous political power. If you are accused of a crime, your guild will support you if a good case can be made for your innocence or the crime is justifiable. You can also gain access to powerful political figures through the guild, if you are a member in good standing. Such connections might require the donation of money or magic items to the guild's coffers. You must pay dues of 5 gp per month to the guild. If you miss payments, you must make up back dues to remain in the guild's good graces.",
             citation: Citation(Book::Phb, 133),
+            scaling: None,
         }]
     }
 }
@@ -118,6 +118,10 @@ impl Backstory for HauntedOne {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Harrowing Event: {}", self.harrowing_event)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.harrowing_event = Self::gen_harrowing_event(rng);
+    }
 }
 
 impl Citations for HauntedOne {
@@ -127,11 +131,12 @@ impl Citations for HauntedOne {
 }
 
 impl Features for HauntedOne {
-    fn features(&self) -> Vec<Feature> {
-        // Those who look into your eyes can see that you have faced unimaginable horror and that you are no stranger to darkness. Though they might fear you, commoners will extend you every courtesy and do their utmost to help you. Unless you have shown yourself to be a danger to them, they will even take up arms to fight alongside you, should you find yourself facing an enemy alone.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Heart of Darkness",
+            description: "Those who look into your eyes can see that you have faced unimaginable horror and that you are no stranger to darkness. Though they might fear you, commoners will extend you every courtesy and do their utmost to help you. Unless you have shown yourself to be a danger to them, they will even take up arms to fight alongside you, should you find yourself facing an enemy alone.",
             citation: Citation(Book::Cos, 209),
+            scaling: None,
         }]
     }
 }
@@ -259,7 +264,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let background = HauntedOne::gen(&mut rng, &AbilityScores::default(), &[], 2);
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
@@ -95,6 +95,10 @@ impl Backstory for Hermit {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Life of Seclusion: {}", self.life_of_seclusion)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.life_of_seclusion = Self::gen_life_of_seclusion(rng);
+    }
 }
 
 impl Citations for Hermit {
@@ -104,12 +108,12 @@ impl Citations for Hermit {
 }
 
 impl Features for Hermit {
-    fn features(&self) -> Vec<Feature> {
-        // The quiet seclusion of your extended hermitage gave you access to a unique and powerful discovery. The exact nature of this revelation depends on the nature of your seclusion. It might be a great truth about the cosmos, the deities, the powerful beings of the outer planes, or the forces of nature. It could be a site that no one else has ever seen. You might have uncovered a fact that has long been forgotten, or unearthed some relic of the past that could rewrite history. It might be information that would be damaging to the people who consigned you to exile, and hence the reason for your return to society.
-        // Work with your DM to determine the details of your discovery and its impact on the campaign.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Discovery",
+            description: "The quiet seclusion of your extended hermitage gave you access to a unique and powerful discovery. The exact nature of this revelation depends on the nature of your seclusion. It might be a great truth about the cosmos, the deities, the powerful beings of the outer planes, or the forces of nature. It could be a site that no one else has ever seen. You might have uncovered a fact that has long been forgotten, or unearthed some relic of the past that could rewrite history. It might be information that would be damaging to the people who consigned you to exile, and hence the reason for your return to society. Work with your DM to determine the details of your discovery and its impact on the campaign.",
             citation: Citation(Book::Phb, 134),
+            scaling: None,
         }]
     }
 }
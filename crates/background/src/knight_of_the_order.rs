@@ -79,6 +79,10 @@ impl Backstory for KnightOfTheOrder {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Knightly Order: {}", self.knightly_order)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.knightly_order = KnightlyOrder::iter().choose(rng).unwrap();
+    }
 }
 
 impl Citations for KnightOfTheOrder {
@@ -88,12 +92,12 @@ impl Citations for KnightOfTheOrder {
 }
 
 impl Features for KnightOfTheOrder {
-    fn features(&self) -> Vec<Feature> {
-        // You receive shelter and succor from members of your knightly order and those who are sympathetic to its aims. If your order is a religious one, you can gain aid from temples and other religious communities of your deity. Knights of civic orders can get help from the community – whether a lone settlement or a great nation that they serve, and knights of philosophical orders can find help from those they have aided in pursuit of their ideals, and those who share those ideals.
-        // This help comes in the form of shelter and meals, and healing when appropriate, as well as occasionally risky assistance, such as a band of local citizens rallying to aid a sorely pressed knight in a fight, or those who support the order helping to smuggle a knight out of town when he or she is being hunted unjustly.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Knightly Regard",
+            description: "You receive shelter and succor from members of your knightly order and those who are sympathetic to its aims. If your order is a religious one, you can gain aid from temples and other religious communities of your deity. Knights of civic orders can get help from the community – whether a lone settlement or a great nation that they serve, and knights of philosophical orders can find help from those they have aided in pursuit of their ideals, and those who share those ideals. This help comes in the form of shelter and meals, and healing when appropriate, as well as occasionally risky assistance, such as a band of local citizens rallying to aid a sorely pressed knight in a fight, or those who support the order helping to smuggle a knight out of town when he or she is being hunted unjustly.",
             citation: Citation(Book::Scag, 151),
+            scaling: None,
         }]
     }
 }
@@ -251,7 +255,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let background = KnightOfTheOrder::gen(&mut rng, &AbilityScores::default(), &[], 2);
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
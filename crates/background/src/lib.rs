@@ -30,7 +30,7 @@ use std::fmt;
 use backstory::Backstory;
 use citation::{CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features};
 use gear::currency::Coin;
 use haunted_one::HauntedOne;
 use itertools::Itertools;
@@ -43,7 +43,7 @@ use stats::{
     equipment::{Equipment, EquipmentOption, StartingEquipment},
     proficiencies::{Proficiencies, Proficiency, ProficiencyOption},
 };
-use strum::{EnumIter, IntoEnumIterator};
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoEnumIterator};
 
 use self::{
     acolyte::Acolyte, charlatan::Charlatan, city_watch::CityWatch, clan_crafter::ClanCrafter,
@@ -128,12 +128,15 @@ pub trait Background:
     pub fn citations(&self) -> CitationList {}
     pub fn coins(&self) -> (Coin, u8) {}
     pub fn deity_required(&self) -> bool {}
+    pub fn effects(&self, level: u8) -> Vec<FeatureEffect> {}
     pub fn equipment(&self) -> Vec<Equipment> {}
-    pub fn features(&self) -> Vec<Feature> {}
+    pub fn features(&self, level: u8) -> Vec<Feature> {}
     pub fn flaws(&self) -> Vec<String> {}
     pub fn ideals(&self) -> Vec<(String, Influence)> {}
     pub fn languages(&self) -> Vec<Language> {}
     pub fn proficiencies(&self) -> Vec<Proficiency> {}
+    pub fn reroll_backstory(&mut self, rng: &mut impl Rng) {}
+    pub fn skills() -> Vec<Skill> {}
     pub fn traits(&self) -> Vec<String> {}
     pub fn weight(
         ability_scores: &AbilityScores,
@@ -142,7 +145,21 @@ pub trait Background:
     ) -> f64 {}
 }]
 /// List of currently supported background options
-#[derive(Deserialize, EnumIter, Serialize)]
+#[derive(Deserialize, EnumDiscriminants, EnumIter, Serialize)]
+#[strum_discriminants(
+    name(BackgroundType),
+    derive(
+        Deserialize,
+        Display,
+        EnumIter,
+        EnumString,
+        Hash,
+        Ord,
+        PartialOrd,
+        Serialize
+    ),
+    strum(ascii_case_insensitive)
+)]
 pub enum BackgroundOption {
     Acolyte(Acolyte),
     Charlatan(Charlatan),
@@ -180,164 +197,190 @@ impl BackgroundOption {
         proficiencies: &[Proficiency],
         proficiency_bonus: i16,
     ) -> Self {
-        let options: Vec<BackgroundOption> = Self::iter().collect();
-        let option = options
-            .choose_weighted(rng, |o| {
-                o.weight(ability_scores, proficiencies, proficiency_bonus)
+        let candidates = Self::candidates(ability_scores, proficiencies, proficiency_bonus);
+        let (background, _) = candidates.choose_weighted(rng, |(_, w)| *w).unwrap();
+        Self::gen_background(
+            rng,
+            ability_scores,
+            proficiencies,
+            proficiency_bonus,
+            *background,
+        )
+    }
+
+    /// Backgrounds considered when choosing a background, weighted by the character's best modifiers
+    /// for each background's skills
+    #[must_use]
+    pub fn candidates(
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        proficiency_bonus: i16,
+    ) -> Vec<(BackgroundType, f64)> {
+        Self::iter()
+            .map(|o| {
+                let weight = o.weight(ability_scores, proficiencies, proficiency_bonus);
+                (BackgroundType::from(&o), weight)
             })
-            .unwrap();
-        match option {
-            Self::Acolyte(_) => Self::Acolyte(Acolyte::gen(
-                rng,
-                ability_scores,
-                proficiencies,
-                proficiency_bonus,
-            )),
-            Self::Charlatan(_) => Self::Charlatan(Charlatan::gen(
+            .collect()
+    }
+
+    /// Generate a specific background, making any choices within it based on the character so far
+    pub fn gen_background(
+        rng: &mut impl Rng,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        proficiency_bonus: i16,
+        background_type: BackgroundType,
+    ) -> Self {
+        match background_type {
+            BackgroundType::Acolyte => Self::Acolyte(Acolyte::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::CityWatch(_) => Self::CityWatch(CityWatch::gen(
+            BackgroundType::Charlatan => Self::Charlatan(Charlatan::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::ClanCrafter(_) => Self::ClanCrafter(ClanCrafter::gen(
+            BackgroundType::CityWatch => Self::CityWatch(CityWatch::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::CloisteredScholar(_) => Self::CloisteredScholar(CloisteredScholar::gen(
+            BackgroundType::ClanCrafter => Self::ClanCrafter(ClanCrafter::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Courtier(_) => Self::Courtier(Courtier::gen(
+            BackgroundType::CloisteredScholar => Self::CloisteredScholar(CloisteredScholar::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Criminal(_) => Self::Criminal(Criminal::gen(
+            BackgroundType::Courtier => Self::Courtier(Courtier::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Entertainer(_) => Self::Entertainer(Entertainer::gen(
+            BackgroundType::Criminal => Self::Criminal(Criminal::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::FactionAgent(_) => Self::FactionAgent(FactionAgent::gen(
+            BackgroundType::Entertainer => Self::Entertainer(Entertainer::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::FarTraveler(_) => Self::FarTraveler(FarTraveler::gen(
+            BackgroundType::FactionAgent => Self::FactionAgent(FactionAgent::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::FolkHero(_) => Self::FolkHero(FolkHero::gen(
+            BackgroundType::FarTraveler => Self::FarTraveler(FarTraveler::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::GuildArtisan(_) => Self::GuildArtisan(GuildArtisan::gen(
+            BackgroundType::FolkHero => Self::FolkHero(FolkHero::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Hermit(_) => Self::Hermit(Hermit::gen(
+            BackgroundType::GuildArtisan => Self::GuildArtisan(GuildArtisan::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::HauntedOne(_) => Self::HauntedOne(HauntedOne::gen(
+            BackgroundType::Hermit => Self::Hermit(Hermit::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Inheritor(_) => Self::Inheritor(Inheritor::gen(
+            BackgroundType::HauntedOne => Self::HauntedOne(HauntedOne::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::KnightOfTheOrder(_) => Self::KnightOfTheOrder(KnightOfTheOrder::gen(
+            BackgroundType::Inheritor => Self::Inheritor(Inheritor::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::MercenaryVeteran(_) => Self::MercenaryVeteran(MercenaryVeteran::gen(
+            BackgroundType::KnightOfTheOrder => Self::KnightOfTheOrder(KnightOfTheOrder::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Noble(_) => Self::Noble(Noble::gen(
+            BackgroundType::MercenaryVeteran => Self::MercenaryVeteran(MercenaryVeteran::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Outlander(_) => Self::Outlander(Outlander::gen(
+            BackgroundType::Noble => Self::Noble(Noble::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Sage(_) => Self::Sage(Sage::gen(
+            BackgroundType::Outlander => Self::Outlander(Outlander::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Sailor(_) => Self::Sailor(Sailor::gen(
+            BackgroundType::Sage => Self::Sage(Sage::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Soldier(_) => Self::Soldier(Soldier::gen(
+            BackgroundType::Sailor => Self::Sailor(Sailor::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::UrbanBountyHunter(_) => Self::UrbanBountyHunter(UrbanBountyHunter::gen(
+            BackgroundType::Soldier => Self::Soldier(Soldier::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::Urchin(_) => Self::Urchin(Urchin::gen(
+            BackgroundType::UrbanBountyHunter => Self::UrbanBountyHunter(UrbanBountyHunter::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::UthgardtTribeMember(_) => Self::UthgardtTribeMember(UthgardtTribeMember::gen(
+            BackgroundType::Urchin => Self::Urchin(Urchin::gen(
                 rng,
                 ability_scores,
                 proficiencies,
                 proficiency_bonus,
             )),
-            Self::WaterdhavianNoble(_) => Self::WaterdhavianNoble(WaterdhavianNoble::gen(
+            BackgroundType::UthgardtTribeMember => Self::UthgardtTribeMember(
+                UthgardtTribeMember::gen(rng, ability_scores, proficiencies, proficiency_bonus),
+            ),
+            BackgroundType::WaterdhavianNoble => Self::WaterdhavianNoble(WaterdhavianNoble::gen(
                 rng,
                 ability_scores,
                 proficiencies,
@@ -48,11 +48,12 @@ impl Citations for MercenaryVeteran {
 }
 
 impl Features for MercenaryVeteran {
-    fn features(&self) -> Vec<Feature> {
-        // You know the mercenary life as only someone who has experienced it can. You are able to identify mercenary companies by their emblems, and you know a little about any such company, including the names and reputations of its commanders and leaders, and who has hired them recently. You can find the taverns and festhalls where mercenaries abide in any area, as long as you speak the language. You can find mercenary work between adventures sufficient to maintain a comfortable lifestyle.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Mercenary Life",
+            description: "You know the mercenary life as only someone who has experienced it can. You are able to identify mercenary companies by their emblems, and you know a little about any such company, including the names and reputations of its commanders and leaders, and who has hired them recently. You can find the taverns and festhalls where mercenaries abide in any area, as long as you speak the language. You can find mercenary work between adventures sufficient to maintain a comfortable lifestyle.",
             citation: Citation(Book::Scag, 152),
+            scaling: None,
         }]
     }
 }
@@ -182,7 +183,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let background = MercenaryVeteran::gen(&mut rng, &AbilityScores::default(), &[], 2);
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
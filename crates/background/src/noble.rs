@@ -95,20 +95,22 @@ impl Citations for Noble {
 }
 
 impl Features for Noble {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         match self.variant {
             Variant::Knight => {
-                // You have the service of three retainers loyal to your family. These retainers can be attendants or messengers, and one might be a majordomo. Your retainers are commoners who can perform mundane tasks for you, but they do not fight for you, will not follow you into obviously dangerous areas (such as dungeons), and will leave if they are frequently endangered or abused.
                 vec![Feature {
                     title: "Retainers",
+                    description: "You have the service of three retainers loyal to your family. These retainers can be attendants or messengers, and one might be a majordomo. Your retainers are commoners who can perform mundane tasks for you, but they do not fight for you, will not follow you into obviously dangerous areas (such as dungeons), and will leave if they are frequently endangered or abused.",
                     citation: Citation(Book::Phb, 136),
+                    scaling: None,
                 }]
             }
             Variant::Noble => {
-                // You have the service of three retainers loyal to your family. These retainers can be attendants or messengers, and one might be a majordomo. Your retainers are commoners who can perform mundane tasks for you, but they do not fight for you, will not follow you into obviously dangerous areas (such as dungeons), and will leave if they are frequently endangered or abused.
                 vec![Feature {
                     title: "Position of Privilege",
+                    description: "You have the service of three retainers loyal to your family. These retainers can be attendants or messengers, and one might be a majordomo. Your retainers are commoners who can perform mundane tasks for you, but they do not fight for you, will not follow you into obviously dangerous areas (such as dungeons), and will leave if they are frequently endangered or abused.",
                     citation: Citation(Book::Phb, 135),
+                    scaling: None,
                 }]
             }
         }
@@ -108,11 +108,12 @@ impl Citations for Outlander {
 }
 
 impl Features for Outlander {
-    fn features(&self) -> Vec<Feature> {
-        // You have an excellent memory for maps and geography, and you can always recall the general layout of terrain, settlements, and other features around you. In addition, you can find food and fresh water for yourself and up to five other people each day, provided that the land offers berries, small game, water, and so forth.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Wanderer",
+            description: "You have an excellent memory for maps and geography, and you can always recall the general layout of terrain, settlements, and other features around you. In addition, you can find food and fresh water for yourself and up to five other people each day, provided that the land offers berries, small game, water, and so forth.",
             citation: Citation(Book::Phb, 136),
+            scaling: None,
         }]
     }
 }
@@ -121,11 +121,12 @@ impl Citations for Sage {
 }
 
 impl Features for Sage {
-    fn features(&self) -> Vec<Feature> {
-        // When you attempt to learn or recall a piece of lore, if you do not know that information, you often know where and from whom you can obtain it. Usually, this information comes from a library, scriptorium, university, or a sage or other learned person or creature. Your DM might rule that the knowledge you seek is secreted away in an almost inaccessible place, or that it simply cannot be found. Unearthing the deepest secrets of the multiverse can require an adventure or even a whole campaign.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Researcher",
+            description: "When you attempt to learn or recall a piece of lore, if you do not know that information, you often know where and from whom you can obtain it. Usually, this information comes from a library, scriptorium, university, or a sage or other learned person or creature. Your DM might rule that the knowledge you seek is secreted away in an almost inaccessible place, or that it simply cannot be found. Unearthing the deepest secrets of the multiverse can require an adventure or even a whole campaign.",
             citation: Citation(Book::Phb, 138),
+            scaling: None,
         }]
     }
 }
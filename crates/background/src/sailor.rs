@@ -116,20 +116,22 @@ impl Citations for Sailor {
 }
 
 impl Features for Sailor {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         match self.variant {
             Variant::Pirate => {
-                // No matter where you go, people are afraid of you due to your reputation. When you are in a civilized settlement, you can get away with minor criminal offenses, such as refusing to pay for food at a tavern or breaking down doors at a local shop, since most people will not report your activity to the authorities.
                 vec![Feature {
                     title: "Bad Reputation",
+                    description: "No matter where you go, people are afraid of you due to your reputation. When you are in a civilized settlement, you can get away with minor criminal offenses, such as refusing to pay for food at a tavern or breaking down doors at a local shop, since most people will not report your activity to the authorities.",
                     citation: Citation(Book::Phb, 139),
+                    scaling: None,
                 }]
             }
             Variant::Sailor => {
-                // When you need to, you can secure free passage on a sailing ship for yourself and your adventuring companions. You might sail on the ship you served on, or another ship you have good relations with (perhaps one captained by a former crewmate). Because you're calling in a favor, you can't be certain of a schedule or route that will meet your every need. Your Dungeon Master will determine how long it takes to get where you need to go. In return for your free passage, you and your companions are expected to assist the crew during the voyage.
                 vec![Feature {
                     title: "Ship's Passage",
+                    description: "When you need to, you can secure free passage on a sailing ship for yourself and your adventuring companions. You might sail on the ship you served on, or another ship you have good relations with (perhaps one captained by a former crewmate). Because you're calling in a favor, you can't be certain of a schedule or route that will meet your every need. Your Dungeon Master will determine how long it takes to get where you need to go. In return for your free passage, you and your companions are expected to assist the crew during the voyage.",
                     citation: Citation(Book::Phb, 139),
+                    scaling: None,
                 }]
             }
         }
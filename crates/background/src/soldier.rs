@@ -122,11 +122,12 @@ impl Citations for Soldier {
 }
 
 impl Features for Soldier {
-    fn features(&self) -> Vec<Feature> {
-        // You have a military rank from your career as a soldier. Soldiers loyal to your former military organization still recognize your authority and influence, and they defer to you if they are of a lower rank. You can invoke your rank to exert influence over other soldiers and requisition simple equipment or horses for temporary use. You can also usually gain access to friendly military encampments and fortresses where your rank is recognized.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Military Rank",
+            description: "You have a military rank from your career as a soldier. Soldiers loyal to your former military organization still recognize your authority and influence, and they defer to you if they are of a lower rank. You can invoke your rank to exert influence over other soldiers and requisition simple equipment or horses for temporary use. You can also usually gain access to friendly military encampments and fortresses where your rank is recognized.",
             citation: Citation(Book::Phb, 140),
+            scaling: None,
         }]
     }
 }
@@ -53,11 +53,12 @@ impl Citations for UrbanBountyHunter {
 }
 
 impl Features for UrbanBountyHunter {
-    fn features(&self) -> Vec<Feature> {
-        // You are in frequent contact with people in the segment of society that your chosen quarries move through. These people might be associated with the criminal underworld, the rough-and-tumble folk of the streets, or members of high society. This connection comes in the form of a contact in any city you visit, a person who provides information about the people and places of the local area.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Ear to the Ground",
+            description: "You are in frequent contact with people in the segment of society that your chosen quarries move through. These people might be associated with the criminal underworld, the rough-and-tumble folk of the streets, or members of high society. This connection comes in the form of a contact in any city you visit, a person who provides information about the people and places of the local area.",
             citation: Citation(Book::Scag, 153),
+            scaling: None,
         }]
     }
 }
@@ -186,7 +187,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let background = UrbanBountyHunter::gen(&mut rng, &AbilityScores::default(), &[], 2);
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
@@ -79,11 +79,12 @@ impl Citations for Urchin {
 }
 
 impl Features for Urchin {
-    fn features(&self) -> Vec<Feature> {
-        // You know the secret patterns and flow to cities and can find passages through the urban sprawl that others would miss. When you are not in combat, you (and companions you lead) can travel between any two locations in the city twice as fast as your speed would normally allow.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "City Secrets",
+            description: "You know the secret patterns and flow to cities and can find passages through the urban sprawl that others would miss. When you are not in combat, you (and companions you lead) can travel between any two locations in the city twice as fast as your speed would normally allow.",
             citation: Citation(Book::Phb, 141),
+            scaling: None,
         }]
     }
 }
@@ -80,6 +80,10 @@ impl Backstory for UthgardtTribeMember {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Tribal Totem: {}", self.tribal_totem)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.tribal_totem = Totem::iter().choose(rng).unwrap();
+    }
 }
 
 impl Citations for UthgardtTribeMember {
@@ -89,12 +93,12 @@ impl Citations for UthgardtTribeMember {
 }
 
 impl Features for UthgardtTribeMember {
-    fn features(&self) -> Vec<Feature> {
-        // You have an excellent knowledge of not only your tribe's territory, but also the terrain and natural resources of the rest of the North. You are familiar enough with any wilderness area that you find twice as much food and water as you normally would when you forage there.
-        // Additionally, you can call upon the hospitality of your people, and those folk allied with your tribe, often including members of druid circles, tribes of nomadic elves, the Harpers, and the priesthoods devoted to the gods of the First Circle.
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![Feature {
             title: "Uthgardt Heritage",
+            description: "You have an excellent knowledge of not only your tribe's territory, but also the terrain and natural resources of the rest of the North. You are familiar enough with any wilderness area that you find twice as much food and water as you normally would when you forage there. Additionally, you can call upon the hospitality of your people, and those folk allied with your tribe, often including members of druid circles, tribes of nomadic elves, the Harpers, and the priesthoods devoted to the gods of the First Circle.",
             citation: Citation(Book::Scag, 154),
+            scaling: None,
         }]
     }
 }
@@ -250,7 +254,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let background = UthgardtTribeMember::gen(&mut rng, &AbilityScores::default(), &[], 2);
-        insta::assert_yaml_snapshot!(background.features());
+        insta::assert_yaml_snapshot!(background.features(1));
     }
 
     #[test]
@@ -1,9 +1,14 @@
+use rand::Rng;
+
 /// Basic trait to return a list of backstory descriptions
 pub trait Backstory {
     /// List of backstory descriptions for this entity
     fn backstory(&self) -> Vec<String> {
         vec![]
     }
+
+    /// Choose new backstory descriptions, keeping any that the rest of the character depends on
+    fn reroll_backstory(&mut self, _: &mut impl Rng) {}
 }
 
 pub const MONSTROUS_ORIGIN: &[&str] = &[
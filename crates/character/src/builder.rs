@@ -0,0 +1,1017 @@
+use std::{cmp::Reverse, error::Error, fmt};
+
+use alignment::Alignment;
+use background::{BackgroundOption, BackgroundType};
+use characteristics::Gender;
+use class::{ClassOption, ClassType};
+use deities::{Deity, Pantheon};
+use race::{RaceOption, RaceType, Subrace};
+use rand::Rng;
+use rand_pcg::Pcg64;
+use stats::ability::{
+    AbilityScore, AbilityScoreMethod, AbilityScoreType, AbilityScores, Skill, MAX_ROLLED_TOTAL,
+    MAX_SCORE,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    Character, ClassLevels, Decision, EquipmentMethod, HitPointMethod, Pinned, Seed, Trace,
+};
+
+/// Highest level a character can reach.
+pub(crate) const MAX_LEVEL: u8 = 20;
+/// Chance of a randomly chosen class being multiclassed, for characters above 1st level.
+const MULTICLASS_CHANCE: f64 = 0.1;
+
+/// Stages of generating a character. Each stage draws from its own stream of random numbers, all derived
+/// from the same master state, so a change in how much randomness one stage uses (such as adding an entry to
+/// a background's tables) doesn't reshuffle the choices made by every stage after it.
+///
+/// The values are the stream numbers, so new stages must be given new values rather than renumbering these.
+#[derive(Clone, Copy)]
+enum Stage {
+    Race = 1,
+    Abilities = 2,
+    Background = 3,
+    Class = 4,
+    HitPoints = 5,
+    Personality = 6,
+    Languages = 7,
+    Deity = 8,
+    Alignment = 9,
+    Proficiencies = 10,
+    Equipment = 11,
+    Spells = 12,
+}
+
+impl Stage {
+    /// Random number generator for this stage, using the stage's stream of the master state
+    fn rng(self, state: u128) -> Pcg64 {
+        Pcg64::new(state, self as u128)
+    }
+}
+
+/// Reasons a set of constraints can't be used to generate a character.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ConstraintError {
+    /// No deity with this name exists (in the chosen pantheon, if there is one).
+    Deity(String),
+    /// Levels must be between 1 and 20.
+    Level(u8),
+    /// No set of rolled ability scores can reach this minimum total.
+    MinimumTotal(i16),
+    /// A subrace was requested without choosing a race.
+    MissingRace,
+    /// The character can't multiclass into this class, either because it is already their first class or
+    /// their ability scores don't meet the prerequisites.
+    Multiclass(ClassType),
+    /// Levels in a second class must leave at least one level for the first class.
+    MulticlassLevel(u8),
+    /// The chosen race doesn't have this subrace.
+    Subrace(Subrace),
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deity(name) => write!(f, "No deity named {name} is available"),
+            Self::Level(level) => write!(f, "Level {level} is not between 1 and {MAX_LEVEL}"),
+            Self::MinimumTotal(minimum) => write!(
+                f,
+                "Rolled ability scores can't total {minimum}, the highest possible is {MAX_ROLLED_TOTAL}"
+            ),
+            Self::MissingRace => write!(f, "A race must be chosen to choose a subrace"),
+            Self::Multiclass(class) => write!(f, "The character can't multiclass into {class}"),
+            Self::MulticlassLevel(level) => {
+                write!(
+                    f,
+                    "{level} levels in a second class leaves none for the first"
+                )
+            }
+            Self::Subrace(subrace) => write!(f, "The chosen race has no {subrace} subrace"),
+        }
+    }
+}
+
+impl Error for ConstraintError {}
+
+/// Generate a character with some of the choices already made.
+///
+/// Anything that isn't pinned is generated the same way as `Character::gen`, so weighted choices
+/// (class by ability scores, background by skills, etc.) still take the pinned choices into account.
+#[derive(Default)]
+pub struct CharacterBuilder {
+    ability_score_method: AbilityScoreMethod,
+    alignment: Option<Alignment>,
+    background: Option<BackgroundType>,
+    class: Option<ClassType>,
+    deity: Option<String>,
+    equipment_method: EquipmentMethod,
+    gender: Option<Gender>,
+    hit_point_method: HitPointMethod,
+    level: Option<u8>,
+    multiclass: Option<(ClassType, u8)>,
+    pantheon: Option<Pantheon>,
+    race: Option<RaceType>,
+    subrace: Option<Subrace>,
+    trace: bool,
+    variant_encumbrance: bool,
+}
+
+impl CharacterBuilder {
+    /// Start a new builder with nothing pinned
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Choose how ability scores are generated (rolled by default)
+    #[must_use]
+    pub fn ability_score_method(mut self, method: AbilityScoreMethod) -> Self {
+        self.ability_score_method = method;
+        self
+    }
+
+    /// Pin the character's alignment
+    #[must_use]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Pin the character's background
+    #[must_use]
+    pub fn background(mut self, background: BackgroundType) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Pin the character's class
+    #[must_use]
+    pub fn class(mut self, class: ClassType) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Pin the character's deity by name. Chooses the matching pantheon if none was pinned.
+    #[must_use]
+    pub fn deity(mut self, deity: impl Into<String>) -> Self {
+        self.deity = Some(deity.into());
+        self
+    }
+
+    /// Choose whether the first class's starting equipment is taken, or its starting gold is rolled instead
+    #[must_use]
+    pub fn equipment_method(mut self, method: EquipmentMethod) -> Self {
+        self.equipment_method = method;
+        self
+    }
+
+    /// Pin the character's gender
+    #[must_use]
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    /// Choose how hit points are determined after 1st level (average by default)
+    #[must_use]
+    pub fn hit_point_method(mut self, method: HitPointMethod) -> Self {
+        self.hit_point_method = method;
+        self
+    }
+
+    /// Pin the character's level (1-20)
+    #[must_use]
+    pub fn level(mut self, level: u8) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Pin a second class the character has levels in, such as 2 levels of Rogue. The rest of the
+    /// character's levels are in their first class. Both classes' multiclassing prerequisites must be met.
+    #[must_use]
+    pub fn multiclass(mut self, class: ClassType, level: u8) -> Self {
+        self.multiclass = Some((class, level));
+        self
+    }
+
+    /// Pin the pantheon the character worships
+    #[must_use]
+    pub fn pantheon(mut self, pantheon: Pantheon) -> Self {
+        self.pantheon = Some(pantheon);
+        self
+    }
+
+    /// Pin the character's race
+    #[must_use]
+    pub fn race(mut self, race: RaceType) -> Self {
+        self.race = Some(race);
+        self
+    }
+
+    /// Pin the character's subrace, such as `Subrace::Hill` for a Hill Dwarf. Requires a race.
+    #[must_use]
+    pub fn subrace(mut self, subrace: Subrace) -> Self {
+        self.subrace = Some(subrace);
+        self
+    }
+
+    /// Keep a trace of the weighted choices made while generating the character, explaining each one
+    #[must_use]
+    pub fn trace(mut self, enabled: bool) -> Self {
+        self.trace = enabled;
+        self
+    }
+
+    /// Use the variant encumbrance rules, reducing the character's speed if they carry too much
+    #[must_use]
+    pub fn variant_encumbrance(mut self, enabled: bool) -> Self {
+        self.variant_encumbrance = enabled;
+        self
+    }
+
+    /// Generate a character from a seed, which is recorded on the character so that the same seed and
+    /// choices can generate it again.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the pinned choices can't be satisfied, such as an unknown subrace or deity.
+    pub fn gen_seeded<'a>(self, seed: Seed) -> Result<Character<'a>, ConstraintError> {
+        let mut character = self.gen(&mut seed.rng())?;
+        character.seed = Some(seed);
+        Ok(character)
+    }
+
+    /// Generate a new character, randomly choosing anything that hasn't been pinned.
+    ///
+    /// Steps are as follows:
+    /// 1. Choose a Race (which also generates a name and some physical characteristics)
+    /// 2. Generate ability scores (and apply the racial ability increases)
+    /// 3. Choose a background (weighted by highest skill modifiers) and class (weighted by ability scores),
+    ///    occasionally multiclassing into a second class whose prerequisites are met. Then apply any Ability
+    ///    Score Improvements for each class's levels, choose subclasses (weighted by ability scores and
+    ///    background skills) and determine hit points
+    /// 4. Choose personality traits and any additional languages
+    /// 5. Choose a pantheon and deity
+    /// 6. Choose alignment (weighted based on inputs from race, personality and deity)
+    /// 7. Choose proficiencies, weighted towards optimal ones based on what is known about the character so far,
+    ///    and then any expertise in them
+    /// 8. Choose equipment (or roll starting gold instead of the class's equipment), plus additional wealth for
+    ///    higher level characters
+    /// 9. Choose cantrips and spells if the class can cast them
+    ///
+    /// Only a master state is drawn from `rng`. Each stage then uses its own stream of random numbers
+    /// derived from it, so content changes in one stage don't change the random choices made by the others.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the pinned choices can't be satisfied, such as an unknown subrace or deity.
+    pub fn gen<'a>(self, rng: &mut impl Rng) -> Result<Character<'a>, ConstraintError> {
+        let level = self.level.unwrap_or(1);
+        if !(1..=MAX_LEVEL).contains(&level) {
+            return Err(ConstraintError::Level(level));
+        }
+        if let AbilityScoreMethod::RolledMinimumTotal(minimum) = self.ability_score_method {
+            if minimum > MAX_ROLLED_TOTAL {
+                return Err(ConstraintError::MinimumTotal(minimum));
+            }
+        }
+        if let Some((_, multiclass_level)) = self.multiclass {
+            if multiclass_level == 0 || multiclass_level >= level {
+                return Err(ConstraintError::MulticlassLevel(multiclass_level));
+            }
+        }
+        let (pantheon, deity) = self.find_deity()?;
+        // Only the master state is drawn from the given generator, each stage has its own stream
+        let state = rng.gen::<u128>();
+
+        let rng = &mut Stage::Race.rng(state);
+
+        let race = match (self.race, self.subrace) {
+            (Some(race), Some(subrace)) => RaceOption::gen_subrace(rng, race, subrace)
+                .ok_or(ConstraintError::Subrace(subrace))?,
+            (Some(race), None) => RaceOption::gen_race(rng, race),
+            (None, Some(_)) => return Err(ConstraintError::MissingRace),
+            (None, None) => RaceOption::gen(rng),
+        };
+        let mut characteristics = race.gen_characteristics(rng);
+        if let Some(gender) = self.gender {
+            characteristics.gender = gender;
+        }
+        let name = race.gen_name(rng, &characteristics);
+
+        let mut abilities = AbilityScores::gen(
+            &mut Stage::Abilities.rng(state),
+            self.ability_score_method,
+            &self.ability_priority(&race),
+        );
+        let mut character = Character {
+            alignment: self.alignment.clone(),
+            deity,
+            level,
+            pantheon,
+            pinned: Pinned {
+                alignment: self.alignment.is_some(),
+                deity: self.deity.is_some(),
+                pantheon: self.pantheon.is_some() || self.deity.is_some(),
+            },
+            trace: self.trace.then(Trace::default),
+            variant_encumbrance: self.variant_encumbrance,
+            ..Character::default()
+        };
+        abilities.increase(race.abilities(), MAX_SCORE);
+        character.abilities = abilities;
+        let rng = &mut Stage::Background.rng(state);
+        let background = self.gen_background(rng, &mut character);
+        let rng = &mut Stage::Class.rng(state);
+        let class = self.gen_class(rng, &mut character)?;
+        character.race = Some(race);
+        character.name = name;
+        character.characteristics = Some(characteristics);
+        character.background = Some(background);
+        character.classes = self.gen_classes(rng, &character.abilities, level, class)?;
+        if self.multiclass.is_none() && character.classes.len() > 1 {
+            character.record(|c| {
+                let (first, second) = (&c.classes[0].class, &c.classes[1].class);
+                Decision::new(
+                    "Multiclass",
+                    ClassOption::multiclass_candidates(&c.abilities, &[first.into()]),
+                    [ClassType::from(second)],
+                    format!(
+                        "You multiclassed into {second} because you meet the ability score prerequisites \
+                        for it and {first}, and {} is its primary ability",
+                        primary_ability(c, second)
+                    ),
+                )
+            });
+        }
+        character.gen_ability_score_improvements(rng);
+        character.gen_subclass(rng);
+        character.gen_hit_points(&mut Stage::HitPoints.rng(state), self.hit_point_method);
+        character.gen_personality(&mut Stage::Personality.rng(state));
+        character.gen_languages(&mut Stage::Languages.rng(state));
+        character.gen_deity(&mut Stage::Deity.rng(state));
+        character.gen_alignment(&mut Stage::Alignment.rng(state));
+        let rng = &mut Stage::Proficiencies.rng(state);
+        character.gen_proficiences(rng);
+        character.gen_expertise(rng);
+        character.gen_equipment(&mut Stage::Equipment.rng(state), self.equipment_method);
+        character.gen_spells(&mut Stage::Spells.rng(state));
+        Ok(character)
+    }
+
+    /// Choose the pinned background, or one weighted by the character's skill modifiers
+    fn gen_background(&self, rng: &mut impl Rng, character: &mut Character) -> BackgroundOption {
+        let (abilities, proficiencies) = (&character.abilities, &character.proficiencies);
+        if let Some(background) = self.background {
+            return BackgroundOption::gen_background(
+                rng,
+                abilities,
+                proficiencies,
+                character.proficiency_bonus(),
+                background,
+            );
+        }
+        let background =
+            BackgroundOption::gen(rng, abilities, proficiencies, character.proficiency_bonus());
+        character.record(|c| background_decision(c, &background));
+        background
+    }
+
+    /// Choose the pinned class, or one weighted by the character's ability scores. If only a second class
+    /// is pinned, the first has to be one the character can multiclass out of into it.
+    fn gen_class(
+        &self,
+        rng: &mut impl Rng,
+        character: &mut Character,
+    ) -> Result<ClassOption, ConstraintError> {
+        Ok(match (self.class, self.multiclass) {
+            (Some(class), _) => ClassOption::gen_class(rng, &character.abilities, class),
+            // Make sure the first class can be multiclassed out of, and isn't the second class
+            (None, Some((multiclass, _))) => {
+                let class = ClassOption::gen_multiclass(rng, &character.abilities, &[multiclass])
+                    .ok_or(ConstraintError::Multiclass(multiclass))?;
+                character.record(|c| {
+                    Decision::new(
+                        "Class",
+                        ClassOption::multiclass_candidates(&c.abilities, &[multiclass]),
+                        [ClassType::from(&class)],
+                        format!(
+                            "You're a {class} because you meet the ability score prerequisites to multiclass \
+                            into {multiclass} from it, and {} is its primary ability",
+                            primary_ability(c, &class)
+                        ),
+                    )
+                });
+                class
+            }
+            (None, None) => {
+                let class = ClassOption::gen(rng, &character.abilities);
+                character.record(|c| {
+                    Decision::new(
+                        "Class",
+                        ClassOption::candidates(&c.abilities),
+                        [ClassType::from(&class)],
+                        format!(
+                            "You're a {class} because {} is its primary ability, and no class has a \
+                            primary ability you have a higher modifier for",
+                            primary_ability(c, &class)
+                        ),
+                    )
+                });
+                class
+            }
+        })
+    }
+
+    /// Split the character's levels between their first class and any second class. A second class is
+    /// only chosen at random if neither class was pinned.
+    fn gen_classes(
+        &self,
+        rng: &mut impl Rng,
+        abilities: &AbilityScores,
+        level: u8,
+        class: ClassOption,
+    ) -> Result<Vec<ClassLevels>, ConstraintError> {
+        let class_type = ClassType::from(&class);
+        let multiclass = match self.multiclass {
+            Some((multiclass, multiclass_level)) => {
+                let second = ClassOption::gen_class(rng, abilities, multiclass);
+                if multiclass == class_type
+                    || !class.meets_multiclass_prerequisites(abilities)
+                    || !second.meets_multiclass_prerequisites(abilities)
+                {
+                    return Err(ConstraintError::Multiclass(multiclass));
+                }
+                Some((second, multiclass_level))
+            }
+            None if self.class.is_none() && level > 1 && rng.gen_bool(MULTICLASS_CHANCE) => {
+                ClassOption::gen_multiclass(rng, abilities, &[class_type])
+                    .map(|second| (second, rng.gen_range(1..=level / 2)))
+            }
+            None => None,
+        };
+        Ok(match multiclass {
+            Some((second, multiclass_level)) => vec![
+                ClassLevels::new(class, level - multiclass_level),
+                ClassLevels::new(second, multiclass_level),
+            ],
+            None => vec![ClassLevels::new(class, level)],
+        })
+    }
+
+    /// Order to assign fixed ability scores in: the pinned class's primary abilities, then those of any second
+    /// class, then the pinned class's secondary abilities, then those the race increases most.
+    fn ability_priority(&self, race: &RaceOption) -> Vec<AbilityScoreType> {
+        let (primary, secondary) = self.class.map(ClassType::ability_rank).unwrap_or_default();
+        let mut priority = primary;
+        if let Some((class, _)) = self.multiclass {
+            priority.extend(class.ability_rank().0);
+        }
+        priority.extend(secondary);
+        let mut increases = race.abilities();
+        increases.sort_by_key(|&AbilityScore(_, increase)| Reverse(increase));
+        priority.extend(
+            increases
+                .into_iter()
+                .map(|AbilityScore(ability, _)| ability),
+        );
+        priority
+    }
+
+    /// Look up a pinned deity, along with the pantheon they belong to.
+    fn find_deity<'a>(&self) -> Result<(Option<Pantheon>, Option<Deity<'a>>), ConstraintError> {
+        let Some(name) = &self.deity else {
+            return Ok((self.pantheon, None));
+        };
+        self.pantheon
+            .map_or_else(|| Pantheon::iter().collect(), |p| vec![p])
+            .into_iter()
+            .find_map(|p| p.find_deity(name).map(|d| (Some(p), Some(d))))
+            .ok_or_else(|| ConstraintError::Deity(name.clone()))
+    }
+}
+
+/// The class's primary ability the character has the highest modifier for, such as "WIS (+3)"
+fn primary_ability(character: &Character, class: &ClassOption) -> String {
+    class
+        .ability_rank()
+        .0
+        .into_iter()
+        .map(|a| (a, character.abilities.modifier(a)))
+        .max_by_key(|&(_, modifier)| modifier)
+        .map(|(ability, modifier)| format!("{ability} ({modifier:+})"))
+        .unwrap_or_default()
+}
+
+/// Explain a background choice, which is weighted by the character's best modifier for each background's
+/// skills
+fn background_decision(character: &Character, background: &BackgroundOption) -> Decision {
+    let bonus = character.proficiency_bonus();
+    let (abilities, proficiencies) = (&character.abilities, &character.proficiencies);
+    let best_skill = background
+        .skills()
+        .into_iter()
+        .map(|s: Skill| (s, s.modifier(abilities, proficiencies, bonus)))
+        .max_by_key(|&(_, modifier)| modifier)
+        .map(|(skill, modifier)| format!("{skill} ({modifier:+})"))
+        .unwrap_or_default();
+    let background = BackgroundType::from(background);
+    Decision::new(
+        "Background",
+        BackgroundOption::candidates(abilities, proficiencies, bonus),
+        [background],
+        format!(
+            "Backgrounds are weighted by your best modifier for their skills, which for {background} is \
+            {best_skill}"
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alignment::{Attitude, Morality};
+    use features::Features;
+    use gear::{
+        adventuring_gear::{Gear, OtherGear},
+        currency::Coin,
+        weapons::Weapon,
+    };
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+    use stats::{
+        ability::Skill,
+        equipment::Item,
+        proficiencies::{Proficiency, ProficiencyLevel, WeaponProficiency},
+    };
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_pinned_choices() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .race(RaceType::Dwarf)
+            .subrace(Subrace::Hill)
+            .class(ClassType::Cleric)
+            .background(BackgroundType::Acolyte)
+            .alignment(Alignment(Attitude::Lawful, Morality::Good))
+            .deity("moradin")
+            .gender(Gender::Female)
+            .level(5)
+            .gen(&mut rng)
+            .unwrap();
+        assert_eq!(character.race.unwrap().to_string(), "Hill Dwarf");
+        // Moradin's domains are Knowledge and Forge, and clerics can only serve Knowledge
+        assert_eq!(
+            character.classes[0].class.to_string(),
+            "Cleric, Knowledge Domain"
+        );
+        assert_eq!(character.background.unwrap().to_string(), "Acolyte");
+        assert_eq!(character.alignment.unwrap().to_string(), "Lawful Good");
+        assert_eq!(character.pantheon, Some(Pantheon::Dwarven));
+        assert_eq!(character.deity.unwrap().name, "Moradin");
+        assert_eq!(character.characteristics.unwrap().gender, Gender::Female);
+        assert_eq!(character.level, 5);
+    }
+
+    #[test]
+    fn test_high_level() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .race(RaceType::Dragonborn)
+            .class(ClassType::Fighter)
+            .level(20)
+            .gen(&mut rng)
+            .unwrap();
+        // 10 at 1st level and 6 (average of d10) each level after, allowing for a Constitution penalty
+        assert!(character.hit_points() >= 10 + 19 * 6 - 20);
+        // 7 Ability Score Improvements should max out a Fighter's primary ability unless some were
+        // taken as feats instead, and no further
+        let primary = character.abilities.0[&AbilityScoreType::Strength]
+            .max(character.abilities.0[&AbilityScoreType::Dexterity]);
+        assert!(primary == 20 || !character.feats.is_empty());
+        assert!(character.abilities.0.values().all(|&score| score <= 20));
+        assert!(character.coins.amount(Coin::Gold) >= 20_250);
+        assert!(character.features(character.level).iter().any(|f| f
+            .scaling
+            .as_deref()
+            .unwrap_or_default()
+            .starts_with("5d6")));
+    }
+
+    #[test]
+    fn test_primal_champion() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .race(RaceType::Human)
+            .class(ClassType::Barbarian)
+            .ability_score_method(AbilityScoreMethod::StandardArray)
+            .level(20)
+            .gen(&mut rng)
+            .unwrap();
+        let strength = character.abilities.score(AbilityScoreType::Strength);
+        let constitution = character.abilities.score(AbilityScoreType::Constitution);
+        // Standard array 15 and 14 plus Primal Champion's 4 and any Human increase, before any
+        // Ability Score Improvements. Both can then go past 20, up to Primal Champion's maximum of 24.
+        assert!((19..=24).contains(&strength));
+        assert!((18..=24).contains(&constitution));
+        assert!(strength > 20 || constitution > 20);
+        assert!(character
+            .abilities
+            .0
+            .iter()
+            .filter(|(a, _)| !matches!(
+                a,
+                AbilityScoreType::Strength | AbilityScoreType::Constitution
+            ))
+            .all(|(_, &score)| score <= 20));
+    }
+
+    #[test]
+    fn test_spells() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .class(ClassType::Wizard)
+            .level(5)
+            .gen(&mut rng)
+            .unwrap();
+        let spells = character.classes[0].spells.as_ref().unwrap();
+        assert_eq!(spells.cantrips.len(), 4);
+        assert_eq!(spells.spellbook.len(), 14);
+        assert!(spells
+            .spellbook
+            .iter()
+            .all(|s| (1..=3).contains(&s.level())));
+
+        let character = CharacterBuilder::new()
+            .class(ClassType::Fighter)
+            .gen(&mut rng)
+            .unwrap();
+        assert!(character.classes[0].spells.is_none());
+    }
+
+    #[test]
+    fn test_subclass_level() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .class(ClassType::Fighter)
+            .level(2)
+            .gen(&mut rng)
+            .unwrap();
+        assert_eq!(character.classes[0].class.to_string(), "Fighter");
+        let character = CharacterBuilder::new()
+            .class(ClassType::Fighter)
+            .level(3)
+            .gen(&mut rng)
+            .unwrap();
+        assert!(character.classes[0]
+            .class
+            .to_string()
+            .starts_with("Fighter, "));
+    }
+
+    #[test]
+    fn test_cleric_domain_matches_deity() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        for _ in 0..10 {
+            let character = CharacterBuilder::new()
+                .class(ClassType::Cleric)
+                .gen(&mut rng)
+                .unwrap();
+            let domain = character.classes[0].class.domain();
+            assert!(domain.is_some());
+            assert!(character.deity.unwrap().domains.contains(&domain.unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_multiclass() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .ability_score_method(AbilityScoreMethod::StandardArray)
+            .class(ClassType::Fighter)
+            .multiclass(ClassType::Wizard, 2)
+            .level(5)
+            .gen(&mut rng)
+            .unwrap();
+        let levels = character
+            .classes
+            .iter()
+            .map(|c| (ClassType::from(&c.class), c.level))
+            .collect::<Vec<_>>();
+        assert_eq!(levels, [(ClassType::Fighter, 3), (ClassType::Wizard, 2)]);
+        // Fighter d10 at 1st level, two more at the average of 6, then two Wizard d6 averages of 4
+        assert_eq!(character.hit_die_rolls, [10, 6, 6, 4, 4]);
+        // Only the first class's saving throws
+        assert!(!character
+            .proficiencies
+            .contains(&Proficiency::SavingThrow(AbilityScoreType::Intelligence)));
+        // Wizard 2 casts as a 2nd level spellcaster
+        let spells = character.classes[1].spells.as_ref().unwrap();
+        assert_eq!(spells.cantrips.len(), 3);
+        assert!(spells.spells.iter().all(|s| s.level() == 1));
+        assert!(character
+            .features(character.level)
+            .iter()
+            .any(|f| f.title == "Arcane Recovery"));
+    }
+
+    #[test]
+    fn test_multiclass_random() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut multiclassed = 0;
+        for _ in 0..50 {
+            let character = CharacterBuilder::new().level(10).gen(&mut rng).unwrap();
+            assert_eq!(character.classes.iter().map(|c| c.level).sum::<u8>(), 10);
+            if character.classes.len() > 1 {
+                multiclassed += 1;
+                // Scores only go up after classes are chosen, so prerequisites are still met
+                assert!(character
+                    .classes
+                    .iter()
+                    .all(|c| c.class.meets_multiclass_prerequisites(&character.abilities)));
+            }
+        }
+        assert!(multiclassed > 0);
+    }
+
+    #[test]
+    fn test_expertise() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .class(ClassType::Rogue)
+            .level(6)
+            .gen(&mut rng)
+            .unwrap();
+        assert_eq!(character.expertise.len(), 4);
+        for proficiency in &character.expertise {
+            assert!(character.proficiencies.contains(proficiency));
+            assert_eq!(
+                character.proficiency_level(proficiency),
+                ProficiencyLevel::Expertise
+            );
+        }
+    }
+
+    #[test]
+    fn test_jack_of_all_trades() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .class(ClassType::Bard)
+            .level(2)
+            .gen(&mut rng)
+            .unwrap();
+        let skill = Skill::iter()
+            .find(|&s| !character.proficiencies.contains(&Proficiency::Skill(s)))
+            .unwrap();
+        assert_eq!(
+            character.proficiency_level(&Proficiency::Skill(skill)),
+            ProficiencyLevel::Half
+        );
+        assert_eq!(
+            character.proficiency_level(&Proficiency::Weapon(WeaponProficiency::Specific(
+                Weapon::Greataxe
+            ))),
+            ProficiencyLevel::None
+        );
+    }
+
+    #[test]
+    fn test_variant_human_feat() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .race(RaceType::Human)
+            .subrace(Subrace::Variant)
+            .gen(&mut rng)
+            .unwrap();
+        assert_eq!(character.feats.len(), 1);
+        let feat = &character.feats[0];
+        assert!(character
+            .features(1)
+            .iter()
+            .any(|f| f.title == <&str>::from(feat.feat)));
+    }
+
+    #[test]
+    fn test_hit_points() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .race(RaceType::Dwarf)
+            .subrace(Subrace::Hill)
+            .class(ClassType::Wizard)
+            .level(5)
+            .gen(&mut rng)
+            .unwrap();
+        let constitution = character.abilities.modifier(AbilityScoreType::Constitution);
+        // d6 max at 1st level, average of 4 after, plus Dwarven Toughness
+        assert_eq!(character.hit_points(), 6 + 4 * 4 + 5 * (constitution + 1));
+
+        let character = CharacterBuilder::new()
+            .class(ClassType::Barbarian)
+            .level(20)
+            .hit_point_method(HitPointMethod::Rolled)
+            .gen(&mut rng)
+            .unwrap();
+        assert_eq!(character.hit_die_rolls.len(), 20);
+        assert_eq!(character.hit_die_rolls[0], 12);
+        assert!(character.hit_die_rolls.iter().all(|r| (1..=12).contains(r)));
+    }
+
+    #[test]
+    fn test_starting_gold() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .class(ClassType::Fighter)
+            .background(BackgroundType::Soldier)
+            .equipment_method(EquipmentMethod::Gold)
+            .gen(&mut rng)
+            .unwrap();
+        // 5d4 x 10 gp for a Fighter, plus the Soldier's 10 gp, is spent on a weapon and some gear
+        assert!(character.coins.value_in(Coin::Gold) < 210);
+        assert!(character
+            .equipment
+            .iter()
+            .any(|e| matches!(e.item, Item::Weapon(_)) && e.proficient(&character.proficiencies)));
+        assert!(character
+            .equipment
+            .iter()
+            .any(|e| e.item == Item::Gear(Gear::Other(OtherGear::Backpack))));
+    }
+
+    #[test]
+    fn test_standard_array_follows_class() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .race(RaceType::Human)
+            .class(ClassType::Wizard)
+            .ability_score_method(AbilityScoreMethod::StandardArray)
+            .gen(&mut rng)
+            .unwrap();
+        // Standard array 15 plus any increase from human, which a Variant Human might put elsewhere
+        let increase = character
+            .race
+            .as_ref()
+            .unwrap()
+            .abilities()
+            .into_iter()
+            .filter(|&AbilityScore(a, _)| a == AbilityScoreType::Intelligence)
+            .map(|AbilityScore(_, increase)| increase)
+            .sum::<i16>();
+        assert_eq!(
+            character.abilities.0[&AbilityScoreType::Intelligence],
+            15 + increase
+        );
+    }
+
+    #[test]
+    fn test_pinned_pantheon() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let character = CharacterBuilder::new()
+            .pantheon(Pantheon::Greek)
+            .gen(&mut rng)
+            .unwrap();
+        assert_eq!(character.pantheon, Some(Pantheon::Greek));
+    }
+
+    #[test]
+    fn test_seeded() {
+        let seed = Seed::new(42);
+        let gen = || {
+            CharacterBuilder::new()
+                .class(ClassType::Wizard)
+                .level(5)
+                .gen_seeded(seed)
+                .unwrap()
+        };
+        let character = gen();
+        assert_eq!(character.seed, Some(seed));
+        assert!(character.to_string().contains("SEED: 3-000000000000002a"));
+        assert_eq!(character.to_string(), gen().to_string());
+    }
+
+    #[test]
+    fn test_seeded_snapshot() {
+        // If this changes, seeds no longer generate the same characters, so `GENERATOR_VERSION` must be bumped
+        let character = CharacterBuilder::new()
+            .level(20)
+            .gen_seeded(Seed::new(1))
+            .unwrap();
+        insta::assert_yaml_snapshot!(character);
+    }
+
+    #[test]
+    fn test_trace() {
+        let untraced = CharacterBuilder::new()
+            .level(5)
+            .gen(&mut Pcg64::seed_from_u64(1))
+            .unwrap();
+        assert!(untraced.trace().is_none());
+        let character = CharacterBuilder::new()
+            .level(5)
+            .trace(true)
+            .gen(&mut Pcg64::seed_from_u64(1))
+            .unwrap();
+        // Tracing doesn't change any of the choices
+        assert_eq!(character.to_string(), untraced.to_string());
+
+        let trace = character.trace().unwrap();
+        let picked = |choice: &str| {
+            trace
+                .decisions()
+                .iter()
+                .find(|d| d.choice == choice)
+                .map(|d| d.picked.clone())
+        };
+        let background = BackgroundType::from(character.background.as_ref().unwrap());
+        let class = ClassType::from(&character.classes[0].class);
+        let alignment = character.alignment.as_ref().unwrap();
+        assert_eq!(picked("Background"), Some(vec![background.to_string()]));
+        assert_eq!(picked("Class"), Some(vec![class.to_string()]));
+        assert_eq!(picked("Attitude"), Some(vec![alignment.0.to_string()]));
+        assert_eq!(picked("Morality"), Some(vec![alignment.1.to_string()]));
+        for decision in trace.decisions() {
+            let chance = decision.candidates.iter().map(|c| c.chance).sum::<f64>();
+            assert!((chance - 1.0).abs() < 1e-9);
+        }
+        assert!(trace.to_string().contains(&format!("Class: {class}")));
+    }
+
+    #[test]
+    fn test_independent_stages() {
+        let gen = |method| {
+            CharacterBuilder::new()
+                .level(5)
+                .hit_point_method(method)
+                .gen(&mut Pcg64::seed_from_u64(1))
+                .unwrap()
+        };
+        let average = gen(HitPointMethod::Average);
+        let rolled = gen(HitPointMethod::Rolled);
+        // Rolling hit dice uses more randomness, but doesn't change the stages after it
+        assert_ne!(average.hit_die_rolls, rolled.hit_die_rolls);
+        assert!(average.languages == rolled.languages);
+        assert_eq!(average.proficiencies, rolled.proficiencies);
+        assert!(average.equipment == rolled.equipment);
+        assert_eq!(
+            serde_yaml::to_string(&average.personality).unwrap(),
+            serde_yaml::to_string(&rolled.personality).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_constraints() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        assert_eq!(
+            CharacterBuilder::new().level(21).gen(&mut rng).err(),
+            Some(ConstraintError::Level(21))
+        );
+        assert_eq!(
+            CharacterBuilder::new()
+                .ability_score_method(AbilityScoreMethod::RolledMinimumTotal(109))
+                .gen(&mut rng)
+                .err(),
+            Some(ConstraintError::MinimumTotal(109))
+        );
+        assert_eq!(
+            CharacterBuilder::new()
+                .subrace(Subrace::Hill)
+                .gen(&mut rng)
+                .err(),
+            Some(ConstraintError::MissingRace)
+        );
+        assert_eq!(
+            CharacterBuilder::new()
+                .race(RaceType::Human)
+                .subrace(Subrace::Hill)
+                .gen(&mut rng)
+                .err(),
+            Some(ConstraintError::Subrace(Subrace::Hill))
+        );
+        assert_eq!(
+            CharacterBuilder::new()
+                .pantheon(Pantheon::Greek)
+                .deity("Moradin")
+                .gen(&mut rng)
+                .err(),
+            Some(ConstraintError::Deity("Moradin".to_string()))
+        );
+        assert_eq!(
+            CharacterBuilder::new()
+                .multiclass(ClassType::Rogue, 1)
+                .gen(&mut rng)
+                .err(),
+            Some(ConstraintError::MulticlassLevel(1))
+        );
+        assert_eq!(
+            CharacterBuilder::new()
+                .class(ClassType::Rogue)
+                .multiclass(ClassType::Rogue, 1)
+                .level(2)
+                .gen(&mut rng)
+                .err(),
+            Some(ConstraintError::Multiclass(ClassType::Rogue))
+        );
+    }
+}
@@ -1,30 +1,140 @@
 #![deny(clippy::all)]
 #![warn(clippy::pedantic)]
 
-use std::fmt;
+mod builder;
+mod seed;
+mod trace;
+
+use std::{cmp::Reverse, fmt, iter};
 
 use alignment::{Alignment, AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::Attack;
 use background::BackgroundOption;
 use backstory::Backstory;
 use characteristics::{Appearance, CharacteristicDetails, Speed};
-use class::ClassOption;
+use class::{ClassOption, ClassType};
 use deities::{Deity, Pantheon, Pantheons};
-use features::{Feature, Features};
-use gear::currency::Coin;
-use languages::Language;
+use dice_roller::{Die, RollCmd};
+use feats::{ChosenFeat, Feat, FeatCandidate};
+use features::{Condition, Feature, FeatureEffect, Features, SaveAdvantage, Sense};
+use gear::{
+    adventuring_gear::{ArcaneFocus, DruidicFocus, Gear, HolySymbol, OtherGear},
+    armor::{Armor, ArmorType},
+    currency::{Coin, Purse},
+    damage::DamageType,
+    weapons::{Weapon, WeaponProperty},
+};
+use languages::{Language, Languages};
 use personality::Personality;
-use race::RaceOption;
+use race::{RaceOption, RaceType};
 use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
+use spells::{multiclass_spell_slots, CasterType, Spell, Spellcaster, Spells};
 use stats::{
-    ability::{AbilityScores, Skill},
-    equipment::{Equipment, EquipmentOption},
-    proficiencies::Proficiency,
+    ability::{AbilityScore, AbilityScoreType, AbilityScores, Skill, MAX_SCORE},
+    armor_class::UnarmoredDefense,
+    equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevel, ProficiencyLevels, ProficiencyOption,
+    },
 };
-use strum::IntoEnumIterator;
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 use trinkets::{TrinketOption, Trinkets};
 
+pub use builder::{CharacterBuilder, ConstraintError};
+pub use seed::{Seed, SeedError, GENERATOR_VERSION};
+pub use trace::{Candidate, Decision, Trace};
+
+/// Chance of taking a feat instead of an Ability Score Improvement
+const FEAT_CHANCE: f64 = 0.25;
+
+/// Ways of determining the hit points gained each level after 1st
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum HitPointMethod {
+    /// Take the fixed value (half the hit die plus one)
+    #[default]
+    Average,
+    /// Roll the hit die
+    Rolled,
+}
+
+/// Ways of choosing the starting equipment from the character's first class
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum EquipmentMethod {
+    /// Take the equipment the class provides
+    #[default]
+    Class,
+    /// Roll the class's starting wealth in gold instead
+    Gold,
+}
+
+/// How weighed down a character is under the variant encumbrance rules (PHB p176)
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, PartialEq, Serialize)]
+pub enum Encumbrance {
+    Unencumbered,
+    /// Carrying more than 5 times their Strength score, reducing speed by 10 feet
+    Encumbered,
+    /// Carrying more than 10 times their Strength score, reducing speed by 20 feet and giving disadvantage on
+    /// ability checks, attack rolls, and saving throws that use Strength, Dexterity, or Constitution
+    #[strum(serialize = "Heavily Encumbered")]
+    HeavilyEncumbered,
+}
+
+impl Encumbrance {
+    /// How much each of the character's speeds is reduced by
+    #[must_use]
+    pub fn speed_penalty(self) -> u8 {
+        match self {
+            Self::Unencumbered => 0,
+            Self::Encumbered => 10,
+            Self::HeavilyEncumbered => 20,
+        }
+    }
+}
+
+/// Choices pinned when the character was generated, which rerolling a section keeps
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+struct Pinned {
+    alignment: bool,
+    deity: bool,
+    pantheon: bool,
+}
+
+/// Parts of a character that can be rerolled without changing the rest of the character
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, Eq, PartialEq, Serialize,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum Section {
+    Name,
+    Personality,
+    Languages,
+    Deity,
+    Equipment,
+    Backstory,
+}
+
+/// Levels a character has in one of their classes
+#[derive(Deserialize, Serialize)]
+pub struct ClassLevels {
+    /// The class, including any subclass chosen for it
+    class: ClassOption,
+    /// Levels taken in the class
+    level: u8,
+    /// Cantrips and spells chosen for the class, if it can cast them.
+    spells: Option<Spells>,
+}
+
+impl ClassLevels {
+    fn new(class: ClassOption, level: u8) -> Self {
+        Self {
+            class,
+            level,
+            spells: None,
+        }
+    }
+}
+
 /// Character information. Mostly stores random choices made for this character.
 #[derive(Default, Deserialize, Serialize)]
 pub struct Character<'a> {
@@ -36,15 +146,24 @@ pub struct Character<'a> {
     background: Option<BackgroundOption>,
     /// Characteristics of the character.
     characteristics: Option<CharacteristicDetails>,
-    /// Character's class choice
-    class: Option<ClassOption>,
+    /// Character's classes, starting with the class they took at 1st level
+    classes: Vec<ClassLevels>,
     /// Currency
-    coins: (Coin, u8),
+    coins: Purse,
     /// Character's chosen deity
     #[serde(borrow)]
     deity: Option<Deity<'a>>,
     /// Equipment randomly chosen for the character.
     equipment: Vec<Equipment>,
+    /// Whether the character took their class's equipment or rolled starting gold instead.
+    #[serde(default)]
+    equipment_method: EquipmentMethod,
+    /// Skills and tools the character has expertise in, doubling their proficiency bonus.
+    expertise: Vec<Proficiency>,
+    /// Feats taken from the character's race or in place of Ability Score Improvements.
+    feats: Vec<ChosenFeat>,
+    /// Result of the hit die for each level (the maximum at 1st level).
+    hit_die_rolls: Vec<i16>,
     /// Languages randomly chosen for the character.
     languages: Vec<Language>,
     /// Current level of the character.
@@ -55,73 +174,299 @@ pub struct Character<'a> {
     pantheon: Option<Pantheon>,
     /// Personality traits of the chracacter.
     personality: Option<Personality>,
+    /// Choices pinned when the character was generated.
+    #[serde(default)]
+    pinned: Pinned,
     /// Proficiencies for the character.
     proficiencies: Vec<Proficiency>,
     /// Race randomly chosen for the character.
     race: Option<RaceOption>,
+    /// Sections rerolled since the character was generated, in order.
+    #[serde(default)]
+    rerolls: Vec<Section>,
+    /// Seed the character was generated from, if it can be generated again.
+    seed: Option<Seed>,
+    /// Weighted choices made while generating the character, if they were traced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    trace: Option<Trace>,
+    /// Whether the variant encumbrance rules (PHB p176) slow the character down for the weight they carry.
+    variant_encumbrance: bool,
 }
 
 impl<'a> Character<'a> {
     /// Generate a new random character
     ///
     /// The methodolgy is to gather together as many static inputs as possible (based on some initial random choices),
-    /// and then have those feed into later choices.
+    /// and then have those feed into later choices. See `CharacterBuilder::gen` for the individual steps, or to
+    /// generate a character with some of the choices already made.
+    ///
+    /// # Panics
     ///
-    /// Steps are as follows:
-    /// 1. Randomly choose a Race (which also generates a name and some physical characteristics)
-    /// 2. Roll ability scores (and apply the racial ability increases)
-    /// 3. Randomly choose a background and personality traits (weighted by highest skill modifiers)
-    /// 4. Choose alignment (weighted based on inputs from race and background)
-    /// 5. Randomly choose any additional languages
-    /// 6. Randomly choose proficiencies, weighted towards optimal ones based on what is known about the character so far
+    /// Will panic if the character can't be generated, which shouldn't happen without any constraints.
     pub fn gen(rng: &mut impl Rng) -> Self {
-        let mut character = Self {
-            level: 1,
-            ..Self::default()
+        CharacterBuilder::new()
+            .gen(rng)
+            .expect("an unconstrained character can always be generated")
+    }
+
+    /// Weighted choices made while generating the character, if the builder was asked to trace them
+    #[must_use]
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_ref()
+    }
+
+    /// Add a decision to the trace. The decision is only worked out if the character is being traced.
+    fn record(&mut self, decision: impl FnOnce(&Self) -> Decision) {
+        if self.trace.is_some() {
+            let decision = decision(self);
+            if let Some(trace) = self.trace.as_mut() {
+                trace.push(decision);
+            }
+        }
+    }
+
+    /// Generate one section of the character again, keeping everything else.
+    ///
+    /// Anything that depends on the section is kept consistent with it: a new personality or deity also
+    /// chooses a new alignment (since both influence it), and new equipment replaces the character's coins
+    /// as well, using the same choice of class equipment or starting gold. An alignment, pantheon or deity
+    /// pinned when the character was generated is always kept.
+    pub fn reroll(&mut self, rng: &mut impl Rng, section: Section) {
+        match section {
+            Section::Backstory => {
+                if let Some(race) = self.race.as_mut() {
+                    race.reroll_backstory(rng);
+                }
+                if let Some(background) = self.background.as_mut() {
+                    background.reroll_backstory(rng);
+                }
+            }
+            Section::Deity => {
+                if !self.pinned.pantheon {
+                    self.pantheon = None;
+                }
+                if !self.pinned.deity {
+                    self.deity = None;
+                }
+                self.gen_deity(rng);
+                self.reroll_alignment(rng);
+            }
+            Section::Equipment => {
+                self.equipment.clear();
+                self.coins = Purse::default();
+                self.gen_equipment(rng, self.equipment_method);
+            }
+            Section::Languages => {
+                self.languages.clear();
+                self.gen_languages(rng);
+            }
+            Section::Name => {
+                if let (Some(race), Some(characteristics)) =
+                    (self.race.as_ref(), self.characteristics.as_ref())
+                {
+                    self.name = race.gen_name(rng, characteristics);
+                }
+            }
+            Section::Personality => {
+                self.gen_personality(rng);
+                self.reroll_alignment(rng);
+            }
+        }
+        self.rerolls.push(section);
+    }
+
+    /// Choose a new alignment, unless it was pinned
+    fn reroll_alignment(&mut self, rng: &mut impl Rng) {
+        if !self.pinned.alignment {
+            self.alignment = None;
+            self.gen_alignment(rng);
+        }
+    }
+
+    /// Highest score an ability can be raised to: 20, unless a racial or class feature raises it
+    fn ability_maximum(&self, ability: AbilityScoreType) -> i16 {
+        self.classes
+            .iter()
+            .flat_map(|c| c.class.ability_maximums(c.level))
+            .chain(self.race.iter().flat_map(RaceOption::ability_maximums))
+            .filter(|&(a, _)| a == ability)
+            .map(|(_, max)| max)
+            .fold(MAX_SCORE, i16::max)
+    }
+
+    /// Raise an ability score, up to its maximum
+    fn increase_ability(&mut self, AbilityScore(ability, val): AbilityScore) {
+        let max = self.ability_maximum(ability);
+        self.abilities
+            .increase(vec![AbilityScore(ability, val)], max);
+    }
+
+    /// Apply the Ability Score Improvements each class has gained at the character's level in it, raising the
+    /// starting class's primary abilities first, then secondary abilities, one point at a time.
+    /// Within each group, the highest score is raised first, and no score is raised above its maximum.
+    ///
+    /// Any feats granted by the race are taken first, then any increases from class features (such as a
+    /// Barbarian's Primal Champion). Each improvement is sometimes replaced with a feat instead, and always
+    /// is once the most important ability is already at its maximum.
+    fn gen_ability_score_improvements(&mut self, rng: &mut impl Rng) {
+        let (primary, secondary) = self
+            .starting_class()
+            .map(ClassOption::ability_rank)
+            .unwrap_or_default();
+        let score = |a: &AbilityScoreType| self.abilities.0.get(a).copied().unwrap_or_default();
+        let priority = [primary, secondary, AbilityScoreType::iter().collect()]
+            .into_iter()
+            .flat_map(|mut group| {
+                group.sort_by_key(|a| Reverse(score(a)));
+                group
+            })
+            .collect::<Vec<_>>();
+        for _ in 0..self
+            .race
+            .as_ref()
+            .map(RaceOption::feats)
+            .unwrap_or_default()
+        {
+            self.gen_feat(rng, &priority);
+        }
+        let class_increases = self
+            .classes
+            .iter()
+            .flat_map(|c| c.class.abilities(c.level))
+            .collect::<Vec<_>>();
+        for increase in class_increases {
+            self.increase_ability(increase);
+        }
+        let improvements = self
+            .classes
+            .iter()
+            .map(|c| {
+                c.class
+                    .ability_score_improvement_levels()
+                    .into_iter()
+                    .filter(|&l| l <= c.level)
+                    .count()
+            })
+            .sum::<usize>();
+        for _ in 0..improvements {
+            let maxed = priority
+                .first()
+                .is_some_and(|&a| self.abilities.score(a) >= self.ability_maximum(a));
+            if (maxed || rng.gen_bool(FEAT_CHANCE)) && self.gen_feat(rng, &priority) {
+                continue;
+            }
+            for _ in 0..2 {
+                if let Some(&ability) = priority
+                    .iter()
+                    .find(|&&a| self.abilities.score(a) < self.ability_maximum(a))
+                {
+                    self.increase_ability(AbilityScore(ability, 1));
+                }
+            }
+        }
+    }
+
+    /// The class the character took at 1st level, which determines their starting proficiencies and equipment
+    fn starting_class(&self) -> Option<&ClassOption> {
+        self.classes.first().map(|c| &c.class)
+    }
+
+    /// Proficiencies from every class, along with any additional proficiencies to choose. Only the
+    /// starting class grants all of its proficiencies; later classes grant their multiclass proficiencies.
+    fn class_proficiencies(&self) -> (Vec<Proficiency>, Vec<ProficiencyOption>) {
+        let mut proficiencies = vec![];
+        let mut addl_proficiencies = vec![];
+        for (i, ClassLevels { class, .. }) in self.classes.iter().enumerate() {
+            if i == 0 {
+                proficiencies.extend(class.proficiencies());
+                addl_proficiencies.extend(class.addl_proficiencies());
+            } else {
+                proficiencies.extend(class.multiclass_proficiencies());
+                addl_proficiencies.extend(class.multiclass_addl_proficiencies());
+            }
+        }
+        (proficiencies, addl_proficiencies)
+    }
+
+    /// Spellcasting for each class that can cast spells, along with the character's level in that class
+    fn spellcasters(&self) -> Vec<(Spellcaster, u8)> {
+        self.classes
+            .iter()
+            .filter_map(|c| c.class.spellcasting().map(|s| (s, c.level)))
+            .collect()
+    }
+
+    /// Take a feat the character qualifies for, applying any ability score increase it grants.
+    /// Returns whether a feat was available.
+    fn gen_feat(&mut self, rng: &mut impl Rng, priority: &[AbilityScoreType]) -> bool {
+        let mut proficiencies = vec![];
+        if let Some(race) = self.race.as_ref() {
+            proficiencies.extend(race.proficiencies());
+        }
+        if let Some(background) = self.background.as_ref() {
+            proficiencies.extend(background.proficiencies());
+        }
+        proficiencies.extend(self.class_proficiencies().0);
+        proficiencies.extend(self.feats.iter().flat_map(Proficiencies::proficiencies));
+        let candidate = FeatCandidate {
+            ability_scores: &self.abilities,
+            proficiencies: &proficiencies,
+            race: self.race.as_ref().map(RaceType::from),
+            size: self.characteristics.as_ref().map(|c| &c.size),
+            spellcasting: self.spellcasters().iter().any(|(s, level)| {
+                s.cantrips_known(*level) > 0 || s.spell_slots(*level).iter().any(|&slots| slots > 0)
+            }),
+            subrace: self.race.as_ref().and_then(RaceOption::subrace),
         };
-        let race = RaceOption::gen(rng);
-        let characteristics = race.gen_characteristics(rng);
-        let name = race.gen_name(rng, &characteristics);
-        let mut abilities = AbilityScores::gen(rng);
-        abilities.increase(race.abilities());
-        character.abilities = abilities;
-        let background = BackgroundOption::gen(
-            rng,
-            &character.abilities,
-            &character.proficiencies,
-            character.proficiency_bonus(),
-        );
-        let class = ClassOption::gen(rng, &character.abilities);
-        character.race = Some(race);
-        character.name = name;
-        character.characteristics = Some(characteristics);
-        character.background = Some(background);
-        println!("{}", class);
-        character.class = Some(class);
-        character.gen_personality(rng);
-        character.gen_languages(rng);
-        character.gen_deity(rng);
-        character.gen_alignment(rng);
-        character.gen_proficiences(rng);
-        character.gen_equipment(rng);
-        character
+        let Some(feat) = Feat::gen(rng, &candidate, &self.feats, priority) else {
+            return false;
+        };
+        if let Some(ability) = feat.ability {
+            self.increase_ability(AbilityScore(ability, 1));
+        }
+        self.feats.push(feat);
+        true
     }
 
     /// Generate character's alignment, feeding in any inputs we have for attitude and morality.
+    /// Keeps an alignment that has already been chosen.
     fn gen_alignment(&mut self, rng: &mut impl Rng) {
-        self.alignment = Some(Alignment::gen(rng, &self.attitude(), &self.morality()));
+        if self.alignment.is_none() {
+            let (attitudes, moralities) = (self.attitude(), self.morality());
+            let alignment = Alignment::gen(rng, &attitudes, &moralities);
+            self.record(|_| {
+                Decision::new(
+                    "Attitude",
+                    Attitude::iter().map(|a| (a, a.weight(&attitudes))),
+                    [alignment.0],
+                    influence_reason(&attitudes),
+                )
+            });
+            self.record(|_| {
+                Decision::new(
+                    "Morality",
+                    Morality::iter().map(|m| (m, m.weight(&moralities))),
+                    [alignment.1],
+                    influence_reason(&moralities),
+                )
+            });
+            self.alignment = Some(alignment);
+        }
     }
 
-    /// Generate a character's pantheon and Deity
+    /// Generate a character's pantheon and Deity. Keeps a pantheon or deity that has already been chosen.
     fn gen_deity(&mut self, rng: &mut impl Rng) {
+        if self.deity.is_some() {
+            return;
+        }
         let mut addl_pantheons = vec![];
         let mut required = vec![];
-        let domain = None;
+        let domain = self.classes.iter().find_map(|c| c.class.domain());
         if let Some(race) = self.race.as_ref() {
             addl_pantheons.extend(race.addl_pantheons());
             required.push(race.deity_required());
         }
-        if let Some(class) = self.class.as_ref() {
+        for ClassLevels { class, .. } in &self.classes {
             addl_pantheons.extend(class.addl_pantheons());
             required.push(class.deity_required());
         }
@@ -130,23 +475,37 @@ impl<'a> Character<'a> {
             required.push(background.deity_required());
         }
         addl_pantheons.extend(self.languages.iter().flat_map(Pantheons::addl_pantheons));
-        let pantheon = Pantheon::choose(rng, addl_pantheons, domain, required.contains(&true));
-        self.pantheon = Some(pantheon);
+        let pantheon = *self.pantheon.get_or_insert_with(|| {
+            Pantheon::choose(rng, addl_pantheons, domain, required.contains(&true))
+        });
         self.deity = pantheon.choose_deity(rng, &self.attitude(), &self.morality(), domain);
     }
 
-    /// Generate any additional equipment.
-    fn gen_equipment(&mut self, rng: &mut impl Rng) {
+    /// Generate any additional equipment. Starting gold can be rolled in place of the first class's equipment,
+    /// but background equipment is always given.
+    fn gen_equipment(&mut self, rng: &mut impl Rng, method: EquipmentMethod) {
+        self.equipment_method = method;
         // Choose a trinket
         let mut addl_equipment = vec![EquipmentOption::Trinket(None, None, true)];
 
-        if let Some(class) = self.class.as_ref() {
-            self.coins = class.coins();
-            self.equipment.extend(class.equipment());
-            addl_equipment.extend(class.addl_equipment());
+        if let Some(ClassLevels { class, .. }) = self.classes.first() {
+            match method {
+                EquipmentMethod::Class => {
+                    let (coin, amount) = class.coins();
+                    self.coins.add(coin, amount.into());
+                    self.equipment.extend(class.equipment());
+                    addl_equipment.extend(class.addl_equipment());
+                }
+                EquipmentMethod::Gold => {
+                    let (roll, multiplier) = class.starting_wealth();
+                    let gold = u32::try_from(roll.roll(rng).total()).unwrap();
+                    self.coins.add(Coin::Gold, gold * multiplier);
+                }
+            }
         }
         if let Some(background) = self.background.as_ref() {
-            self.coins = background.coins();
+            let (coin, amount) = background.coins();
+            self.coins.add(coin, amount.into());
             self.equipment.extend(background.equipment());
             addl_equipment.extend(background.addl_equipment());
         }
@@ -163,6 +522,219 @@ impl<'a> Character<'a> {
             ));
         }
         self.equipment.sort();
+        self.gen_level_wealth(rng);
+        if method == EquipmentMethod::Gold {
+            self.go_shopping(rng);
+        }
+    }
+
+    /// Characters starting above 1st level get additional gold based on their tier (DMG p38).
+    fn gen_level_wealth(&mut self, rng: &mut impl Rng) {
+        let (base, multiplier) = match self.level {
+            0..=4 => return,
+            5..=10 => (500, 25),
+            11..=16 => (5000, 250),
+            17..=u8::MAX => (20000, 250),
+        };
+        let roll = u32::try_from(RollCmd(1, Die::D10).roll(rng).total()).unwrap();
+        self.coins.add(Coin::Gold, base + roll * multiplier);
+    }
+
+    /// Spend the character's coins on gear they are missing: a weapon they are proficient with and its
+    /// ammunition, a spellcasting focus (and spellbook) for their class, an adventuring pack, and the best
+    /// armor and shield they can afford if they improve their Armor Class.
+    ///
+    /// Useful for characters who started with gold instead of equipment, or to restock between adventures.
+    pub fn go_shopping(&mut self, rng: &mut impl Rng) {
+        self.buy_weapon();
+        self.buy_ammunition();
+        self.buy_spellcasting_gear(rng);
+        self.buy_pack();
+        self.buy_armor();
+        self.equipment.sort();
+    }
+
+    /// Whether the character has enough money to buy an item
+    fn can_afford(&self, item: &Item) -> bool {
+        item.cost()
+            .is_some_and(|(coin, price)| coin.copper_value() * price <= self.coins.copper_value())
+    }
+
+    /// Pay for an item and add it to the character's equipment, if they can afford it
+    fn buy(&mut self, item: Item, amount: usize) {
+        if let Some((coin, price)) = item.cost() {
+            if self.coins.spend(coin, price) {
+                self.equipment.push(Equipment::new(item, amount));
+            }
+        }
+    }
+
+    /// Buy the affordable weapon the character would deal the most damage with, unless they already have a
+    /// weapon they are proficient with.
+    fn buy_weapon(&mut self) {
+        let has_weapon = self
+            .equipment
+            .iter()
+            .any(|e| matches!(e.item, Item::Weapon(_)) && e.proficient(&self.proficiencies));
+        if has_weapon {
+            return;
+        }
+        let weapon = Weapon::iter()
+            .filter(|&weapon| {
+                let item = Item::Weapon(weapon);
+                !weapon.properties().contains(&WeaponProperty::Special)
+                    && self.can_afford(&item)
+                    && Equipment::new(item, 1).proficient(&self.proficiencies)
+            })
+            .max_by_key(|&weapon| {
+                let attack = Attack::weapon(
+                    weapon,
+                    &self.abilities,
+                    &self.proficiencies,
+                    self.proficiency_bonus(),
+                );
+                // Twice the average damage, to keep it a whole number
+                let damage = attack.damage.map_or(0, |d| {
+                    d.modifier * 2
+                        + d.roll.map_or(0, |RollCmd(dice, die)| {
+                            i16::try_from(dice).unwrap() * (die as i16 + 1)
+                        })
+                });
+                let (coin, price) = weapon.cost();
+                (damage, Reverse(coin.copper_value() * price))
+            });
+        if let Some(weapon) = weapon {
+            self.buy(Item::Weapon(weapon), 1);
+        }
+    }
+
+    /// Buy ammunition for any weapons that need it, if the character has run out
+    fn buy_ammunition(&mut self) {
+        let mut needed = self
+            .equipment
+            .iter()
+            .filter_map(|e| match e.item {
+                Item::Weapon(weapon) => weapon.default_ammunition(),
+                _ => None,
+            })
+            .map(|(ammunition, _)| ammunition)
+            .collect::<Vec<_>>();
+        needed.sort();
+        needed.dedup();
+        for ammunition in needed {
+            let item = Item::Ammunition(ammunition.clone());
+            if !self.equipment.iter().any(|e| e.item == item) {
+                self.buy(item, ammunition.default_amount());
+            }
+        }
+    }
+
+    /// Buy a spellcasting focus suited to the character's classes if they don't have one, and a spellbook for
+    /// wizards.
+    fn buy_spellcasting_gear(&mut self, rng: &mut impl Rng) {
+        let classes = self
+            .classes
+            .iter()
+            .map(|c| ClassType::from(&c.class))
+            .collect::<Vec<_>>();
+        let has_focus = self.equipment.iter().any(|e| {
+            matches!(
+                e.item,
+                Item::Gear(
+                    Gear::ArcaneFocus(_)
+                        | Gear::DruidicFocus(_)
+                        | Gear::HolySymbol(_)
+                        | Gear::Other(OtherGear::ComponentPouch)
+                )
+            )
+        });
+        if !has_focus {
+            let focuses = classes
+                .iter()
+                .flat_map(|class| match class {
+                    ClassType::Cleric | ClassType::Paladin => {
+                        HolySymbol::iter().map(Gear::HolySymbol).collect()
+                    }
+                    ClassType::Druid => DruidicFocus::iter().map(Gear::DruidicFocus).collect(),
+                    ClassType::Sorcerer | ClassType::Warlock | ClassType::Wizard => {
+                        ArcaneFocus::iter().map(Gear::ArcaneFocus).collect()
+                    }
+                    _ => vec![],
+                })
+                .map(Item::Gear)
+                .filter(|item| self.can_afford(item))
+                .collect::<Vec<_>>();
+            if let Some(focus) = focuses.choose(rng) {
+                self.buy(focus.clone(), 1);
+            }
+        }
+        let spellbook = Item::Gear(Gear::Other(OtherGear::Spellbook));
+        if classes.contains(&ClassType::Wizard)
+            && !self.equipment.iter().any(|e| e.item == spellbook)
+        {
+            self.buy(spellbook, 1);
+        }
+    }
+
+    /// Buy an explorer's pack, unless the character already has a backpack
+    fn buy_pack(&mut self) {
+        let backpack = Item::Gear(Gear::Other(OtherGear::Backpack));
+        if self.equipment.iter().any(|e| e.item == backpack) {
+            return;
+        }
+        let (coin, price) = Pack::Explorer.cost();
+        if self.coins.spend(coin, price) {
+            self.equipment.extend(Pack::Explorer.equipment());
+        }
+    }
+
+    /// Buy the body armor, and then the shield, that most improve the character's Armor Class out of those they
+    /// are proficient with, can afford, and are strong enough to wear without being slowed. A shield isn't
+    /// bought if all of the character's weapons need two hands.
+    fn buy_armor(&mut self) {
+        let two_handed = self
+            .equipment
+            .iter()
+            .filter_map(|e| match e.item {
+                Item::Weapon(weapon) => Some(weapon),
+                _ => None,
+            })
+            .map(|weapon| weapon.properties().contains(&WeaponProperty::TwoHanded))
+            .reduce(|a, b| a && b)
+            .unwrap_or_default();
+        for shield in [false, true] {
+            if shield && two_handed {
+                continue;
+            }
+            let strength = self.abilities.score(AbilityScoreType::Strength);
+            let candidates = Armor::iter()
+                .filter(|armor| {
+                    (armor.armor_type() == ArmorType::Shield) == shield
+                        && self
+                            .proficiencies
+                            .contains(&Proficiency::Armor(armor.armor_type()))
+                        && armor
+                            .strength_requirement()
+                            .is_none_or(|requirement| strength >= requirement)
+                        && self.can_afford(&Item::Armor(*armor))
+                })
+                .collect::<Vec<_>>();
+            let current = self.armor_class().0;
+            let mut best = None;
+            for armor in candidates {
+                self.equipment.push(Equipment::new(Item::Armor(armor), 1));
+                let ac = self.armor_class().0;
+                self.equipment.pop();
+                let (coin, price) = armor.cost();
+                let key = (ac, Reverse(coin.copper_value() * price));
+                if ac > current && best.as_ref().is_none_or(|(k, _)| key > *k) {
+                    best = Some((key, armor));
+                }
+            }
+            if let Some((_, armor)) = best {
+                self.buy(Item::Armor(armor), 1);
+            }
+        }
     }
 
     /// Generate any additional languages, ensuring no overlap with current languages.
@@ -174,7 +746,7 @@ impl<'a> Character<'a> {
             languages.extend(race.languages());
             addl_languages.push(race.addl_languages());
         }
-        if let Some(class) = self.class.as_ref() {
+        for ClassLevels { class, .. } in &self.classes {
             languages.extend(class.languages());
             addl_languages.push(class.addl_languages());
         }
@@ -182,6 +754,10 @@ impl<'a> Character<'a> {
             languages.extend(background.languages());
             addl_languages.push(background.addl_languages());
         }
+        for feat in &self.feats {
+            languages.extend(feat.languages());
+            addl_languages.push(feat.addl_languages());
+        }
 
         // Handle any dupes across these options
         for l in languages {
@@ -260,9 +836,21 @@ impl<'a> Character<'a> {
             proficiencies.extend(background.proficiencies());
             addl_proficiencies.extend(background.addl_proficiencies());
         }
-        if let Some(class) = self.class.as_ref() {
-            proficiencies.extend(class.proficiencies());
-            addl_proficiencies.extend(class.addl_proficiencies());
+        let (class_proficiencies, class_addl_proficiencies) = self.class_proficiencies();
+        proficiencies.extend(class_proficiencies);
+        addl_proficiencies.extend(class_addl_proficiencies);
+        for feat in &self.feats {
+            proficiencies.extend(feat.proficiencies());
+            addl_proficiencies.extend(feat.addl_proficiencies());
+        }
+        // Features that grant a proficiency the character already has (such as Diamond Soul's saving
+        // throws) don't offer a replacement, so skip them rather than treating them as dupes
+        for effect in self.effects(self.level) {
+            if let FeatureEffect::Proficiency(p) = effect {
+                if !proficiencies.contains(&p) {
+                    proficiencies.push(p);
+                }
+            }
         }
         // Handle any dupes across these options
         self.add_or_replace_proficiencies(rng, proficiencies);
@@ -276,11 +864,400 @@ impl<'a> Character<'a> {
                 &self.proficiencies,
                 self.proficiency_bonus(),
             );
+            if let ProficiencyOption::Skill(skills, _) = &option {
+                self.record(|c| c.skill_decision(skills.as_deref(), &choices));
+            }
             self.add_or_replace_proficiencies(rng, choices);
         }
         self.proficiencies.sort();
     }
 
+    /// Explain a choice of skill proficiencies, which are weighted by the character's modifier for each skill
+    fn skill_decision(&self, skills: Option<&[Skill]>, choices: &[Proficiency]) -> Decision {
+        let bonus = self.proficiency_bonus();
+        let candidates = skills
+            .map_or_else(|| Skill::iter().collect(), <[Skill]>::to_vec)
+            .into_iter()
+            .filter(|&s| !s.proficient(&self.proficiencies))
+            .map(|s| (s, s.weight(&self.abilities, &self.proficiencies, bonus)))
+            .collect::<Vec<_>>();
+        let picked = choices
+            .iter()
+            .filter_map(|p| match p {
+                Proficiency::Skill(s) => Some(*s),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let modifiers = picked
+            .iter()
+            .map(|s| {
+                let modifier = s.modifier(&self.abilities, &self.proficiencies, bonus);
+                format!("{s} ({modifier:+})")
+            })
+            .collect::<Vec<_>>();
+        Decision::new(
+            "Skill Proficiencies",
+            candidates,
+            picked,
+            format!(
+                "Skills with higher modifiers are more likely, picking {}",
+                modifiers.join(", ")
+            ),
+        )
+    }
+
+    /// Choose skills and tools to double the proficiency bonus for, from the proficiencies the character
+    /// already has, for each class and feat that grants expertise.
+    fn gen_expertise(&mut self, rng: &mut impl Rng) {
+        let options = self
+            .classes
+            .iter()
+            .flat_map(|c| c.class.expertise(c.level))
+            .chain(self.feats.iter().flat_map(|f| f.expertise(self.level)))
+            .collect::<Vec<_>>();
+        for option in options {
+            let choices = option.gen(
+                rng,
+                &self.abilities,
+                &self.proficiencies,
+                &self.expertise,
+                self.proficiency_bonus(),
+            );
+            self.expertise.extend(choices);
+        }
+        self.expertise.sort();
+    }
+
+    /// Determine the hit die result for each level, using the hit die of the class the level was taken in:
+    /// the maximum at 1st level, and then either the average or a roll for each level after.
+    fn gen_hit_points(&mut self, rng: &mut impl Rng, method: HitPointMethod) {
+        let hit_dice = self
+            .classes
+            .iter()
+            .flat_map(|c| iter::repeat_n(c.class.hit_die(), c.level.into()))
+            .collect::<Vec<_>>();
+        self.hit_die_rolls = hit_dice
+            .into_iter()
+            .enumerate()
+            .map(|(i, hit_die)| {
+                let max = hit_die as i16;
+                match (i, method) {
+                    (0, _) => max,
+                    (_, HitPointMethod::Average) => max / 2 + 1,
+                    (_, HitPointMethod::Rolled) => {
+                        i16::try_from(RollCmd(1, hit_die).roll(rng).total()).unwrap()
+                    }
+                }
+            })
+            .collect();
+    }
+
+    /// Choose a subclass for each class the character is high enough level in, weighted towards the skills their
+    /// background gives them. Clerics serve a domain held by their pinned deity or pantheon, if any.
+    fn gen_subclass(&mut self, rng: &mut impl Rng) {
+        let proficiencies = self
+            .background
+            .as_ref()
+            .map(BackgroundOption::proficiencies)
+            .unwrap_or_default();
+        let domains = match (&self.deity, self.pantheon) {
+            (Some(deity), _) => deity.domains.clone(),
+            (None, Some(pantheon)) => pantheon
+                .deities(None)
+                .into_iter()
+                .flat_map(|d| d.domains)
+                .collect(),
+            (None, None) => vec![],
+        };
+        for ClassLevels { class, level, .. } in &mut self.classes {
+            class.gen_subclass(rng, *level, &self.abilities, &proficiencies, &domains);
+        }
+    }
+
+    /// Choose cantrips and spells for each of the character's classes that can cast them, as if they
+    /// only had levels in that class
+    fn gen_spells(&mut self, rng: &mut impl Rng) {
+        for ClassLevels {
+            class,
+            level,
+            spells,
+        } in &mut self.classes
+        {
+            *spells = class
+                .spellcasting()
+                .map(|spellcaster| spellcaster.gen(rng, *level, &self.abilities));
+        }
+    }
+
+    /// Armor from the character's equipment that they are proficient with
+    fn proficient_armor(&self) -> impl Iterator<Item = Armor> + '_ {
+        self.equipment
+            .iter()
+            .filter_map(|e| match e.item {
+                Item::Armor(armor) => Some(armor),
+                _ => None,
+            })
+            .filter(|armor| {
+                self.proficiencies
+                    .contains(&Proficiency::Armor(armor.armor_type()))
+            })
+    }
+
+    /// Every way the character could calculate their Armor Class: the total, a description, the body
+    /// armor being worn (if any), and whether a shield is wielded.
+    fn armor_class_options(&self) -> Vec<(i16, String, Option<Armor>, bool)> {
+        let shield = self
+            .proficient_armor()
+            .find(|armor| armor.armor_type() == ArmorType::Shield);
+        let with_shield = |ac: i16, name: String, allowed: bool| match shield {
+            Some(shield) if allowed => {
+                (ac + shield.armor_class(), format!("{name}, {shield}"), true)
+            }
+            _ => (ac, name, false),
+        };
+        let dexterity = self.abilities.modifier(AbilityScoreType::Dexterity);
+
+        let mut defenses = vec![UnarmoredDefense::unarmored()];
+        defenses.extend(self.race.as_ref().and_then(RaceOption::unarmored_defense));
+        defenses.extend(
+            self.classes
+                .iter()
+                .filter_map(|c| c.class.unarmored_defense()),
+        );
+
+        let mut options = defenses
+            .into_iter()
+            .map(|defense| {
+                let (ac, name, shield) = with_shield(
+                    defense.armor_class(&self.abilities),
+                    defense.name.to_string(),
+                    defense.shield,
+                );
+                (ac, name, None, shield)
+            })
+            .collect::<Vec<_>>();
+        options.extend(
+            self.proficient_armor()
+                .filter(|armor| armor.armor_type() != ArmorType::Shield)
+                .map(|armor| {
+                    let dex = armor
+                        .max_dex_modifier()
+                        .map_or(dexterity, |max| dexterity.min(max));
+                    let (ac, name, shield) =
+                        with_shield(armor.armor_class() + dex, armor.to_string(), true);
+                    (ac, name, Some(armor), shield)
+                }),
+        );
+        options
+    }
+
+    /// Armor Class option the character uses, preferring not to wear armor if it makes no difference.
+    fn best_armor_class_option(&self) -> (i16, String, Option<Armor>, bool) {
+        self.armor_class_options()
+            .into_iter()
+            .max_by_key(|(ac, _, armor, _)| (*ac, armor.is_none()))
+            .unwrap()
+    }
+
+    /// Highest Armor Class available to the character, with a description and the body armor being worn (if any).
+    fn armor_class(&self) -> (i16, String, Option<Armor>) {
+        let (ac, name, armor, _) = self.best_armor_class_option();
+        (ac, name, armor)
+    }
+
+    /// Attacks with each weapon in the character's equipment, followed by any racial attacks
+    fn attacks(&self) -> Vec<Attack> {
+        let mut attacks = self
+            .equipment
+            .iter()
+            .filter_map(|e| match e.item {
+                Item::Weapon(weapon) => Some(Attack::weapon(
+                    weapon,
+                    &self.abilities,
+                    &self.proficiencies,
+                    self.proficiency_bonus(),
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if let Some(race) = self.race.as_ref() {
+            attacks.extend(race.attacks(self.level, &self.abilities, self.proficiency_bonus()));
+        }
+        attacks
+    }
+
+    /// Maximum hit points, adding the Constitution modifier and any racial, class or feat bonus to each hit die result.
+    fn hit_points(&self) -> i16 {
+        let constitution = self.abilities.modifier(AbilityScoreType::Constitution);
+        let per_level = self
+            .race
+            .as_ref()
+            .map(RaceOption::hit_points_per_level)
+            .unwrap_or_default()
+            + self
+                .feats
+                .iter()
+                .map(|f| f.feat.hit_points_per_level())
+                .sum::<i16>();
+        // Class bonuses only apply to levels in that class
+        let class_bonus = self
+            .classes
+            .iter()
+            .map(|c| c.class.hit_points_per_level() * i16::from(c.level))
+            .sum::<i16>();
+        self.hit_die_rolls
+            .iter()
+            // Always gain at least one hit point per level
+            .map(|roll| (roll + constitution).max(1) + per_level)
+            .sum::<i16>()
+            + class_bonus
+    }
+
+    /// How much of the proficiency bonus the character adds to checks with a skill or tool.
+    /// Without proficiency, the best half proficiency from their classes that applies is used.
+    fn proficiency_level(&self, proficiency: &Proficiency) -> ProficiencyLevel {
+        if self.expertise.contains(proficiency) {
+            return ProficiencyLevel::Expertise;
+        }
+        if self.proficiencies.contains(proficiency) {
+            return ProficiencyLevel::Proficient;
+        }
+        match proficiency {
+            Proficiency::Skill(skill) => self.half_proficiency(Some(skill.ability_score_type())),
+            Proficiency::Tool(_) => self.half_proficiency(None),
+            // Only ability checks benefit from half proficiency
+            Proficiency::Armor(_)
+            | Proficiency::SavingThrow(_)
+            | Proficiency::Vehicle(_)
+            | Proficiency::Weapon(_) => ProficiencyLevel::None,
+        }
+    }
+
+    /// The best half proficiency from the character's classes for an ability check using a given
+    /// ability, or with no set ability (such as a tool check).
+    fn half_proficiency(&self, ability: Option<AbilityScoreType>) -> ProficiencyLevel {
+        self.classes
+            .iter()
+            .filter_map(|c| c.class.half_proficiency(c.level))
+            .map(|h| h.level(ability))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Initiative modifier, which is a Dexterity check plus any bonuses from feats
+    fn initiative(&self) -> i16 {
+        self.abilities.modifier(AbilityScoreType::Dexterity)
+            + self
+                .half_proficiency(Some(AbilityScoreType::Dexterity))
+                .bonus(self.proficiency_bonus())
+            + self
+                .feats
+                .iter()
+                .map(|f| f.feat.initiative_bonus())
+                .sum::<i16>()
+    }
+
+    /// Passive score for a skill: 10 plus the skill's modifier and any bonuses from feats
+    fn passive(&self, skill: Skill) -> i16 {
+        10 + skill.modifier_at(
+            &self.abilities,
+            self.proficiency_level(&Proficiency::Skill(skill)),
+            self.proficiency_bonus(),
+        ) + self
+            .feats
+            .iter()
+            .map(|f| f.feat.passive_bonus(skill))
+            .sum::<i16>()
+    }
+
+    /// Types of damage the character is immune to
+    fn immunities(&self) -> Vec<DamageType> {
+        let mut immunities = self
+            .effects(self.level)
+            .into_iter()
+            .filter_map(|e| match e {
+                FeatureEffect::DamageImmunity(d) => Some(d),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        immunities.sort();
+        immunities.dedup();
+        immunities
+    }
+
+    /// Types of damage the character is resistant to, unless they are already immune
+    fn resistances(&self) -> Vec<DamageType> {
+        let immunities = self.immunities();
+        let mut resistances = self
+            .effects(self.level)
+            .into_iter()
+            .filter_map(|e| match e {
+                FeatureEffect::DamageResistance(d) if !immunities.contains(&d) => Some(d),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        resistances.sort();
+        resistances.dedup();
+        resistances
+    }
+
+    /// Conditions the character can't be affected by
+    fn condition_immunities(&self) -> Vec<Condition> {
+        let mut conditions = self
+            .effects(self.level)
+            .into_iter()
+            .filter_map(|e| match e {
+                FeatureEffect::ConditionImmunity(c) => Some(c),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        conditions.sort();
+        conditions.dedup();
+        conditions
+    }
+
+    /// Saving throws the character has advantage on
+    fn save_advantages(&self) -> Vec<SaveAdvantage> {
+        let mut advantages = vec![];
+        for effect in self.effects(self.level) {
+            if let FeatureEffect::SaveAdvantage(advantage) = effect {
+                if !advantages.contains(&advantage) {
+                    advantages.push(advantage);
+                }
+            }
+        }
+        advantages
+    }
+
+    /// Special senses the character has. If more than one feature grants the same sense,
+    /// only the longest range is kept.
+    fn senses(&self) -> Vec<(Sense, u16)> {
+        let mut senses: Vec<(Sense, u16)> = vec![];
+        for effect in self.effects(self.level) {
+            if let FeatureEffect::Sense(sense, range) = effect {
+                match senses.iter_mut().find(|(s, _)| *s == sense) {
+                    Some((_, r)) => *r = (*r).max(range),
+                    None => senses.push((sense, range)),
+                }
+            }
+        }
+        senses.sort_unstable();
+        senses
+    }
+
+    /// Spells granted by features and traits, rather than learned as part of a class
+    fn feature_spells(&self) -> Vec<Spell> {
+        let mut spells = vec![];
+        for effect in self.effects(self.level) {
+            if let FeatureEffect::Spell(spell) = effect {
+                if !spells.contains(&spell) {
+                    spells.push(spell);
+                }
+            }
+        }
+        spells
+    }
+
     /// Return the character's proficiency bonus based on their level.
     fn proficiency_bonus(&self) -> i16 {
         match self.level {
@@ -292,22 +1269,166 @@ impl<'a> Character<'a> {
         }
     }
 
+    /// Total weight of the character's equipment in pounds
+    fn carried_weight(&self) -> f32 {
+        self.equipment.iter().map(Equipment::weight).sum()
+    }
+
+    /// Multiplier to the weight the character can carry, push, drag or lift. Small and Medium creatures share
+    /// the same limits, and Powerful Build counts as one size larger, doubling them.
+    fn size_multiplier(&self) -> u16 {
+        if self
+            .effects(self.level)
+            .contains(&FeatureEffect::PowerfulBuild)
+        {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Weight in pounds the character can carry: 15 times their Strength score (PHB p176)
+    fn carrying_capacity(&self) -> u16 {
+        let strength =
+            u16::try_from(self.abilities.score(AbilityScoreType::Strength)).unwrap_or_default();
+        strength * 15 * self.size_multiplier()
+    }
+
+    /// How weighed down the character is by their equipment under the variant encumbrance rules
+    fn encumbrance(&self) -> Encumbrance {
+        let strength = f32::from(
+            u16::try_from(self.abilities.score(AbilityScoreType::Strength)).unwrap_or_default()
+                * self.size_multiplier(),
+        );
+        let weight = self.carried_weight();
+        if weight > strength * 10.0 {
+            Encumbrance::HeavilyEncumbered
+        } else if weight > strength * 5.0 {
+            Encumbrance::Encumbered
+        } else {
+            Encumbrance::Unencumbered
+        }
+    }
+
     /// Return the speeds of the character.
+    ///
+    /// Features can increase walking speed, some only depending on the armor worn, and wearing armor
+    /// without meeting its Strength requirement reduces each speed by 10 feet. With variant
+    /// encumbrance, carrying too much reduces them further.
     fn speeds(&self) -> Vec<Speed> {
-        self.characteristics
+        let (_, _, armor, shield) = self.best_armor_class_option();
+        let heavy_armor = armor.is_some_and(|a| a.armor_type() == ArmorType::Heavy);
+        let unarmored = armor.is_none() && !shield;
+        let bonus = self
+            .effects(self.level)
+            .into_iter()
+            .map(|e| match e {
+                FeatureEffect::SpeedBonus(b) => b,
+                FeatureEffect::SpeedBonusWithoutHeavyArmor(b) if !heavy_armor => b,
+                FeatureEffect::UnarmoredSpeedBonus(b) if unarmored => b,
+                _ => 0,
+            })
+            .fold(0, u8::saturating_add);
+        let speeds = self
+            .characteristics
             .as_ref()
             .map(|c| c.base_speeds.clone())
             .unwrap_or_default()
+            .into_iter()
+            .map(|speed| match speed {
+                Speed::Walking(s) => Speed::Walking(s.saturating_add(bonus)),
+                s => s,
+            })
+            .collect::<Vec<_>>();
+        let too_heavy = armor
+            .and_then(Armor::strength_requirement)
+            .is_some_and(|requirement| {
+                self.abilities.score(AbilityScoreType::Strength) < requirement
+            });
+        let reduces_speed = self
+            .race
+            .as_ref()
+            .is_none_or(RaceOption::heavy_armor_reduces_speed);
+        let mut penalty = if too_heavy && reduces_speed { 10 } else { 0 };
+        if self.variant_encumbrance {
+            penalty += self.encumbrance().speed_penalty();
+        }
+        if penalty == 0 {
+            return speeds;
+        }
+        speeds
+            .into_iter()
+            .map(|speed| match speed {
+                Speed::Climbing(s) => Speed::Climbing(s.saturating_sub(penalty)),
+                Speed::Flying(s) => Speed::Flying(s.saturating_sub(penalty)),
+                Speed::Swimming(s) => Speed::Swimming(s.saturating_sub(penalty)),
+                Speed::Walking(s) => Speed::Walking(s.saturating_sub(penalty)),
+            })
+            .collect()
     }
 }
 
-/// Combine all attitude and morality influences for the character (race and personality)
+/// Spell level or slot as an ordinal, such as "1st"
+fn ordinal(level: usize) -> String {
+    match level {
+        1 => "1st".to_string(),
+        2 => "2nd".to_string(),
+        3 => "3rd".to_string(),
+        _ => format!("{level}th"),
+    }
+}
+
+/// Mark for the proficiency column of the skills and tools tables
+fn proficiency_mark(level: ProficiencyLevel) -> &'static str {
+    match level {
+        ProficiencyLevel::None => "",
+        ProficiencyLevel::Half | ProficiencyLevel::HalfRoundedUp => " ½",
+        ProficiencyLevel::Proficient => " X",
+        ProficiencyLevel::Expertise => "XX",
+    }
+}
+
+/// Slots available at each spell level, such as "1st: 4, 2nd: 2"
+fn slot_list(slots: &[usize]) -> String {
+    slots
+        .iter()
+        .enumerate()
+        .filter(|(_, &slots)| slots > 0)
+        .map(|(level, slots)| format!("{}: {slots}", ordinal(level + 1)))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Explain an attitude or morality choice by how often the character's race, class, personality and
+/// deity lean towards each option, such as "Influenced towards Lawful (2), Neutral (1)"
+fn influence_reason<T: Copy + fmt::Display + IntoEnumIterator + PartialEq>(
+    influences: &[T],
+) -> String {
+    let counts = T::iter()
+        .map(|o| (o, influences.iter().filter(|&&i| i == o).count()))
+        .filter(|&(_, count)| count > 0)
+        .map(|(o, count)| format!("{o} ({count})"))
+        .collect::<Vec<_>>();
+    if counts.is_empty() {
+        "Nothing influenced it, so each option was equally likely".to_string()
+    } else {
+        format!(
+            "Influenced towards {}, each influence making an option more likely",
+            counts.join(", ")
+        )
+    }
+}
+
+/// Combine all attitude and morality influences for the character (race, class, personality and deity)
 impl<'a> AlignmentInfluences for Character<'a> {
     fn attitude(&self) -> Vec<Attitude> {
         let mut attitude = vec![];
         if let Some(race) = self.race.as_ref() {
             attitude.extend(race.attitude());
         }
+        for ClassLevels { class, .. } in &self.classes {
+            attitude.extend(class.attitude());
+        }
         if let Some(personality) = self.personality.as_ref() {
             attitude.extend(personality.attitude());
         }
@@ -322,6 +1443,9 @@ impl<'a> AlignmentInfluences for Character<'a> {
         if let Some(race) = self.race.as_ref() {
             morality.extend(race.morality());
         }
+        for ClassLevels { class, .. } in &self.classes {
+            morality.extend(class.morality());
+        }
         if let Some(personality) = self.personality.as_ref() {
             morality.extend(personality.morality());
         }
@@ -349,7 +1473,7 @@ impl<'a> Backstory for Character<'a> {
         if let Some(race) = self.race.as_ref() {
             backstory.extend(race.backstory());
         }
-        if let Some(class) = self.class.as_ref() {
+        for ClassLevels { class, .. } in &self.classes {
             backstory.extend(class.backstory());
         }
         if let Some(background) = self.background.as_ref() {
@@ -359,37 +1483,37 @@ impl<'a> Backstory for Character<'a> {
     }
 }
 
-/// Combine all features and traits for the characters (race and background)
+/// Combine all features and traits for the characters (race, class, background and feats)
 impl<'a> Features for Character<'a> {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, level: u8) -> Vec<Feature> {
         let mut features = vec![];
         if let Some(race) = self.race.as_ref() {
-            features.extend(race.features());
+            features.extend(race.features(level));
         }
-        if let Some(class) = self.class.as_ref() {
-            features.extend(class.features());
+        // Class features depend on the character's level in that class
+        for ClassLevels { class, level, .. } in &self.classes {
+            features.extend(class.features(*level));
         }
         if let Some(background) = self.background.as_ref() {
-            features.extend(background.features());
+            features.extend(background.features(level));
         }
+        features.extend(self.feats.iter().flat_map(|f| f.features(level)));
         features
     }
-}
-
-/// Combine all resistances the character has.
-impl<'a> Resistances for Character<'a> {
-    fn immunities(&self) -> Vec<DamageType> {
-        self.race
-            .as_ref()
-            .map(RaceOption::immunities)
-            .unwrap_or_default()
-    }
 
-    fn resistances(&self) -> Vec<DamageType> {
-        self.race
-            .as_ref()
-            .map(RaceOption::resistances)
-            .unwrap_or_default()
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![];
+        if let Some(race) = self.race.as_ref() {
+            effects.extend(race.effects(level));
+        }
+        for ClassLevels { class, level, .. } in &self.classes {
+            effects.extend(class.effects(*level));
+        }
+        if let Some(background) = self.background.as_ref() {
+            effects.extend(background.effects(level));
+        }
+        effects.extend(self.feats.iter().flat_map(|f| f.effects(level)));
+        effects
     }
 }
 
@@ -413,42 +1537,251 @@ impl<'a> fmt::Display for Character<'a> {
         if let Some(race) = self.race.as_ref() {
             writeln!(f, "RACE: {} ({})", race, race.citations())?;
         }
-        if let Some(class) = self.class.as_ref() {
-            writeln!(f, "CLASS: {} ({})", class, class.citations())?;
+        if !self.classes.is_empty() {
+            let multiclassed = self.classes.len() > 1;
+            writeln!(
+                f,
+                "CLASS: {}",
+                self.classes
+                    .iter()
+                    .map(|ClassLevels { class, level, .. }| if multiclassed {
+                        format!("{class} {level} ({})", class.citations())
+                    } else {
+                        format!("{class} ({})", class.citations())
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" / ")
+            )?;
         }
         if let Some(background) = self.background.as_ref() {
             writeln!(f, "BACKGROUND: {} ({})", background, background.citations())?;
         }
         writeln!(f, "ALIGNMENT: {}", self.alignment.as_ref().unwrap())?;
         writeln!(f, "LEVEL: {}", self.level)?;
+        if let Some(seed) = self.seed {
+            write!(f, "SEED: {seed}")?;
+            if !self.rerolls.is_empty() {
+                let rerolls = self.rerolls.iter().map(ToString::to_string);
+                write!(f, " (rerolled {})", rerolls.collect::<Vec<_>>().join(", "))?;
+            }
+            writeln!(f)?;
+        }
         writeln!(f)?;
         writeln!(f, "{}", self.abilities)?;
-        writeln!(f, "SKILLS:")?;
-        writeln!(f, "PROF  MOD  SKILL            BONUS:")?;
-        for skill in Skill::iter() {
+        writeln!(f, "SAVING THROWS:")?;
+        writeln!(f, "PROF  SAVE  BONUS:")?;
+        for ability in AbilityScoreType::iter() {
             writeln!(
                 f,
-                "{:4}  {}  {:15}  {:+}",
-                if skill.proficient(&self.proficiencies,) {
+                "{:4}  {:4}  {:+}",
+                if self
+                    .proficiencies
+                    .contains(&Proficiency::SavingThrow(ability))
+                {
                     " X"
                 } else {
                     ""
                 },
+                ability.to_string(),
+                self.abilities
+                    .saving_throw(ability, &self.proficiencies, self.proficiency_bonus()),
+            )?;
+        }
+        for advantage in self.save_advantages() {
+            writeln!(f, "- {advantage}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "SKILLS:")?;
+        writeln!(f, "PROF  MOD  SKILL            BONUS:")?;
+        for skill in Skill::iter() {
+            let level = self.proficiency_level(&Proficiency::Skill(skill));
+            writeln!(
+                f,
+                "{:4}  {}  {:15}  {:+}",
+                proficiency_mark(level),
                 skill.ability_score_type(),
                 skill,
-                skill.modifier(
-                    &self.abilities,
-                    &self.proficiencies,
-                    self.proficiency_bonus(),
-                ),
+                skill.modifier_at(&self.abilities, level, self.proficiency_bonus()),
             )?;
         }
         writeln!(f)?;
+        for skill in [Skill::Perception, Skill::Investigation, Skill::Insight] {
+            writeln!(
+                f,
+                "PASSIVE {}: {}",
+                skill.to_string().to_uppercase(),
+                self.passive(skill)
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "TOOLS:")?;
+        writeln!(f, "PROF  TOOL                       BONUS:")?;
+        for tool in self.proficiencies.iter().filter_map(|p| match p {
+            Proficiency::Tool(tool) => Some(tool),
+            _ => None,
+        }) {
+            let level = self.proficiency_level(&Proficiency::Tool(*tool));
+            writeln!(
+                f,
+                "{:4}  {:25}  {:+}",
+                proficiency_mark(level),
+                tool.to_string(),
+                level.bonus(self.proficiency_bonus()),
+            )?;
+        }
+        let other_tools = self.half_proficiency(None);
+        if other_tools != ProficiencyLevel::None {
+            writeln!(
+                f,
+                "{:4}  {:25}  {:+}",
+                proficiency_mark(other_tools),
+                "Other tools",
+                other_tools.bonus(self.proficiency_bonus()),
+            )?;
+        }
+        writeln!(f)?;
+        let (armor_class, source, armor) = self.armor_class();
+        writeln!(
+            f,
+            "ARMOR CLASS: {armor_class} ({source}){}",
+            if armor.is_some_and(Armor::stealth_disadvantage) {
+                " - Disadvantage on Stealth checks"
+            } else {
+                ""
+            }
+        )?;
+        writeln!(f, "INITIATIVE: {:+}", self.initiative())?;
         writeln!(f, "SPEED:")?;
         for speed in self.speeds() {
             writeln!(f, "{}", speed)?;
         }
+        writeln!(
+            f,
+            "SENSES: {}",
+            self.senses()
+                .iter()
+                .map(|(sense, range)| format!("{sense} {range}ft"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )?;
+        write!(
+            f,
+            "CARRYING: {} lb / {} lb",
+            self.carried_weight(),
+            self.carrying_capacity()
+        )?;
+        if self.variant_encumbrance {
+            write!(f, " ({})", self.encumbrance())?;
+        }
+        writeln!(f)?;
+        writeln!(f, "HIT POINTS: {}", self.hit_points())?;
+        if !self.classes.is_empty() {
+            writeln!(
+                f,
+                "HIT DICE: {}",
+                self.classes
+                    .iter()
+                    .map(|c| RollCmd(c.level.into(), c.class.hit_die()).to_string())
+                    .collect::<Vec<String>>()
+                    .join(" + ")
+            )?;
+        }
         writeln!(f, "PROFICIENCY BONUS: {:+}", self.proficiency_bonus())?;
+        writeln!(f)?;
+        writeln!(f, "ATTACKS:")?;
+        writeln!(
+            f,
+            "{:20} {:20} {:16} {:20} PROPERTIES",
+            "NAME", "RANGE", "HIT", "DAMAGE"
+        )?;
+        for attack in self.attacks() {
+            writeln!(f, "{attack}")?;
+        }
+        writeln!(f)?;
+        let spellcasters = self.spellcasters();
+        let multiclassed = self.classes.len() > 1;
+        for (i, (class, spellcaster, spells)) in self
+            .classes
+            .iter()
+            .filter_map(|c| Some((&c.class, c.class.spellcasting()?, c.spells.as_ref()?)))
+            .enumerate()
+        {
+            writeln!(
+                f,
+                "SPELLCASTING{}: {} (Save DC {}, Attack Bonus {:+})",
+                if multiclassed {
+                    format!(" ({})", ClassType::from(class))
+                } else {
+                    String::new()
+                },
+                spellcaster.ability,
+                spellcaster.save_dc(&self.abilities, self.proficiency_bonus()),
+                spellcaster.attack_bonus(&self.abilities, self.proficiency_bonus()),
+            )?;
+            // Slots are shared between classes, so only list them once
+            if i == 0 {
+                writeln!(
+                    f,
+                    "SPELL SLOTS: {}",
+                    slot_list(&multiclass_spell_slots(&spellcasters))
+                )?;
+                if spellcasters.len() > 1 {
+                    for (pact, level) in spellcasters
+                        .iter()
+                        .filter(|(s, _)| s.caster_type == CasterType::Pact)
+                    {
+                        writeln!(
+                            f,
+                            "PACT MAGIC SLOTS: {}",
+                            slot_list(&pact.spell_slots(*level))
+                        )?;
+                    }
+                }
+            }
+            writeln!(
+                f,
+                "CANTRIPS: {}",
+                spells
+                    .cantrips
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+            if !spells.spellbook.is_empty() {
+                writeln!(
+                    f,
+                    "SPELLBOOK: {}",
+                    spells
+                        .spellbook
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )?;
+            }
+            writeln!(f, "SPELLS:")?;
+            for spell in &spells.spells {
+                writeln!(
+                    f,
+                    "{:5} {:30} {:14} {:16} {:22} {:8} {} ({})",
+                    ordinal(spell.level().into()),
+                    spell.to_string(),
+                    spell.school().to_string(),
+                    spell.casting_time().to_string(),
+                    spell.range(),
+                    spell
+                        .components()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    spell.duration(),
+                    spell.citation(),
+                )?;
+            }
+            writeln!(f)?;
+        }
         writeln!(
             f,
             "RESISTANCES: {}",
@@ -467,6 +1800,27 @@ impl<'a> fmt::Display for Character<'a> {
                 .collect::<Vec<String>>()
                 .join(", ")
         )?;
+        writeln!(
+            f,
+            "CONDITION IMMUNITIES: {}",
+            self.condition_immunities()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        )?;
+        let feature_spells = self.feature_spells();
+        if !feature_spells.is_empty() {
+            writeln!(
+                f,
+                "FEATURE SPELLS: {}",
+                feature_spells
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+        }
         writeln!(
             f,
             "LANGUAGES: {}",
@@ -485,7 +1839,7 @@ impl<'a> fmt::Display for Character<'a> {
             writeln!(f, "{}", personality)?;
         }
         writeln!(f, "FEATURES AND TRAITS:")?;
-        for feature in self.features() {
+        for feature in self.features(self.level) {
             writeln!(f, "- {}", feature)?;
         }
         writeln!(f)?;
@@ -511,9 +1865,8 @@ impl<'a> fmt::Display for Character<'a> {
             self.proficiencies
                 .iter()
                 .filter_map(|p| match p {
-                    Proficiency::Skill(_) => None,
+                    Proficiency::SavingThrow(_) | Proficiency::Skill(_) => None,
                     Proficiency::Armor(_)
-                    | Proficiency::SavingThrow(_)
                     | Proficiency::Tool(_)
                     | Proficiency::Weapon(_)
                     | Proficiency::Vehicle(_) => Some(format!("{:?}", p)),
@@ -527,7 +1880,7 @@ impl<'a> fmt::Display for Character<'a> {
             writeln!(f, "{}", equipment)?;
         }
         writeln!(f)?;
-        writeln!(f, "COINS: {}{}", self.coins.1, self.coins.0)?;
+        writeln!(f, "COINS: {}", self.coins)?;
         write!(f, "")
     }
 }
@@ -554,4 +1907,317 @@ mod tests {
         let character = Character::gen(&mut rng);
         insta::assert_display_snapshot!(character);
     }
+
+    #[test]
+    fn test_unarmored_defense() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Lizardfolk)
+            .class(class::ClassType::Monk)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities = AbilityScores(
+            AbilityScoreType::iter()
+                .map(|a| {
+                    (
+                        a,
+                        if a == AbilityScoreType::Wisdom {
+                            16
+                        } else {
+                            12
+                        },
+                    )
+                })
+                .collect(),
+        );
+        character.equipment = vec![];
+        // Monk's 10 + Dex + Wis beats the Lizardfolk's 13 + Dex
+        assert_eq!(character.armor_class().0, 14);
+        assert_eq!(character.armor_class().1, "Unarmored Defense");
+
+        // Natural Armor still benefits from a shield, but Unarmored Defense doesn't
+        character.equipment = vec![Equipment::new(Item::Armor(Armor::Shield), 1)];
+        character.proficiencies = vec![Proficiency::Armor(ArmorType::Shield)];
+        assert_eq!(character.armor_class().0, 16);
+        assert_eq!(character.armor_class().1, "Natural Armor, Shield");
+    }
+
+    #[test]
+    fn test_initiative_and_passives() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut character = CharacterBuilder::new()
+            .class(class::ClassType::Bard)
+            .level(2)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities = AbilityScores(AbilityScoreType::iter().map(|a| (a, 14)).collect());
+        character.proficiencies = vec![Proficiency::Skill(Skill::Perception)];
+        character.expertise = vec![];
+        character.feats = vec![];
+        // Jack of All Trades adds half of the +2 proficiency bonus to initiative
+        assert_eq!(character.initiative(), 3);
+        assert_eq!(character.passive(Skill::Perception), 14);
+        assert_eq!(character.passive(Skill::Insight), 13);
+
+        character.feats = vec![
+            ChosenFeat {
+                feat: Feat::Alert,
+                ability: None,
+            },
+            ChosenFeat {
+                feat: Feat::Observant,
+                ability: None,
+            },
+        ];
+        assert_eq!(character.initiative(), 8);
+        assert_eq!(character.passive(Skill::Perception), 19);
+        assert_eq!(character.passive(Skill::Insight), 13);
+    }
+
+    #[test]
+    fn test_armor_speed_bonuses() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Human)
+            .class(class::ClassType::Monk)
+            .level(6)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities = AbilityScores(AbilityScoreType::iter().map(|a| (a, 10)).collect());
+        character.equipment = vec![];
+        assert!(character.speeds().contains(&Speed::Walking(45)));
+        // Unarmored Movement doesn't apply while wielding a shield
+        character
+            .proficiencies
+            .push(Proficiency::Armor(ArmorType::Shield));
+        character.equipment = vec![Equipment::new(Item::Armor(Armor::Shield), 1)];
+        assert!(character.speeds().contains(&Speed::Walking(30)));
+
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Human)
+            .class(class::ClassType::Barbarian)
+            .level(5)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities = AbilityScores(AbilityScoreType::iter().map(|a| (a, 15)).collect());
+        character.equipment = vec![];
+        assert!(character.speeds().contains(&Speed::Walking(40)));
+        // Fast Movement doesn't apply in heavy armor
+        character
+            .proficiencies
+            .push(Proficiency::Armor(ArmorType::Heavy));
+        character.equipment = vec![Equipment::new(Item::Armor(Armor::Plate), 1)];
+        assert!(character.speeds().contains(&Speed::Walking(30)));
+    }
+
+    #[test]
+    fn test_heavy_armor() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Human)
+            .class(class::ClassType::Fighter)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities = AbilityScores(AbilityScoreType::iter().map(|a| (a, 10)).collect());
+        character.equipment = vec![Equipment::new(Item::Armor(Armor::Plate), 1)];
+        assert_eq!(
+            character.armor_class(),
+            (18, "Plate armor".to_string(), Some(Armor::Plate))
+        );
+        assert!(character.speeds().contains(&Speed::Walking(20)));
+
+        character.abilities.0.insert(AbilityScoreType::Strength, 15);
+        assert!(character.speeds().contains(&Speed::Walking(30)));
+    }
+
+    #[test]
+    fn test_go_shopping() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Human)
+            .class(class::ClassType::Fighter)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities = AbilityScores(AbilityScoreType::iter().map(|a| (a, 14)).collect());
+        character
+            .abilities
+            .0
+            .insert(AbilityScoreType::Dexterity, 10);
+        character.equipment = vec![];
+        character.coins = Purse::default();
+        character.coins.add(Coin::Gold, 100);
+        character.go_shopping(&mut rng);
+        // Cheapest of the two-handed weapons with the highest damage, an explorer's pack, and chain mail
+        assert!(character
+            .equipment
+            .contains(&Equipment::new(Item::Weapon(Weapon::Maul), 1)));
+        assert!(character.equipment.contains(&Equipment::new(
+            Item::Gear(Gear::Other(OtherGear::Backpack)),
+            1
+        )));
+        assert_eq!(character.armor_class().2, Some(Armor::ChainMail));
+        assert_eq!(character.coins.to_string(), "5gp");
+
+        // Nothing is bought twice when restocking, and a shield is no use with a maul
+        character.coins.add(Coin::Gold, 5);
+        character.go_shopping(&mut rng);
+        assert_eq!(character.coins.to_string(), "10gp");
+    }
+
+    #[test]
+    fn test_encumbrance() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Human)
+            .class(class::ClassType::Fighter)
+            .variant_encumbrance(true)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities.0.insert(AbilityScoreType::Strength, 10);
+        character.equipment = vec![Equipment::new(Item::Weapon(Weapon::Maul), 6)];
+        assert!((character.carried_weight() - 60.0).abs() < f32::EPSILON);
+        assert_eq!(character.carrying_capacity(), 150);
+        assert_eq!(character.encumbrance(), Encumbrance::Encumbered);
+        assert_eq!(character.speeds(), vec![Speed::Walking(20)]);
+        character.variant_encumbrance = false;
+        assert_eq!(character.speeds(), vec![Speed::Walking(30)]);
+
+        // Powerful Build doubles the weight a Goliath can carry
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Goliath)
+            .class(class::ClassType::Fighter)
+            .variant_encumbrance(true)
+            .gen(&mut rng)
+            .unwrap();
+        character.abilities.0.insert(AbilityScoreType::Strength, 10);
+        character.equipment = vec![Equipment::new(Item::Weapon(Weapon::Maul), 6)];
+        assert_eq!(character.carrying_capacity(), 300);
+        assert_eq!(character.encumbrance(), Encumbrance::Unencumbered);
+        assert_eq!(character.speeds(), vec![Speed::Walking(30)]);
+    }
+
+    #[test]
+    fn test_powerful_build() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        for race in [
+            race::RaceType::Bugbear,
+            race::RaceType::Firbolg,
+            race::RaceType::Goliath,
+            race::RaceType::Orc,
+            race::RaceType::Human,
+        ] {
+            let mut character = CharacterBuilder::new().race(race).gen(&mut rng).unwrap();
+            character.abilities.0.insert(AbilityScoreType::Strength, 12);
+            let expected = if race == race::RaceType::Human {
+                180
+            } else {
+                360
+            };
+            assert_eq!(character.carrying_capacity(), expected, "{race}");
+        }
+    }
+
+    #[test]
+    fn test_feature_effects() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut character = CharacterBuilder::new()
+            .race(race::RaceType::Tiefling)
+            .class(class::ClassType::Fighter)
+            .gen(&mut rng)
+            .unwrap();
+        character.equipment = vec![];
+        character.feats = vec![];
+        assert_eq!(character.senses(), vec![(Sense::Darkvision, 60)]);
+        assert_eq!(character.resistances(), vec![DamageType::Fire]);
+        assert!(character.speeds().contains(&Speed::Walking(30)));
+
+        character.feats = vec![
+            ChosenFeat {
+                feat: Feat::InfernalConstitution,
+                ability: None,
+            },
+            ChosenFeat {
+                feat: Feat::Mobile,
+                ability: None,
+            },
+        ];
+        assert_eq!(
+            character.resistances(),
+            vec![DamageType::Cold, DamageType::Fire, DamageType::Poison]
+        );
+        assert_eq!(character.save_advantages().len(), 1);
+        assert!(character.speeds().contains(&Speed::Walking(40)));
+    }
+
+    #[test]
+    fn test_reroll() {
+        let seed = Seed::new(1);
+        let mut character = CharacterBuilder::new().gen_seeded(seed).unwrap();
+        let sheet = character.to_string();
+        let personality = serde_yaml::to_string(&character.personality).unwrap();
+        let languages = character.languages.clone();
+
+        character.reroll(&mut seed.reroll_rng(0), Section::Name);
+        assert!(serde_yaml::to_string(&character.personality).unwrap() == personality);
+        assert!(character.languages == languages);
+        assert!(character
+            .to_string()
+            .contains(&format!("SEED: {seed} (rerolled Name)")));
+
+        // Rerolling the same way gives the same character
+        let mut again = CharacterBuilder::new().gen_seeded(seed).unwrap();
+        assert_eq!(again.to_string(), sheet);
+        again.reroll(&mut seed.reroll_rng(0), Section::Name);
+        assert_eq!(again.to_string(), character.to_string());
+
+        // New equipment replaces the old, rather than adding to it
+        let coins = character.coins.clone();
+        character.reroll(&mut seed.reroll_rng(1), Section::Equipment);
+        assert_eq!(character.coins, coins);
+        assert!(character
+            .to_string()
+            .contains(&format!("SEED: {seed} (rerolled Name, Equipment)")));
+    }
+
+    #[test]
+    fn test_reroll_keeps_pinned() {
+        let seed = Seed::new(1);
+        let mut character = CharacterBuilder::new()
+            .alignment(Alignment(Attitude::Chaotic, Morality::Evil))
+            .pantheon(Pantheon::Greek)
+            .gen_seeded(seed)
+            .unwrap();
+        character.reroll(&mut seed.reroll_rng(0), Section::Personality);
+        assert_eq!(
+            character.alignment.as_ref().unwrap().to_string(),
+            "Chaotic Evil"
+        );
+        character.reroll(&mut seed.reroll_rng(1), Section::Deity);
+        assert_eq!(
+            character.alignment.as_ref().unwrap().to_string(),
+            "Chaotic Evil"
+        );
+        assert_eq!(character.pantheon, Some(Pantheon::Greek));
+    }
+
+    #[test]
+    fn test_feature_proficiencies() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        // Diamond Soul grants every saving throw, including the two a Monk starts with
+        let character = CharacterBuilder::new()
+            .class(class::ClassType::Monk)
+            .level(14)
+            .gen(&mut rng)
+            .unwrap();
+        for ability in AbilityScoreType::iter() {
+            assert_eq!(
+                character
+                    .proficiencies
+                    .iter()
+                    .filter(|&p| p == &Proficiency::SavingThrow(ability))
+                    .count(),
+                1
+            );
+        }
+    }
 }
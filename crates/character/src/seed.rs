@@ -0,0 +1,131 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+/// Version of the character generator. Bump this whenever a change means an existing seed would no longer
+/// generate the same character, so that old seeds are rejected instead of quietly giving a different one.
+pub const GENERATOR_VERSION: u16 = 3;
+
+/// Everything needed to generate the same character again.
+///
+/// Written as `<version>-<value>`, such as `3-00000000075bcd15`: the generator version in decimal, then
+/// the 64-bit value passed to `Pcg64::seed_from_u64` as 16 hex digits. This is not the generator's raw
+/// 128-bit state, which `seed_from_u64` derives from the value.
+/// The same seed and the same constraints always generate the same character with the same version.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Seed(u64);
+
+impl Seed {
+    /// Seed for the current generator version from a 64-bit value
+    #[must_use]
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Choose a new random seed
+    pub fn gen(rng: &mut impl Rng) -> Self {
+        Self(rng.gen())
+    }
+
+    /// Random number generator to generate a character with
+    #[must_use]
+    pub fn rng(self) -> Pcg64 {
+        Pcg64::seed_from_u64(self.0)
+    }
+
+    /// Random number generator for a character's nth reroll of a section, so that a character generated from
+    /// this seed can be rerolled the same way again
+    #[must_use]
+    pub fn reroll_rng(self, index: usize) -> Pcg64 {
+        Pcg64::new(u128::from(self.0), index as u128)
+    }
+}
+
+impl fmt::Display for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{GENERATOR_VERSION}-{:016x}", self.0)
+    }
+}
+
+/// Reasons a seed can't be used to generate a character.
+#[derive(Debug, Eq, PartialEq)]
+pub enum SeedError {
+    /// The seed isn't in the `<version>-<value>` format.
+    Invalid(String),
+    /// The seed is from a different version of the generator, so wouldn't generate the same character.
+    Version(u16),
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(seed) => write!(f, "{seed} is not a valid seed"),
+            Self::Version(version) => write!(
+                f,
+                "Seed is from version {version} of the generator, but this is version {GENERATOR_VERSION}"
+            ),
+        }
+    }
+}
+
+impl Error for SeedError {}
+
+impl FromStr for Seed {
+    type Err = SeedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SeedError::Invalid(s.to_string());
+        let (version, value) = s.trim().split_once('-').ok_or_else(invalid)?;
+        let version = version.parse::<u16>().map_err(|_| invalid())?;
+        let value = u64::from_str_radix(value, 16).map_err(|_| invalid())?;
+        if version != GENERATOR_VERSION {
+            return Err(SeedError::Version(version));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<Seed> for String {
+    fn from(seed: Seed) -> Self {
+        seed.to_string()
+    }
+}
+
+impl TryFrom<String> for Seed {
+    type Error = SeedError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let seed = Seed::new(123_456_789);
+        assert_eq!(seed.to_string(), "3-00000000075bcd15");
+        assert_eq!("3-00000000075bcd15".parse(), Ok(seed));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            "75bcd15".parse::<Seed>(),
+            Err(SeedError::Invalid("75bcd15".into()))
+        );
+        assert_eq!(
+            "1-xyz".parse::<Seed>(),
+            Err(SeedError::Invalid("1-xyz".into()))
+        );
+        assert_eq!(
+            "1-00000000075bcd15".parse::<Seed>(),
+            Err(SeedError::Version(1))
+        );
+    }
+}
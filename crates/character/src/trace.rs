@@ -0,0 +1,98 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// An option considered for a weighted choice
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Candidate {
+    /// Name of the option
+    pub option: String,
+    /// Weight the option was chosen with
+    pub weight: f64,
+    /// Share of the total weight, so the chance of picking this option first
+    pub chance: f64,
+}
+
+/// A weighted choice made while generating a character: the options that were considered, which were
+/// picked, and why.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Decision {
+    /// What was being chosen, such as "Class"
+    pub choice: String,
+    /// Options that were considered, most likely first
+    pub candidates: Vec<Candidate>,
+    /// Options that were picked
+    pub picked: Vec<String>,
+    /// Explanation of the weighting that led to the pick
+    pub reason: String,
+}
+
+impl Decision {
+    /// Record a choice from weighted options. Candidates are sorted from most to least likely.
+    pub(crate) fn new<O: fmt::Display, P: fmt::Display>(
+        choice: impl Into<String>,
+        candidates: impl IntoIterator<Item = (O, f64)>,
+        picked: impl IntoIterator<Item = P>,
+        reason: impl Into<String>,
+    ) -> Self {
+        let candidates = candidates.into_iter().collect::<Vec<_>>();
+        let total = candidates.iter().map(|(_, w)| w).sum::<f64>();
+        let mut candidates = candidates
+            .into_iter()
+            .map(|(option, weight)| Candidate {
+                option: option.to_string(),
+                weight,
+                chance: if total > 0.0 { weight / total } else { 0.0 },
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        Self {
+            choice: choice.into(),
+            candidates,
+            picked: picked.into_iter().map(|p| p.to_string()).collect(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.choice, self.picked.join(", "))?;
+        writeln!(f, "  {}", self.reason)?;
+        write!(
+            f,
+            "  {}",
+            self.candidates
+                .iter()
+                .map(|c| format!("{} {:.0}%", c.option, c.chance * 100.0))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Record of the weighted choices made while generating a character, in the order they were made.
+/// Only kept if asked for with `CharacterBuilder::trace`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Trace(Vec<Decision>);
+
+impl Trace {
+    /// Decisions made, in order
+    #[must_use]
+    pub fn decisions(&self) -> &[Decision] {
+        &self.0
+    }
+
+    pub(crate) fn push(&mut self, decision: Decision) {
+        self.0.push(decision);
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for decision in &self.0 {
+            writeln!(f, "{decision}")?;
+        }
+        Ok(())
+    }
+}
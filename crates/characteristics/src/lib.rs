@@ -8,7 +8,7 @@ use dice_roller::RollCmd;
 use names::human::Ethnicity;
 use rand::{prelude::IteratorRandom, Rng};
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumIter, IntoEnumIterator};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 
 /// An range of ages a given adventurer could be.
 pub struct AgeRange(pub RangeInclusive<u16>);
@@ -21,7 +21,10 @@ impl AgeRange {
 }
 
 /// Really only here to help decide on names, not core to a character choice.
-#[derive(Deserialize, Display, EnumIter, Serialize)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, Eq, PartialEq, Serialize,
+)]
+#[strum(ascii_case_insensitive)]
 pub enum Gender {
     Female,
     Male,
@@ -79,7 +82,7 @@ impl HeightAndWeightTable {
 }
 
 /// Size of character (there are more options for monsters)
-#[derive(Deserialize, Display, EnumIter, Serialize)]
+#[derive(Debug, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
 pub enum Size {
     Small,
     Medium,
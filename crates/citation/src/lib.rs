@@ -13,6 +13,8 @@ use strum::Display;
 pub enum Book {
     #[strum(serialize = "COS")]
     Cos,
+    #[strum(serialize = "DMG")]
+    Dmg,
     #[strum(serialize = "MTOF")]
     Mtof,
     #[strum(serialize = "PHB")]
@@ -21,6 +23,8 @@ pub enum Book {
     Scag,
     #[strum(serialize = "VGTM")]
     Vgtm,
+    #[strum(serialize = "XGTE")]
+    Xgte,
 }
 
 /// Book and page number for citations.
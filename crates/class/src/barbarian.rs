@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Attitude};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, FeatureEffect, Features};
 use gear::{
     armor::ArmorType,
     weapons::{Weapon, WeaponCategory, WeaponClassification},
@@ -11,28 +13,212 @@ use gear::{
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::Spellcasting;
 use stats::{
-    ability::{AbilityScoreType, AbilityScores, Skill},
+    ability::{AbilityScore, AbilityScoreType, AbilityScores, Skill},
+    armor_class::UnarmoredDefense,
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Maximum Strength and Constitution score once Primal Champion raises them
+const PRIMAL_CHAMPION_MAX: i16 = 24;
+
+/// Primal paths a barbarian can follow, chosen at 3rd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum PrimalPath {
+    #[strum(serialize = "Path of the Berserker")]
+    Berserker,
+    #[strum(serialize = "Path of the Totem Warrior")]
+    TotemWarrior,
+}
+
+impl AlignmentInfluences for PrimalPath {
+    fn attitude(&self) -> Vec<Attitude> {
+        match self {
+            Self::Berserker => vec![Attitude::Chaotic],
+            Self::TotemWarrior => vec![],
+        }
+    }
+}
+
+impl Citations for PrimalPath {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Berserker => Citation(Book::Phb, 49),
+            Self::TotemWarrior => Citation(Book::Phb, 50),
+        }])
+    }
+}
+
+impl Features for PrimalPath {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Berserker => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Frenzy",
+                        description: "While raging you can go into a frenzy, making a single melee weapon attack as a bonus action on each of your turns. You suffer a level of exhaustion when the rage ends.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Mindless Rage",
+                        description: "You can't be charmed or frightened while raging.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Intimidating Presence",
+                        description: "You can use your action to frighten someone with your menacing presence.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Retaliation",
+                        description: "When you take damage from a creature within 5 feet of you, you can use your reaction to make a melee weapon attack against it.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::TotemWarrior => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Spirit Seeker",
+                        description: "You can cast the beast sense and speak with animals spells, but only as rituals.",
+                        citation: Citation(Book::Phb, 50),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Totem Spirit",
+                        description: "You choose a bear, eagle or wolf totem spirit, which grants a benefit while you rage.",
+                        citation: Citation(Book::Phb, 50),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Aspect of the Beast",
+                        description: "You gain a magical benefit based on the totem animal of your choice.",
+                        citation: Citation(Book::Phb, 50),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Spirit Walker",
+                        description: "You can cast the commune with nature spell, but only as a ritual.",
+                        citation: Citation(Book::Phb, 50),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Totemic Attunement",
+                        description: "You gain a magical benefit based on a totem animal of your choice while raging.",
+                        citation: Citation(Book::Phb, 50),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Subclass for PrimalPath {
+    fn level() -> u8 {
+        3
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Berserker => (
+                vec![AbilityScoreType::Constitution],
+                vec![Skill::Intimidation],
+            ),
+            Self::TotemWarrior => (
+                vec![AbilityScoreType::Wisdom],
+                vec![Skill::AnimalHandling, Skill::Nature, Skill::Survival],
+            ),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Barbarian;
+pub struct Barbarian {
+    /// Primal path, chosen at 3rd level
+    path: Option<PrimalPath>,
+}
+
+impl AlignmentInfluences for Barbarian {
+    fn attitude(&self) -> Vec<Attitude> {
+        self.path.map(|path| path.attitude()).unwrap_or_default()
+    }
+}
 
 impl Backstory for Barbarian {}
 
 impl Citations for Barbarian {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 46)])
+        let mut citations = vec![Citation(Book::Phb, 46)];
+        if let Some(path) = self.path {
+            citations.extend(path.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Barbarian {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
+    }
+
+    fn abilities(&self, level: u8) -> Vec<AbilityScore> {
+        // Primal Champion
+        if level >= 20 {
+            vec![
+                AbilityScore(AbilityScoreType::Strength, 4),
+                AbilityScore(AbilityScoreType::Constitution, 4),
+            ]
+        } else {
+            vec![]
+        }
+    }
+
+    fn ability_maximums(&self, level: u8) -> Vec<(AbilityScoreType, i16)> {
+        // Primal Champion
+        if level >= 20 {
+            vec![
+                (AbilityScoreType::Strength, PRIMAL_CHAMPION_MAX),
+                (AbilityScoreType::Constitution, PRIMAL_CHAMPION_MAX),
+            ]
+        } else {
+            vec![]
+        }
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -41,9 +227,198 @@ impl Class for Barbarian {
             vec![AbilityScoreType::Constitution],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D12
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Strength]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![
+            Proficiency::Armor(ArmorType::Shield),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Simple)),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Martial)),
+        ]
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.path = PrimalPath::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(2, Die::D4), 10)
+    }
+
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        Some(UnarmoredDefense {
+            name: "Unarmored Defense",
+            base: 10,
+            abilities: vec![AbilityScoreType::Dexterity, AbilityScoreType::Constitution],
+            shield: true,
+        })
+    }
 }
 
-impl Features for Barbarian {}
+impl Features for Barbarian {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let rages = scaling_at(
+            level,
+            &[
+                (1, "2 rages"),
+                (3, "3 rages"),
+                (6, "4 rages"),
+                (12, "5 rages"),
+                (17, "6 rages"),
+                (20, "Unlimited rages"),
+            ],
+        )
+        .unwrap_or_default();
+        let damage = scaling_at(level, &[(1, 2), (9, 3), (16, 4)]).unwrap_or_default();
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Rage",
+                        description: "As a bonus action you can enter a rage, gaining advantage on Strength checks and saving throws, bonus melee damage, and resistance to bludgeoning, piercing and slashing damage.",
+                        citation: Citation(Book::Phb, 48),
+                        scaling: Some(format!("{rages}, +{damage} damage")),
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Unarmored Defense",
+                        description: "While you aren't wearing armor, your AC equals 10 + your Dexterity modifier + your Constitution modifier. You can still use a shield.",
+                        citation: Citation(Book::Phb, 48),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Reckless Attack",
+                        description: "You can gain advantage on Strength melee weapon attacks during your turn, but attacks against you have advantage until your next turn.",
+                        citation: Citation(Book::Phb, 48),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Danger Sense",
+                        description: "You have advantage on Dexterity saving throws against effects you can see, as long as you aren't blinded, deafened or incapacitated.",
+                        citation: Citation(Book::Phb, 48),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Extra Attack",
+                        description: "You can attack twice, instead of once, whenever you take the Attack action on your turn.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Fast Movement",
+                        description: "Your speed increases by 10 feet while you aren't wearing heavy armor.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Feral Instinct",
+                        description: "You have advantage on initiative rolls, and can act normally on a surprise round if you enter your rage first.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    9,
+                    Feature {
+                        title: "Brutal Critical",
+                        description: "You can roll additional weapon damage dice when determining the extra damage for a critical hit with a melee attack.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: scaling_at(
+                            level,
+                            &[
+                                (9, "1 additional die"),
+                                (13, "2 additional dice"),
+                                (17, "3 additional dice"),
+                            ],
+                        ),
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Relentless Rage",
+                        description: "If you drop to 0 hit points while raging, you can make a DC 10 Constitution saving throw to drop to 1 hit point instead. The DC increases by 5 each time.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Persistent Rage",
+                        description: "Your rage only ends early if you fall unconscious or choose to end it.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Indomitable Might",
+                        description: "If your total for a Strength check is less than your Strength score, you can use that score in place of the total.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Primal Champion",
+                        description: "Your Strength and Constitution scores increase by 4, and your maximum for those scores is now 24.",
+                        citation: Citation(Book::Phb, 49),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(path) = self.path {
+            features.extend(path.features(level));
+        }
+        features
+    }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        // Fast Movement
+        if level >= 5 {
+            vec![FeatureEffect::SpeedBonusWithoutHeavyArmor(10)]
+        } else {
+            vec![]
+        }
+    }
+}
 
 impl Languages for Barbarian {}
 
@@ -77,6 +452,10 @@ impl Proficiencies for Barbarian {
     }
 }
 
+impl ProficiencyLevels for Barbarian {}
+
+impl Spellcasting for Barbarian {}
+
 impl StartingEquipment for Barbarian {
     fn equipment(&self) -> Vec<Equipment> {
         let mut equipment = Pack::Explorer.equipment();
@@ -119,7 +498,11 @@ impl StartingEquipment for Barbarian {
 
 impl fmt::Display for Barbarian {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Barbarian")
+        write!(f, "Barbarian")?;
+        if let Some(path) = self.path {
+            write!(f, ", {path}")?;
+        }
+        Ok(())
     }
 }
 
@@ -148,33 +531,76 @@ mod tests {
         insta::assert_yaml_snapshot!(Barbarian::ability_rank());
     }
 
+    #[test]
+    fn test_effects() {
+        let class = Barbarian::default();
+        assert!(class.effects(4).is_empty());
+        assert_eq!(
+            class.effects(5),
+            vec![FeatureEffect::SpeedBonusWithoutHeavyArmor(10)]
+        );
+    }
+
+    #[test]
+    fn test_primal_champion() {
+        let class = Barbarian::default();
+        assert!(class.abilities(19).is_empty());
+        assert!(class.ability_maximums(19).is_empty());
+        assert_eq!(
+            class
+                .abilities(20)
+                .into_iter()
+                .map(|AbilityScore(a, v)| (a, v))
+                .collect::<Vec<_>>(),
+            vec![
+                (AbilityScoreType::Strength, 4),
+                (AbilityScoreType::Constitution, 4),
+            ]
+        );
+        assert_eq!(
+            class.ability_maximums(20),
+            vec![
+                (AbilityScoreType::Strength, 24),
+                (AbilityScoreType::Constitution, 24),
+            ]
+        );
+    }
+
     #[test]
     fn test_snapshot_citations() {
-        let class = Barbarian;
+        let class = Barbarian::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
+    #[test]
+    fn test_snapshot_features() {
+        let class = Barbarian {
+            path: Some(PrimalPath::Berserker),
+        };
+        insta::assert_yaml_snapshot!(class.features(9));
+    }
+
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Barbarian;
+        let class = Barbarian::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Barbarian;
+        let class = Barbarian::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Barbarian;
+        let class = Barbarian::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Barbarian;
+        let class = Barbarian::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::AlignmentInfluences;
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, Features};
 use gear::{
     armor::{Armor, ArmorType},
     tools::{MusicalInstrument, Tool},
@@ -12,28 +14,188 @@ use gear::{
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
-    ability::{AbilityScoreType, AbilityScores},
+    ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        ExpertiseOption, HalfProficiency, Proficiencies, Proficiency, ProficiencyLevels,
+        ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Spells known at each level
+const SPELLS_KNOWN: [usize; 20] = [
+    4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 15, 15, 16, 18, 19, 19, 20, 22, 22, 22,
+];
+
+/// Bard colleges a bard can join, chosen at 3rd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum BardCollege {
+    #[strum(serialize = "College of Lore")]
+    Lore,
+    #[strum(serialize = "College of Valor")]
+    Valor,
+}
+
+impl Citations for BardCollege {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Lore => Citation(Book::Phb, 54),
+            Self::Valor => Citation(Book::Phb, 55),
+        }])
+    }
+}
+
+impl Features for BardCollege {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Lore => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Bonus Proficiencies",
+                        description: "You gain proficiency with three skills of your choice.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Cutting Words",
+                        description: "You can use your reaction to expend a use of Bardic Inspiration, subtracting the roll from a creature's attack roll, ability check or damage roll.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Additional Magical Secrets",
+                        description: "You learn two spells of your choice from any class.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Peerless Skill",
+                        description: "When you make an ability check, you can expend a use of Bardic Inspiration and add the roll to the check.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Valor => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Bonus Proficiencies",
+                        description: "You gain proficiency with medium armor, shields, and martial weapons.",
+                        citation: Citation(Book::Phb, 55),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Combat Inspiration",
+                        description: "A creature with your Bardic Inspiration die can add it to a weapon damage roll or to its AC against an attack.",
+                        citation: Citation(Book::Phb, 55),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Extra Attack",
+                        description: "You can attack twice, instead of once, whenever you take the Attack action on your turn.",
+                        citation: Citation(Book::Phb, 55),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Battle Magic",
+                        description: "When you use your action to cast a bard spell, you can make one weapon attack as a bonus action.",
+                        citation: Citation(Book::Phb, 55),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Proficiencies for BardCollege {
+    fn proficiencies(&self) -> Vec<Proficiency> {
+        match self {
+            Self::Lore => vec![],
+            Self::Valor => vec![
+                Proficiency::Armor(ArmorType::Medium),
+                Proficiency::Armor(ArmorType::Shield),
+                Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Martial)),
+            ],
+        }
+    }
+
+    fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        match self {
+            Self::Lore => vec![ProficiencyOption::Skill(None, 3)],
+            Self::Valor => vec![],
+        }
+    }
+}
+
+impl Subclass for BardCollege {
+    fn level() -> u8 {
+        3
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Lore => (
+                vec![AbilityScoreType::Intelligence],
+                vec![Skill::Arcana, Skill::History, Skill::Religion],
+            ),
+            Self::Valor => (
+                vec![AbilityScoreType::Strength, AbilityScoreType::Dexterity],
+                vec![Skill::Athletics],
+            ),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Bard;
+pub struct Bard {
+    /// Bard college, chosen at 3rd level
+    college: Option<BardCollege>,
+}
+
+impl AlignmentInfluences for Bard {}
 
 impl Backstory for Bard {}
 
 impl Citations for Bard {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 51)])
+        let mut citations = vec![Citation(Book::Phb, 51)];
+        if let Some(college) = self.college {
+            citations.extend(college.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Bard {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -42,9 +204,144 @@ impl Class for Bard {
             vec![AbilityScoreType::Dexterity],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D8
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Charisma]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        let mut proficiencies = vec![Proficiency::Armor(ArmorType::Light)];
+        if let Some(college) = self.college {
+            proficiencies.extend(college.proficiencies());
+        }
+        proficiencies
+    }
+
+    fn multiclass_addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        let mut addl_proficiencies = vec![
+            ProficiencyOption::MusicalInstrument(1),
+            ProficiencyOption::Skill(None, 1),
+        ];
+        if let Some(college) = self.college {
+            addl_proficiencies.extend(college.addl_proficiencies());
+        }
+        addl_proficiencies
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.college = BardCollege::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(5, Die::D4), 10)
+    }
 }
 
-impl Features for Bard {}
+impl Features for Bard {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Bardic Inspiration",
+                        description: "As a bonus action, you can give a creature within 60 feet an inspiration die to add to one ability check, attack roll or saving throw. You have a number of uses equal to your Charisma modifier per long rest.",
+                        citation: Citation(Book::Phb, 53),
+                        scaling: scaling_at(
+                            level,
+                            &[(1, "d6"), (5, "d8"), (10, "d10"), (15, "d12")],
+                        ),
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Jack of All Trades",
+                        description: "You can add half your proficiency bonus to any ability check that doesn't already include your proficiency bonus.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Song of Rest",
+                        description: "Friendly creatures who regain hit points during a short rest while hearing your performance regain extra hit points.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: scaling_at(
+                            level,
+                            &[(2, "d6"), (9, "d8"), (13, "d10"), (17, "d12")],
+                        ),
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Expertise",
+                        description: "Your proficiency bonus is doubled for ability checks with your chosen skill proficiencies.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: scaling_at(level, &[(3, "2 skills"), (10, "4 skills")]),
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Font of Inspiration",
+                        description: "You regain all expended uses of Bardic Inspiration when you finish a short or long rest.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Countercharm",
+                        description: "As an action, you can start a performance that gives friendly creatures within 30 feet advantage on saving throws against being frightened or charmed.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Magical Secrets",
+                        description: "You learn spells of your choice from any class, which count as bard spells for you.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: scaling_at(
+                            level,
+                            &[(10, "2 spells"), (14, "4 spells"), (18, "6 spells")],
+                        ),
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Superior Inspiration",
+                        description: "When you roll initiative and have no uses of Bardic Inspiration left, you regain one use.",
+                        citation: Citation(Book::Phb, 54),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(college) = self.college {
+            features.extend(college.features(level));
+        }
+        features
+    }
+}
 
 impl Languages for Bard {}
 
@@ -52,7 +349,7 @@ impl Pantheons for Bard {}
 
 impl Proficiencies for Bard {
     fn proficiencies(&self) -> Vec<Proficiency> {
-        vec![
+        let mut proficiencies = vec![
             Proficiency::Armor(ArmorType::Light),
             Proficiency::SavingThrow(AbilityScoreType::Charisma),
             Proficiency::SavingThrow(AbilityScoreType::Dexterity),
@@ -61,14 +358,52 @@ impl Proficiencies for Bard {
             Proficiency::Weapon(WeaponProficiency::Specific(Weapon::Longsword)),
             Proficiency::Weapon(WeaponProficiency::Specific(Weapon::Rapier)),
             Proficiency::Weapon(WeaponProficiency::Specific(Weapon::Shortsword)),
-        ]
+        ];
+        if let Some(college) = self.college {
+            proficiencies.extend(college.proficiencies());
+        }
+        proficiencies
     }
 
     fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
-        vec![
+        let mut addl_proficiencies = vec![
             ProficiencyOption::MusicalInstrument(3),
             ProficiencyOption::Skill(None, 3),
-        ]
+        ];
+        if let Some(college) = self.college {
+            addl_proficiencies.extend(college.addl_proficiencies());
+        }
+        addl_proficiencies
+    }
+}
+
+impl ProficiencyLevels for Bard {
+    fn expertise(&self, level: u8) -> Vec<ExpertiseOption> {
+        [3, 10]
+            .into_iter()
+            .filter(|&gained| gained <= level)
+            .map(|_| ExpertiseOption::Skill(2))
+            .collect()
+    }
+
+    fn half_proficiency(&self, level: u8) -> Option<HalfProficiency> {
+        // Jack of All Trades
+        (level >= 2).then_some(HalfProficiency {
+            abilities: None,
+            round_up: false,
+        })
+    }
+}
+
+impl Spellcasting for Bard {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Charisma,
+            caster_type: CasterType::Full,
+            cantrips: 2,
+            list: SpellList::Bard,
+            preparation: Preparation::Known(SPELLS_KNOWN),
+        })
     }
 }
 
@@ -113,7 +448,11 @@ impl StartingEquipment for Bard {
 
 impl fmt::Display for Bard {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Bard")
+        write!(f, "Bard")?;
+        if let Some(college) = self.college {
+            write!(f, ", {college}")?;
+        }
+        Ok(())
     }
 }
 
@@ -122,6 +461,7 @@ mod tests {
     use super::*;
     use rand::SeedableRng;
     use rand_pcg::Pcg64;
+    use stats::proficiencies::ProficiencyLevel;
 
     #[test]
     fn test_snapshot() {
@@ -144,31 +484,46 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Bard;
+        let class = Bard::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
+    #[test]
+    fn test_proficiency_levels() {
+        let class = Bard::default();
+        assert!(class.half_proficiency(1).is_none());
+        assert_eq!(
+            class.half_proficiency(2).unwrap().level(None),
+            ProficiencyLevel::Half
+        );
+        assert!(class.expertise(2).is_empty());
+        assert_eq!(
+            class.expertise(10),
+            vec![ExpertiseOption::Skill(2), ExpertiseOption::Skill(2)]
+        );
+    }
+
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Bard;
+        let class = Bard::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Bard;
+        let class = Bard::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Bard;
+        let class = Bard::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Bard;
+        let class = Bard::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
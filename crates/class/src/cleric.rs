@@ -1,38 +1,606 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Attitude, Morality};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, Features};
 use gear::{
     armor::{Armor, ArmorType},
     weapons::{Weapon, WeaponCategory},
 };
-use languages::Languages;
+use languages::{LanguageType, Languages};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        ExpertiseOption, Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption,
+        WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Divine domains a cleric can serve, chosen at 1st level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum DivineDomain {
+    #[strum(serialize = "Knowledge Domain")]
+    Knowledge,
+    #[strum(serialize = "Life Domain")]
+    Life,
+    #[strum(serialize = "Light Domain")]
+    Light,
+    #[strum(serialize = "Nature Domain")]
+    Nature,
+    #[strum(serialize = "Tempest Domain")]
+    Tempest,
+    #[strum(serialize = "Trickery Domain")]
+    Trickery,
+    #[strum(serialize = "War Domain")]
+    War,
+}
+
+impl AlignmentInfluences for DivineDomain {
+    fn attitude(&self) -> Vec<Attitude> {
+        match self {
+            Self::Knowledge
+            | Self::Life
+            | Self::Light
+            | Self::Nature
+            | Self::Tempest
+            | Self::War => vec![],
+            Self::Trickery => vec![Attitude::Chaotic],
+        }
+    }
+
+    fn morality(&self) -> Vec<Morality> {
+        match self {
+            Self::Knowledge | Self::Nature | Self::Tempest | Self::Trickery | Self::War => vec![],
+            Self::Life | Self::Light => vec![Morality::Good],
+        }
+    }
+}
+
+impl Citations for DivineDomain {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Knowledge => Citation(Book::Phb, 59),
+            Self::Life | Self::Light => Citation(Book::Phb, 60),
+            Self::Nature => Citation(Book::Phb, 61),
+            Self::Tempest | Self::Trickery => Citation(Book::Phb, 62),
+            Self::War => Citation(Book::Phb, 63),
+        }])
+    }
+}
+
+impl Features for DivineDomain {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Knowledge => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Blessings of Knowledge",
+                        description: "You learn two languages and become proficient in two of Arcana, History, Nature or Religion, doubling your proficiency bonus for them.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Knowledge of the Ages",
+                        description: "You can use your Channel Divinity to gain proficiency with a skill or tool for 10 minutes.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Channel Divinity: Read Thoughts",
+                        description: "You can use your Channel Divinity to read a creature's thoughts and cast suggestion on it without expending a spell slot.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Potent Spellcasting",
+                        description: "You add your Wisdom modifier to the damage you deal with any cleric cantrip.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Visions of the Past",
+                        description: "You can call up visions of the past that relate to an object you hold or your immediate surroundings.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Life => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Bonus Proficiency",
+                        description: "You gain proficiency with heavy armor.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Disciple of Life",
+                        description: "Your healing spells of 1st level or higher restore additional hit points equal to 2 + the spell's level.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Preserve Life",
+                        description: "You can use your Channel Divinity to restore hit points equal to five times your cleric level, divided among creatures within 30 feet.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Blessed Healer",
+                        description: "When you cast a healing spell of 1st level or higher on another creature, you regain 2 + the spell's level hit points.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Divine Strike",
+                        description: "Once on each of your turns when you hit with a weapon attack, you can deal an extra 1d8 radiant damage, increasing to 2d8 at 14th level.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Supreme Healing",
+                        description: "When you would roll dice to restore hit points with a spell, you use the highest number possible for each die instead.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Light => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Bonus Cantrip",
+                        description: "You gain the light cantrip if you don't already know it.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Warding Flare",
+                        description: "You can use your reaction to impose disadvantage on an attack roll against you from a creature you can see within 30 feet.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Radiance of the Dawn",
+                        description: "You can use your Channel Divinity to dispel magical darkness and deal 2d10 + your cleric level radiant damage to hostile creatures within 30 feet.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Improved Flare",
+                        description: "You can use Warding Flare when a creature attacks a creature other than you.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Potent Spellcasting",
+                        description: "You add your Wisdom modifier to the damage you deal with any cleric cantrip.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Corona of Light",
+                        description: "You can use your action to emit an aura of sunlight that gives enemies disadvantage on saving throws against fire or radiant spells.",
+                        citation: Citation(Book::Phb, 60),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Nature => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Acolyte of Nature",
+                        description: "You learn one druid cantrip and gain proficiency in Animal Handling, Nature or Survival.",
+                        citation: Citation(Book::Phb, 61),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Bonus Proficiency",
+                        description: "You gain proficiency with heavy armor.",
+                        citation: Citation(Book::Phb, 61),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Charm Animals and Plants",
+                        description: "You can use your Channel Divinity to charm beasts and plant creatures within 30 feet for 1 minute.",
+                        citation: Citation(Book::Phb, 61),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Dampen Elements",
+                        description: "When you or a creature within 30 feet takes acid, cold, fire, lightning or thunder damage, you can use your reaction to grant resistance to it.",
+                        citation: Citation(Book::Phb, 61),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Divine Strike",
+                        description: "Once on each of your turns when you hit with a weapon attack, you can deal an extra 1d8 cold, fire or lightning damage, increasing to 2d8 at 14th level.",
+                        citation: Citation(Book::Phb, 61),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Master of Nature",
+                        description: "You can use a bonus action to command animals and plants you have charmed.",
+                        citation: Citation(Book::Phb, 61),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Tempest => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Bonus Proficiencies",
+                        description: "You gain proficiency with martial weapons and heavy armor.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Wrath of the Storm",
+                        description: "When a creature within 5 feet hits you, you can use your reaction to deal 2d8 lightning or thunder damage to it.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Destructive Wrath",
+                        description: "You can use your Channel Divinity to deal maximum damage when you roll lightning or thunder damage.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Thunderbolt Strike",
+                        description: "When you deal lightning damage to a Large or smaller creature, you can push it up to 10 feet away from you.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Divine Strike",
+                        description: "Once on each of your turns when you hit with a weapon attack, you can deal an extra 1d8 thunder damage, increasing to 2d8 at 14th level.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Stormborn",
+                        description: "You have a flying speed equal to your walking speed whenever you are not underground or indoors.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Trickery => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Blessing of the Trickster",
+                        description: "You can use your action to give another willing creature advantage on Dexterity (Stealth) checks for 1 hour.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Invoke Duplicity",
+                        description: "You can use your Channel Divinity to create an illusory duplicate of yourself that you can cast spells through.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Channel Divinity: Cloak of Shadows",
+                        description: "You can use your Channel Divinity to become invisible until the end of your next turn.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Divine Strike",
+                        description: "Once on each of your turns when you hit with a weapon attack, you can deal an extra 1d8 poison damage, increasing to 2d8 at 14th level.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Improved Duplicity",
+                        description: "You can create up to four duplicates of yourself with Invoke Duplicity.",
+                        citation: Citation(Book::Phb, 62),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::War => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Bonus Proficiencies",
+                        description: "You gain proficiency with martial weapons and heavy armor.",
+                        citation: Citation(Book::Phb, 63),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "War Priest",
+                        description: "When you use the Attack action, you can make one weapon attack as a bonus action a number of times equal to your Wisdom modifier per long rest.",
+                        citation: Citation(Book::Phb, 63),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Guided Strike",
+                        description: "You can use your Channel Divinity to gain a +10 bonus to an attack roll.",
+                        citation: Citation(Book::Phb, 63),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Channel Divinity: War God's Blessing",
+                        description: "You can use your reaction and Channel Divinity to grant a creature within 30 feet a +10 bonus to an attack roll.",
+                        citation: Citation(Book::Phb, 63),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Divine Strike",
+                        description: "Once on each of your turns when you hit with a weapon attack, you can deal an extra 1d8 damage of the weapon's type, increasing to 2d8 at 14th level.",
+                        citation: Citation(Book::Phb, 63),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Avatar of Battle",
+                        description: "You gain resistance to bludgeoning, piercing and slashing damage from nonmagical weapons.",
+                        citation: Citation(Book::Phb, 63),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Languages for DivineDomain {
+    fn addl_languages(&self) -> (usize, Option<LanguageType>) {
+        match self {
+            Self::Knowledge => (2, None),
+            Self::Life
+            | Self::Light
+            | Self::Nature
+            | Self::Tempest
+            | Self::Trickery
+            | Self::War => (0, None),
+        }
+    }
+}
+
+impl Proficiencies for DivineDomain {
+    fn proficiencies(&self) -> Vec<Proficiency> {
+        match self {
+            Self::Knowledge | Self::Light | Self::Trickery => vec![],
+            Self::Life | Self::Nature => vec![Proficiency::Armor(ArmorType::Heavy)],
+            Self::Tempest | Self::War => vec![
+                Proficiency::Armor(ArmorType::Heavy),
+                Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Martial)),
+            ],
+        }
+    }
+
+    fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        match self {
+            Self::Knowledge => vec![ProficiencyOption::Skill(
+                Some(vec![
+                    Skill::Arcana,
+                    Skill::History,
+                    Skill::Nature,
+                    Skill::Religion,
+                ]),
+                2,
+            )],
+            Self::Life | Self::Light | Self::Tempest | Self::Trickery | Self::War => vec![],
+            Self::Nature => vec![ProficiencyOption::Skill(
+                Some(vec![Skill::AnimalHandling, Skill::Nature, Skill::Survival]),
+                1,
+            )],
+        }
+    }
+}
+
+impl ProficiencyLevels for DivineDomain {
+    fn expertise(&self, _: u8) -> Vec<ExpertiseOption> {
+        match self {
+            // Blessings of Knowledge
+            Self::Knowledge => vec![ExpertiseOption::From(
+                [
+                    Skill::Arcana,
+                    Skill::History,
+                    Skill::Nature,
+                    Skill::Religion,
+                ]
+                .into_iter()
+                .map(Proficiency::Skill)
+                .collect(),
+                2,
+            )],
+            _ => vec![],
+        }
+    }
+}
+
+impl Subclass for DivineDomain {
+    fn level() -> u8 {
+        1
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Knowledge => (
+                vec![AbilityScoreType::Intelligence],
+                vec![Skill::Arcana, Skill::History, Skill::Religion],
+            ),
+            Self::Life => (vec![], vec![Skill::Medicine]),
+            Self::Light => (vec![], vec![]),
+            Self::Nature => (
+                vec![],
+                vec![Skill::AnimalHandling, Skill::Nature, Skill::Survival],
+            ),
+            Self::Tempest => (vec![AbilityScoreType::Strength], vec![]),
+            Self::Trickery => (
+                vec![AbilityScoreType::Dexterity, AbilityScoreType::Charisma],
+                vec![Skill::Deception, Skill::Stealth],
+            ),
+            Self::War => (vec![AbilityScoreType::Strength], vec![Skill::Athletics]),
+        }
+    }
+
+    fn deity_domain(self) -> Option<Domain> {
+        match self {
+            Self::Knowledge => Some(Domain::Knowledge),
+            Self::Life => Some(Domain::Life),
+            Self::Light => Some(Domain::Light),
+            Self::Nature => Some(Domain::Nature),
+            Self::Tempest => Some(Domain::Tempest),
+            Self::Trickery => Some(Domain::Trickery),
+            Self::War => Some(Domain::War),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Cleric;
+pub struct Cleric {
+    /// Divine domain, chosen at 1st level
+    domain: Option<DivineDomain>,
+}
+
+impl AlignmentInfluences for Cleric {
+    fn attitude(&self) -> Vec<Attitude> {
+        self.domain
+            .map(|domain| domain.attitude())
+            .unwrap_or_default()
+    }
+
+    fn morality(&self) -> Vec<Morality> {
+        self.domain
+            .map(|domain| domain.morality())
+            .unwrap_or_default()
+    }
+}
 
 impl Backstory for Cleric {}
 
 impl Citations for Cleric {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 56)])
+        let mut citations = vec![Citation(Book::Phb, 56)];
+        if let Some(domain) = self.domain {
+            citations.extend(domain.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Cleric {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -41,32 +609,151 @@ impl Class for Cleric {
             vec![AbilityScoreType::Strength, AbilityScoreType::Constitution],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D8
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Wisdom]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        let mut proficiencies = vec![
+            Proficiency::Armor(ArmorType::Light),
+            Proficiency::Armor(ArmorType::Medium),
+            Proficiency::Armor(ArmorType::Shield),
+        ];
+        if let Some(domain) = self.domain {
+            proficiencies.extend(domain.proficiencies());
+        }
+        proficiencies
+    }
+
+    fn multiclass_addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        self.domain
+            .as_ref()
+            .map(Proficiencies::addl_proficiencies)
+            .unwrap_or_default()
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.domain = DivineDomain::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(5, Die::D4), 10)
+    }
 }
 
-impl Features for Cleric {}
+impl Features for Cleric {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity",
+                        description: "You can channel divine energy directly from your deity to fuel magical effects, regaining uses when you finish a short or long rest.",
+                        citation: Citation(Book::Phb, 58),
+                        scaling: scaling_at(level, &[(2, "1/rest"), (6, "2/rest"), (18, "3/rest")]),
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Channel Divinity: Turn Undead",
+                        description: "As an action, each undead that can see or hear you within 30 feet must make a Wisdom saving throw or be turned for 1 minute.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Destroy Undead",
+                        description: "When an undead fails its saving throw against your Turn Undead feature, it is instantly destroyed if its challenge rating is low enough.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: scaling_at(
+                            level,
+                            &[
+                                (5, "CR 1/2 or lower"),
+                                (8, "CR 1 or lower"),
+                                (11, "CR 2 or lower"),
+                                (14, "CR 3 or lower"),
+                                (17, "CR 4 or lower"),
+                            ],
+                        ),
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Divine Intervention",
+                        description: "You can use your action to implore your deity to intervene on your behalf.",
+                        citation: Citation(Book::Phb, 59),
+                        scaling: scaling_at(
+                            level,
+                            &[
+                                (10, "Succeeds on a d100 roll of your cleric level or lower"),
+                                (20, "Succeeds automatically"),
+                            ],
+                        ),
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(domain) = self.domain {
+            features.extend(domain.features(level));
+        }
+        features
+    }
+}
 
-impl Languages for Cleric {}
+impl Languages for Cleric {
+    fn addl_languages(&self) -> (usize, Option<LanguageType>) {
+        self.domain
+            .map(|domain| domain.addl_languages())
+            .unwrap_or_default()
+    }
+}
 
 impl Pantheons for Cleric {
     fn deity_required(&self) -> bool {
         true
     }
+
+    fn domain(&self) -> Option<Domain> {
+        self.domain.and_then(DivineDomain::deity_domain)
+    }
 }
 
 impl Proficiencies for Cleric {
     fn proficiencies(&self) -> Vec<Proficiency> {
-        vec![
+        let mut proficiencies = vec![
             Proficiency::Armor(ArmorType::Light),
             Proficiency::Armor(ArmorType::Medium),
             Proficiency::Armor(ArmorType::Shield),
             Proficiency::SavingThrow(AbilityScoreType::Charisma),
             Proficiency::SavingThrow(AbilityScoreType::Wisdom),
             Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Simple)),
-        ]
+        ];
+        if let Some(domain) = self.domain {
+            proficiencies.extend(domain.proficiencies());
+        }
+        proficiencies
     }
 
     fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
-        vec![ProficiencyOption::Skill(
+        let mut addl_proficiencies = vec![ProficiencyOption::Skill(
             Some(vec![
                 Skill::History,
                 Skill::Insight,
@@ -75,7 +762,32 @@ impl Proficiencies for Cleric {
                 Skill::Religion,
             ]),
             2,
-        )]
+        )];
+        if let Some(domain) = self.domain {
+            addl_proficiencies.extend(domain.addl_proficiencies());
+        }
+        addl_proficiencies
+    }
+}
+
+impl ProficiencyLevels for Cleric {
+    fn expertise(&self, level: u8) -> Vec<ExpertiseOption> {
+        self.domain
+            .as_ref()
+            .map(|d| d.expertise(level))
+            .unwrap_or_default()
+    }
+}
+
+impl Spellcasting for Cleric {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Wisdom,
+            caster_type: CasterType::Full,
+            cantrips: 3,
+            list: SpellList::Cleric,
+            preparation: Preparation::Prepared,
+        })
     }
 }
 
@@ -119,7 +831,11 @@ impl StartingEquipment for Cleric {
 
 impl fmt::Display for Cleric {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Cleric")
+        write!(f, "Cleric")?;
+        if let Some(domain) = self.domain {
+            write!(f, ", {domain}")?;
+        }
+        Ok(())
     }
 }
 
@@ -150,37 +866,55 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Cleric;
+        let class = Cleric::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_deity_required() {
-        let class = Cleric;
+        let class = Cleric::default();
         insta::assert_yaml_snapshot!(class.deity_required());
     }
 
+    #[test]
+    fn test_snapshot_features() {
+        let class = Cleric {
+            domain: Some(DivineDomain::Life),
+        };
+        insta::assert_yaml_snapshot!(class.features(5));
+    }
+
+    #[test]
+    fn test_domain() {
+        let class = Cleric {
+            domain: Some(DivineDomain::Tempest),
+        };
+        assert_eq!(class.to_string(), "Cleric, Tempest Domain");
+        assert!(class.domain() == Some(Domain::Tempest));
+        assert!(Cleric::default().domain().is_none());
+    }
+
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Cleric;
+        let class = Cleric::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Cleric;
+        let class = Cleric::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Cleric;
+        let class = Cleric::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Cleric;
+        let class = Cleric::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
@@ -1,39 +1,214 @@
 use std::fmt;
 
+use alignment::AlignmentInfluences;
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Condition, Feature, FeatureEffect, Features};
 use gear::{
     armor::{Armor, ArmorType},
+    damage::DamageType,
     tools::Tool,
     weapons::{Weapon, WeaponCategory, WeaponClassification},
 };
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Druid circles a druid can join, chosen at 2nd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum DruidCircle {
+    #[strum(serialize = "Circle of the Land")]
+    Land,
+    #[strum(serialize = "Circle of the Moon")]
+    Moon,
+}
+
+impl Citations for DruidCircle {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Land => Citation(Book::Phb, 68),
+            Self::Moon => Citation(Book::Phb, 69),
+        }])
+    }
+}
+
+impl Features for DruidCircle {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Land => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Bonus Cantrip",
+                        description: "You learn one additional druid cantrip of your choice.",
+                        citation: Citation(Book::Phb, 68),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Natural Recovery",
+                        description: "During a short rest, you can recover expended spell slots with a combined level equal to half your druid level.",
+                        citation: Citation(Book::Phb, 68),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Circle Spells",
+                        description: "You always have additional spells prepared based on the land where you became a druid.",
+                        citation: Citation(Book::Phb, 68),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Land's Stride",
+                        description: "Moving through nonmagical difficult terrain costs you no extra movement, and you have advantage against magically created plants.",
+                        citation: Citation(Book::Phb, 68),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Nature's Ward",
+                        description: "You can't be charmed or frightened by elementals or fey, and you are immune to poison and disease.",
+                        citation: Citation(Book::Phb, 68),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Nature's Sanctuary",
+                        description: "Beasts and plant creatures must make a Wisdom saving throw before attacking you.",
+                        citation: Citation(Book::Phb, 68),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Moon => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Combat Wild Shape",
+                        description: "You can use Wild Shape as a bonus action, and expend spell slots to regain hit points while transformed.",
+                        citation: Citation(Book::Phb, 69),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Circle Forms",
+                        description: "You can transform into beasts with a challenge rating as high as 1, and higher at later levels.",
+                        citation: Citation(Book::Phb, 69),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Primal Strike",
+                        description: "Your attacks in beast form count as magical.",
+                        citation: Citation(Book::Phb, 69),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Elemental Wild Shape",
+                        description: "You can expend two uses of Wild Shape to transform into an air, earth, fire or water elemental.",
+                        citation: Citation(Book::Phb, 69),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Thousand Forms",
+                        description: "You can cast the alter self spell at will.",
+                        citation: Citation(Book::Phb, 69),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        match self {
+            // Nature's Ward
+            Self::Land if level >= 10 => vec![
+                FeatureEffect::DamageImmunity(DamageType::Poison),
+                FeatureEffect::ConditionImmunity(Condition::Poisoned),
+            ],
+            Self::Land | Self::Moon => vec![],
+        }
+    }
+}
+
+impl Subclass for DruidCircle {
+    fn level() -> u8 {
+        2
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Land => (
+                vec![AbilityScoreType::Intelligence],
+                vec![Skill::Nature, Skill::Survival],
+            ),
+            Self::Moon => (
+                vec![AbilityScoreType::Strength, AbilityScoreType::Constitution],
+                vec![Skill::AnimalHandling],
+            ),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Druid;
+pub struct Druid {
+    /// Druid circle, chosen at 2nd level
+    circle: Option<DruidCircle>,
+}
+
+impl AlignmentInfluences for Druid {}
 
 impl Backstory for Druid {}
 
 impl Citations for Druid {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 64)])
+        let mut citations = vec![Citation(Book::Phb, 64)];
+        if let Some(circle) = self.circle {
+            citations.extend(circle.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Druid {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -42,9 +217,110 @@ impl Class for Druid {
             vec![AbilityScoreType::Constitution],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D8
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Wisdom]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![
+            Proficiency::Armor(ArmorType::Light),
+            Proficiency::Armor(ArmorType::Medium),
+            Proficiency::Armor(ArmorType::Shield),
+        ]
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.circle = DruidCircle::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(2, Die::D4), 10)
+    }
 }
 
-impl Features for Druid {}
+impl Features for Druid {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Druidic",
+                        description: "You know Druidic, the secret language of druids, and can use it to leave hidden messages.",
+                        citation: Citation(Book::Phb, 66),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Wild Shape",
+                        description: "You can use your action to magically assume the shape of a beast that you have seen before, twice per short or long rest.",
+                        citation: Citation(Book::Phb, 66),
+                        scaling: scaling_at(
+                            level,
+                            &[
+                                (2, "Max CR 1/4, no flying or swimming speed"),
+                                (4, "Max CR 1/2, no flying speed"),
+                                (8, "Max CR 1"),
+                            ],
+                        ),
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Timeless Body",
+                        description: "You age only one year for every 10 years that pass.",
+                        citation: Citation(Book::Phb, 67),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Beast Spells",
+                        description: "You can cast many of your druid spells in any shape you assume using Wild Shape.",
+                        citation: Citation(Book::Phb, 67),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Archdruid",
+                        description: "You can use your Wild Shape an unlimited number of times, and ignore the verbal, somatic and non-costly material components of your druid spells.",
+                        citation: Citation(Book::Phb, 67),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(circle) = self.circle {
+            features.extend(circle.features(level));
+        }
+        features
+    }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        self.circle
+            .map(|circle| circle.effects(level))
+            .unwrap_or_default()
+    }
+}
 
 impl Languages for Druid {}
 
@@ -89,6 +365,20 @@ impl Proficiencies for Druid {
     }
 }
 
+impl ProficiencyLevels for Druid {}
+
+impl Spellcasting for Druid {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Wisdom,
+            caster_type: CasterType::Full,
+            cantrips: 2,
+            list: SpellList::Druid,
+            preparation: Preparation::Prepared,
+        })
+    }
+}
+
 impl StartingEquipment for Druid {
     fn equipment(&self) -> Vec<Equipment> {
         let mut equipment = Pack::Explorer.equipment();
@@ -128,7 +418,11 @@ impl StartingEquipment for Druid {
 
 impl fmt::Display for Druid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Druid")
+        write!(f, "Druid")?;
+        if let Some(circle) = self.circle {
+            write!(f, ", {circle}")?;
+        }
+        Ok(())
     }
 }
 
@@ -159,31 +453,31 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Druid;
+        let class = Druid::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Druid;
+        let class = Druid::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Druid;
+        let class = Druid::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Druid;
+        let class = Druid::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Druid;
+        let class = Druid::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::AlignmentInfluences;
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, Features};
 use gear::{
     adventuring_gear::{Gear, OtherGear},
     armor::{Armor, ArmorType},
@@ -13,14 +15,23 @@ use itertools::Itertools;
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        HalfProficiency, Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption,
+        WeaponProficiency,
+    },
 };
-use strum::{EnumIter, IntoEnumIterator};
+use strum::{Display, EnumIter, IntoEnumIterator};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Spells known at each level as an Eldritch Knight
+const SPELLS_KNOWN: [usize; 20] = [
+    0, 0, 3, 4, 4, 4, 5, 6, 6, 7, 8, 8, 9, 10, 10, 11, 11, 11, 12, 13,
+];
 
 #[derive(Deserialize, EnumIter, Serialize)]
 enum Base {
@@ -34,22 +45,252 @@ impl Default for Base {
     }
 }
 
+/// Martial archetypes a fighter can emulate, chosen at 3rd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum MartialArchetype {
+    Champion,
+    #[strum(serialize = "Battle Master")]
+    BattleMaster,
+    #[strum(serialize = "Eldritch Knight")]
+    EldritchKnight,
+}
+
+impl Citations for MartialArchetype {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Champion => Citation(Book::Phb, 72),
+            Self::BattleMaster => Citation(Book::Phb, 73),
+            Self::EldritchKnight => Citation(Book::Phb, 74),
+        }])
+    }
+}
+
+impl Features for MartialArchetype {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Champion => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Improved Critical",
+                        description: "Your weapon attacks score a critical hit on a roll of 19 or 20.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Remarkable Athlete",
+                        description: "You add half your proficiency bonus to Strength, Dexterity and Constitution checks that don't already use it.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Additional Fighting Style",
+                        description: "You can choose a second option from the Fighting Style class feature.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Superior Critical",
+                        description: "Your weapon attacks score a critical hit on a roll of 18-20.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Survivor",
+                        description: "At the start of each of your turns, you regain 5 + your Constitution modifier hit points if you have no more than half your hit points left.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::BattleMaster => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Combat Superiority",
+                        description: "You learn maneuvers that are fueled by superiority dice.",
+                        citation: Citation(Book::Phb, 73),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Student of War",
+                        description: "You gain proficiency with one type of artisan's tools of your choice.",
+                        citation: Citation(Book::Phb, 73),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Know Your Enemy",
+                        description: "If you spend 1 minute observing a creature, you learn how it compares to you in two characteristics.",
+                        citation: Citation(Book::Phb, 73),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Improved Combat Superiority",
+                        description: "Your superiority dice turn into d12s.",
+                        citation: Citation(Book::Phb, 73),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Relentless",
+                        description: "When you roll initiative and have no superiority dice remaining, you regain one.",
+                        citation: Citation(Book::Phb, 73),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::EldritchKnight => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Spellcasting",
+                        description: "You can cast wizard spells, mostly from the abjuration and evocation schools, using Intelligence.",
+                        citation: Citation(Book::Phb, 74),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Weapon Bond",
+                        description: "You can bond with up to two weapons, which can't be disarmed and can be summoned to your hand as a bonus action.",
+                        citation: Citation(Book::Phb, 74),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "War Magic",
+                        description: "When you use your action to cast a cantrip, you can make one weapon attack as a bonus action.",
+                        citation: Citation(Book::Phb, 74),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Eldritch Strike",
+                        description: "A creature you hit with a weapon attack has disadvantage on its next saving throw against your spells.",
+                        citation: Citation(Book::Phb, 74),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Arcane Charge",
+                        description: "When you use Action Surge, you can teleport up to 30 feet.",
+                        citation: Citation(Book::Phb, 74),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Improved War Magic",
+                        description: "When you use your action to cast a spell, you can make one weapon attack as a bonus action.",
+                        citation: Citation(Book::Phb, 74),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Proficiencies for MartialArchetype {
+    fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        match self {
+            Self::Champion | Self::EldritchKnight => vec![],
+            Self::BattleMaster => vec![ProficiencyOption::ArtisansTools],
+        }
+    }
+}
+
+impl ProficiencyLevels for MartialArchetype {
+    fn half_proficiency(&self, level: u8) -> Option<HalfProficiency> {
+        // Remarkable Athlete
+        (matches!(self, Self::Champion) && level >= 7).then_some(HalfProficiency {
+            abilities: Some(vec![
+                AbilityScoreType::Strength,
+                AbilityScoreType::Dexterity,
+                AbilityScoreType::Constitution,
+            ]),
+            round_up: true,
+        })
+    }
+}
+
+impl Subclass for MartialArchetype {
+    fn level() -> u8 {
+        3
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Champion => (
+                vec![AbilityScoreType::Strength, AbilityScoreType::Constitution],
+                vec![Skill::Athletics],
+            ),
+            Self::BattleMaster => (
+                vec![AbilityScoreType::Intelligence],
+                vec![Skill::History, Skill::Insight],
+            ),
+            Self::EldritchKnight => (vec![AbilityScoreType::Intelligence], vec![Skill::Arcana]),
+        }
+    }
+}
+
 #[derive(Default, Deserialize, Serialize)]
 pub struct Fighter {
+    /// Martial archetype, chosen at 3rd level
+    archetype: Option<MartialArchetype>,
     base: Base,
 }
 
+impl AlignmentInfluences for Fighter {}
+
 impl Backstory for Fighter {}
 
 impl Citations for Fighter {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 70)])
+        let mut citations = vec![Citation(Book::Phb, 70)];
+        if let Some(archetype) = self.archetype {
+            citations.extend(archetype.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Fighter {
     fn gen(_: &mut impl Rng, ability_scores: &AbilityScores) -> Self {
         Self {
+            archetype: None,
             base: if ability_scores.modifier(AbilityScoreType::Dexterity)
                 > ability_scores.modifier(AbilityScoreType::Strength)
             {
@@ -69,9 +310,116 @@ impl Class for Fighter {
             ],
         )
     }
+
+    fn ability_score_improvement_levels() -> Vec<u8> {
+        vec![4, 6, 8, 12, 14, 16, 19]
+    }
+
+    fn hit_die() -> Die {
+        Die::D10
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![
+            AbilityScoreType::Strength,
+            AbilityScoreType::Dexterity,
+        ]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![
+            Proficiency::Armor(ArmorType::Light),
+            Proficiency::Armor(ArmorType::Medium),
+            Proficiency::Armor(ArmorType::Shield),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Simple)),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Martial)),
+        ]
+    }
+
+    fn multiclass_addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        self.archetype
+            .as_ref()
+            .map(Proficiencies::addl_proficiencies)
+            .unwrap_or_default()
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.archetype = MartialArchetype::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(5, Die::D4), 10)
+    }
 }
 
-impl Features for Fighter {}
+impl Features for Fighter {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Fighting Style",
+                        description: "You adopt a particular style of fighting as your specialty.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Second Wind",
+                        description: "On your turn, you can use a bonus action to regain hit points equal to 1d10 + your fighter level once per short or long rest.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Action Surge",
+                        description: "On your turn, you can take one additional action once per short or long rest.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: scaling_at(level, &[(2, "1 use"), (17, "2 uses")]),
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Extra Attack",
+                        description: "You can attack more than once whenever you take the Attack action on your turn.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: scaling_at(
+                            level,
+                            &[(5, "2 attacks"), (11, "3 attacks"), (20, "4 attacks")],
+                        ),
+                    },
+                ),
+                (
+                    9,
+                    Feature {
+                        title: "Indomitable",
+                        description: "You can reroll a saving throw that you fail, and must use the new roll.",
+                        citation: Citation(Book::Phb, 72),
+                        scaling: scaling_at(level, &[(9, "1 use"), (13, "2 uses"), (17, "3 uses")]),
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(archetype) = self.archetype {
+            features.extend(archetype.features(level));
+        }
+        features
+    }
+}
 
 impl Languages for Fighter {}
 
@@ -91,7 +439,7 @@ impl Proficiencies for Fighter {
     }
 
     fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
-        vec![ProficiencyOption::Skill(
+        let mut addl_proficiencies = vec![ProficiencyOption::Skill(
             Some(vec![
                 Skill::Acrobatics,
                 Skill::AnimalHandling,
@@ -103,7 +451,31 @@ impl Proficiencies for Fighter {
                 Skill::Survival,
             ]),
             2,
-        )]
+        )];
+        if let Some(archetype) = self.archetype {
+            addl_proficiencies.extend(archetype.addl_proficiencies());
+        }
+        addl_proficiencies
+    }
+}
+
+impl ProficiencyLevels for Fighter {
+    fn half_proficiency(&self, level: u8) -> Option<HalfProficiency> {
+        self.archetype
+            .as_ref()
+            .and_then(|a| a.half_proficiency(level))
+    }
+}
+
+impl Spellcasting for Fighter {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        matches!(self.archetype, Some(MartialArchetype::EldritchKnight)).then_some(Spellcaster {
+            ability: AbilityScoreType::Intelligence,
+            caster_type: CasterType::Third,
+            cantrips: 2,
+            list: SpellList::Wizard,
+            preparation: Preparation::Known(SPELLS_KNOWN),
+        })
     }
 }
 
@@ -144,7 +516,11 @@ impl StartingEquipment for Fighter {
 
 impl fmt::Display for Fighter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Fighter")
+        write!(f, "Fighter")?;
+        if let Some(archetype) = self.archetype {
+            write!(f, ", {archetype}")?;
+        }
+        Ok(())
     }
 }
 
@@ -153,6 +529,7 @@ mod tests {
     use super::*;
     use rand::SeedableRng;
     use rand_pcg::Pcg64;
+    use stats::proficiencies::ProficiencyLevel;
 
     #[test]
     fn test_snapshot() {
@@ -173,17 +550,56 @@ mod tests {
         insta::assert_yaml_snapshot!(Fighter::ability_rank());
     }
 
+    #[test]
+    fn test_ability_score_improvement_levels() {
+        assert_eq!(
+            Fighter::ability_score_improvement_levels(),
+            vec![4, 6, 8, 12, 14, 16, 19]
+        );
+    }
+
     #[test]
     fn test_snapshot_citations() {
         let class = Fighter {
+            archetype: None,
             base: Base::Dexterity,
         };
         insta::assert_yaml_snapshot!(class.citations());
     }
 
+    #[test]
+    fn test_spellcasting() {
+        let class = Fighter {
+            archetype: Some(MartialArchetype::EldritchKnight),
+            base: Base::Strength,
+        };
+        let spellcasting = class.spellcasting().unwrap();
+        assert_eq!(spellcasting.spell_slots(3), vec![2]);
+        assert_eq!(spellcasting.cantrips_known(3), 2);
+        assert!(Fighter::default().spellcasting().is_none());
+    }
+
+    #[test]
+    fn test_remarkable_athlete() {
+        let class = Fighter {
+            archetype: Some(MartialArchetype::Champion),
+            base: Base::Strength,
+        };
+        assert!(class.half_proficiency(6).is_none());
+        assert_eq!(
+            class
+                .half_proficiency(7)
+                .unwrap()
+                .level(Some(AbilityScoreType::Dexterity)),
+            ProficiencyLevel::HalfRoundedUp
+        );
+        assert!(Fighter::default().half_proficiency(20).is_none());
+    }
+
     #[test]
     fn test_snapshot_proficiencies() {
         let class = Fighter {
+            archetype: None,
             base: Base::Dexterity,
         };
         insta::assert_yaml_snapshot!(class.proficiencies());
@@ -192,6 +608,7 @@ mod tests {
     #[test]
     fn test_snapshot_addl_proficiencies() {
         let class = Fighter {
+            archetype: None,
             base: Base::Dexterity,
         };
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
@@ -200,13 +617,18 @@ mod tests {
     #[test]
     fn test_snapshot_equipment() {
         insta::assert_yaml_snapshot!(Base::iter()
-            .map(|base| (Fighter { base }).equipment())
+            .map(|base| (Fighter {
+                archetype: None,
+                base,
+            })
+            .equipment())
             .collect::<Vec<Vec<Equipment>>>());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
         let class = Fighter {
+            archetype: None,
             base: Base::Dexterity,
         };
         insta::assert_yaml_snapshot!(class.addl_equipment());
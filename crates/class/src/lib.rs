@@ -13,21 +13,28 @@ mod wizard;
 
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Attitude, Morality};
 use backstory::Backstory;
 use citation::{CitationList, Citations};
-use deities::{Pantheon, PantheonWeight, Pantheons};
-use features::{Feature, Features};
+use deities::{Domain, Pantheon, PantheonWeight, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, FeatureEffect, Features};
 use gear::currency::Coin;
 use itertools::Itertools;
 use languages::{Language, LanguageType, Languages};
 use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
+use spells::{Spellcaster, Spellcasting};
 use stats::{
-    ability::{exp_weight, AbilityScoreType, AbilityScores},
+    ability::{exp_weight, AbilityScore, AbilityScoreType, AbilityScores, Skill},
+    armor_class::UnarmoredDefense,
     equipment::{Equipment, EquipmentOption, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption},
+    proficiencies::{
+        ExpertiseOption, HalfProficiency, Proficiencies, Proficiency, ProficiencyLevels,
+        ProficiencyOption,
+    },
 };
-use strum::{EnumIter, IntoEnumIterator};
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoEnumIterator};
 
 use self::{
     barbarian::Barbarian, bard::Bard, cleric::Cleric, druid::Druid, fighter::Fighter, monk::Monk,
@@ -35,6 +42,11 @@ use self::{
     wizard::Wizard,
 };
 
+/// Levels at which most classes gain an Ability Score Improvement
+const ASI_LEVELS: [u8; 5] = [4, 8, 12, 16, 19];
+/// Score needed in an ability to multiclass into or out of a class that requires it
+const MULTICLASS_MINIMUM: i16 = 13;
+
 fn max_score_mod(types: &[AbilityScoreType], ability_scores: &AbilityScores) -> i16 {
     types
         .iter()
@@ -59,22 +71,151 @@ pub(crate) fn class_weight(
     exp_weight(weight, 0)
 }
 
+/// Features gained at or below a given level, from a list of the level each is gained at
+pub(crate) fn features_by_level(features: Vec<(u8, Feature)>, level: u8) -> Vec<Feature> {
+    features
+        .into_iter()
+        .filter(|&(gained, _)| gained <= level)
+        .map(|(_, feature)| feature)
+        .collect()
+}
+
+/// Value for a feature that scales with level, from a table of the level each value starts at
+pub(crate) fn scaling_at<T: fmt::Display>(level: u8, table: &[(u8, T)]) -> Option<String> {
+    table
+        .iter()
+        .rev()
+        .find(|&&(from, _)| from <= level)
+        .map(|(_, value)| value.to_string())
+}
+
+/// Shared behavior for the options (domains, oaths, paths, etc.) a class chooses its subclass from
+pub(crate) trait Subclass:
+    Citations + Copy + Features + IntoEnumIterator + fmt::Display
+{
+    /// Level the subclass is chosen at
+    fn level() -> u8;
+
+    /// Abilities and skills that suit this subclass
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>);
+
+    /// Domain of the deities this subclass serves, if any
+    fn deity_domain(self) -> Option<Domain> {
+        None
+    }
+
+    /// Weight based on the best suitable ability modifier, with a boost for each suitable skill
+    /// the character is already proficient in
+    fn weight(self, ability_scores: &AbilityScores, proficiencies: &[Proficiency]) -> f64 {
+        let (abilities, skills) = self.affinities();
+        let ability = max_score_mod(&abilities, ability_scores).max(0);
+        let skills = skills
+            .into_iter()
+            .filter(|&s| proficiencies.contains(&Proficiency::Skill(s)))
+            .count();
+        exp_weight(ability + 2 * i16::try_from(skills).unwrap_or_default(), 0)
+    }
+
+    /// Choose a subclass, if the character is high enough level to have one.
+    /// If any options serve one of the given deity domains, only those are considered.
+    fn gen(
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) -> Option<Self> {
+        if level < Self::level() {
+            return None;
+        }
+        let options = Self::iter().collect_vec();
+        let serving = options
+            .iter()
+            .copied()
+            .filter(|o| o.deity_domain().is_some_and(|d| domains.contains(&d)))
+            .collect_vec();
+        let options = if serving.is_empty() { options } else { serving };
+        options
+            .choose_weighted(rng, |o| o.weight(ability_scores, proficiencies))
+            .ok()
+            .copied()
+    }
+}
+
 pub trait Class:
-    Backstory
+    AlignmentInfluences
+    + Backstory
     + Citations
     + Features
     + Languages
     + Pantheons
     + Proficiencies
+    + ProficiencyLevels
+    + Spellcasting
     + StartingEquipment
     + fmt::Display
 {
     /// Generate new instance of class
     fn gen(rng: &mut impl Rng, ability_scores: &AbilityScores) -> Self;
 
+    /// Ability score increases gained from class features by the given level
+    fn abilities(&self, _level: u8) -> Vec<AbilityScore> {
+        vec![]
+    }
+
+    /// Abilities whose maximum score class features have raised above 20 by the given level
+    fn ability_maximums(&self, _level: u8) -> Vec<(AbilityScoreType, i16)> {
+        vec![]
+    }
+
     /// Return primary and secondary ability ranking
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>);
 
+    /// Levels at which the class gains an Ability Score Improvement
+    fn ability_score_improvement_levels() -> Vec<u8> {
+        ASI_LEVELS.to_vec()
+    }
+
+    /// Choose a subclass once the character reaches the level it is chosen at, weighted by ability
+    /// scores and the skills from their background. Prefers subclasses serving the given deity domains.
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    );
+
+    /// Die rolled for hit points each level
+    fn hit_die() -> Die;
+
+    /// Additional hit points gained each level
+    fn hit_points_per_level(&self) -> i16 {
+        0
+    }
+
+    /// Abilities needed to multiclass into or out of the class (PHB p163). Every group must have
+    /// at least one ability with a score of 13 or higher.
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>>;
+
+    /// Proficiencies gained when the class isn't the character's first class
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency>;
+
+    /// Additional proficiencies to choose when the class isn't the character's first class
+    fn multiclass_addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        vec![]
+    }
+
+    /// Dice rolled for starting gold, and what the roll is multiplied by, when taking gold instead of the
+    /// class's starting equipment (PHB p143)
+    fn starting_wealth() -> (RollCmd, u32);
+
+    /// Alternative way to calculate Armor Class when not wearing armor
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        None
+    }
+
     /// Weight based on quick build suggestions in Player's Handbook
     fn weight(ability_scores: &AbilityScores) -> f64 {
         let (primary, secondary) = Self::ability_rank();
@@ -83,22 +224,54 @@ pub trait Class:
 }
 
 #[impl_enum::with_methods {
-    fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {}
+    pub fn abilities(&self, level: u8) -> Vec<AbilityScore> {}
+    pub fn ability_maximums(&self, level: u8) -> Vec<(AbilityScoreType, i16)> {}
+    pub fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {}
+    pub fn ability_score_improvement_levels() -> Vec<u8> {}
     pub fn addl_equipment(&self) -> Vec<EquipmentOption> {}
     pub fn addl_languages(&self) -> (usize, Option<LanguageType>) {}
     pub fn addl_pantheons(&self) -> Vec<(Pantheon, PantheonWeight)> {}
     pub fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {}
+    pub fn attitude(&self) -> Vec<Attitude> {}
     pub fn backstory(&self) -> Vec<String> {}
     pub fn citations(&self) -> CitationList {}
     pub fn coins(&self) -> (Coin, u8) {}
     pub fn deity_required(&self) -> bool {}
+    pub fn domain(&self) -> Option<Domain> {}
+    pub fn effects(&self, level: u8) -> Vec<FeatureEffect> {}
     pub fn equipment(&self) -> Vec<Equipment> {}
-    pub fn features(&self) -> Vec<Feature> {}
+    pub fn expertise(&self, level: u8) -> Vec<ExpertiseOption> {}
+    pub fn features(&self, level: u8) -> Vec<Feature> {}
+    pub fn gen_subclass(&mut self, rng: &mut impl Rng, level: u8, ability_scores: &AbilityScores, proficiencies: &[Proficiency], domains: &[Domain]) {}
+    pub fn half_proficiency(&self, level: u8) -> Option<HalfProficiency> {}
+    pub fn hit_die() -> Die {}
+    pub fn hit_points_per_level(&self) -> i16 {}
     pub fn languages(&self) -> Vec<Language> {}
+    pub fn morality(&self) -> Vec<Morality> {}
+    pub fn multiclass_addl_proficiencies(&self) -> Vec<ProficiencyOption> {}
+    pub fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {}
+    pub fn multiclass_proficiencies(&self) -> Vec<Proficiency> {}
     pub fn proficiencies(&self) -> Vec<Proficiency> {}
+    pub fn spellcasting(&self) -> Option<Spellcaster> {}
+    pub fn starting_wealth() -> (RollCmd, u32) {}
+    pub fn unarmored_defense(&self) -> Option<UnarmoredDefense> {}
     pub fn weight(ability_scores: &AbilityScores) -> f64 {}
 }]
-#[derive(Deserialize, EnumIter, Serialize)]
+#[derive(Deserialize, EnumDiscriminants, EnumIter, Serialize)]
+#[strum_discriminants(
+    name(ClassType),
+    derive(
+        Deserialize,
+        Display,
+        EnumIter,
+        EnumString,
+        Hash,
+        Ord,
+        PartialOrd,
+        Serialize
+    ),
+    strum(ascii_case_insensitive)
+)]
 pub enum ClassOption {
     Barbarian(Barbarian),
     Bard(Bard),
@@ -117,33 +290,105 @@ pub enum ClassOption {
 impl ClassOption {
     /// Choose a random background option, weighted by ability scores
     pub fn gen(rng: &mut impl Rng, ability_scores: &AbilityScores) -> Self {
+        let candidates = Self::candidates(ability_scores);
+        let (class, _) = candidates.choose_weighted(rng, |(_, w)| *w).unwrap();
+        Self::gen_class(rng, ability_scores, *class)
+    }
+
+    /// Classes considered when choosing a class, with their weights. Only the classes whose primary
+    /// abilities have the highest modifier are considered, weighted by their primary and secondary abilities.
+    #[must_use]
+    pub fn candidates(ability_scores: &AbilityScores) -> Vec<(ClassType, f64)> {
         // Get options from optimal classes
-        let groups = Self::iter()
-            .map(|c| (max_score_mod(&c.ability_rank().0, ability_scores), c))
-            .sorted_by(|a, b| Ord::cmp(&b.0, &a.0))
-            .group_by(|&(m, _)| m);
-        let options = groups
-            .into_iter()
-            .sorted_by(|a, b| Ord::cmp(&b.0, &a.0))
-            .next()
-            .map(|(_, g)| g.map(|(_, o)| o).collect_vec())
-            .unwrap();
-        let option = options
-            .choose_weighted(rng, |o| o.weight(ability_scores))
-            .unwrap();
-        match option {
-            Self::Barbarian(_) => Self::Barbarian(Barbarian::gen(rng, ability_scores)),
-            Self::Bard(_) => Self::Bard(Bard::gen(rng, ability_scores)),
-            Self::Cleric(_) => Self::Cleric(Cleric::gen(rng, ability_scores)),
-            Self::Druid(_) => Self::Druid(Druid::gen(rng, ability_scores)),
-            Self::Fighter(_) => Self::Fighter(Fighter::gen(rng, ability_scores)),
-            Self::Monk(_) => Self::Monk(Monk::gen(rng, ability_scores)),
-            Self::Paladin(_) => Self::Paladin(Paladin::gen(rng, ability_scores)),
-            Self::Ranger(_) => Self::Ranger(Ranger::gen(rng, ability_scores)),
-            Self::Rogue(_) => Self::Rogue(Rogue::gen(rng, ability_scores)),
-            Self::Sorcerer(_) => Self::Sorcerer(Sorcerer::gen(rng, ability_scores)),
-            Self::Warlock(_) => Self::Warlock(Warlock::gen(rng, ability_scores)),
-            Self::Wizard(_) => Self::Wizard(Wizard::gen(rng, ability_scores)),
+        let best = Self::iter()
+            .map(|c| max_score_mod(&c.ability_rank().0, ability_scores))
+            .max()
+            .unwrap_or_default();
+        Self::iter()
+            .filter(|c| max_score_mod(&c.ability_rank().0, ability_scores) == best)
+            .map(|c| (ClassType::from(&c), c.weight(ability_scores)))
+            .collect()
+    }
+
+    /// Choose an additional class for a multiclassed character, weighted by ability scores.
+    /// The character must meet the prerequisites of both their current classes and the new one.
+    pub fn gen_multiclass(
+        rng: &mut impl Rng,
+        ability_scores: &AbilityScores,
+        current: &[ClassType],
+    ) -> Option<Self> {
+        let candidates = Self::multiclass_candidates(ability_scores, current);
+        let (class, _) = candidates.choose_weighted(rng, |(_, w)| *w).ok()?;
+        Some(Self::gen_class(rng, ability_scores, *class))
+    }
+
+    /// Classes considered when choosing an additional class for a multiclassed character, with their weights.
+    /// Empty if the character can't multiclass out of their current classes.
+    #[must_use]
+    pub fn multiclass_candidates(
+        ability_scores: &AbilityScores,
+        current: &[ClassType],
+    ) -> Vec<(ClassType, f64)> {
+        let allowed = |c: &Self| c.meets_multiclass_prerequisites(ability_scores);
+        if Self::iter().any(|c| current.contains(&(&c).into()) && !allowed(&c)) {
+            return vec![];
+        }
+        Self::iter()
+            .filter(|c| !current.contains(&c.into()) && allowed(c))
+            .map(|c| (ClassType::from(&c), c.weight(ability_scores)))
+            .collect_vec()
+    }
+
+    /// Whether the ability scores are high enough to multiclass into or out of this class
+    #[must_use]
+    pub fn meets_multiclass_prerequisites(&self, ability_scores: &AbilityScores) -> bool {
+        self.multiclass_prerequisites().iter().all(|group| {
+            group
+                .iter()
+                .any(|&a| ability_scores.score(a) >= MULTICLASS_MINIMUM)
+        })
+    }
+
+    /// Generate a specific class, making any choices within it based on ability scores
+    pub fn gen_class(
+        rng: &mut impl Rng,
+        ability_scores: &AbilityScores,
+        class_type: ClassType,
+    ) -> Self {
+        match class_type {
+            ClassType::Barbarian => Self::Barbarian(Barbarian::gen(rng, ability_scores)),
+            ClassType::Bard => Self::Bard(Bard::gen(rng, ability_scores)),
+            ClassType::Cleric => Self::Cleric(Cleric::gen(rng, ability_scores)),
+            ClassType::Druid => Self::Druid(Druid::gen(rng, ability_scores)),
+            ClassType::Fighter => Self::Fighter(Fighter::gen(rng, ability_scores)),
+            ClassType::Monk => Self::Monk(Monk::gen(rng, ability_scores)),
+            ClassType::Paladin => Self::Paladin(Paladin::gen(rng, ability_scores)),
+            ClassType::Ranger => Self::Ranger(Ranger::gen(rng, ability_scores)),
+            ClassType::Rogue => Self::Rogue(Rogue::gen(rng, ability_scores)),
+            ClassType::Sorcerer => Self::Sorcerer(Sorcerer::gen(rng, ability_scores)),
+            ClassType::Warlock => Self::Warlock(Warlock::gen(rng, ability_scores)),
+            ClassType::Wizard => Self::Wizard(Wizard::gen(rng, ability_scores)),
+        }
+    }
+}
+
+impl ClassType {
+    /// Return primary and secondary ability ranking for this class
+    #[must_use]
+    pub fn ability_rank(self) -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
+        match self {
+            Self::Barbarian => Barbarian::ability_rank(),
+            Self::Bard => Bard::ability_rank(),
+            Self::Cleric => Cleric::ability_rank(),
+            Self::Druid => Druid::ability_rank(),
+            Self::Fighter => Fighter::ability_rank(),
+            Self::Monk => Monk::ability_rank(),
+            Self::Paladin => Paladin::ability_rank(),
+            Self::Ranger => Ranger::ability_rank(),
+            Self::Rogue => Rogue::ability_rank(),
+            Self::Sorcerer => Sorcerer::ability_rank(),
+            Self::Warlock => Warlock::ability_rank(),
+            Self::Wizard => Wizard::ability_rank(),
         }
     }
 }
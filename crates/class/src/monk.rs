@@ -1,35 +1,204 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Attitude};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
-use gear::weapons::{Weapon, WeaponCategory};
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Condition, Feature, FeatureEffect, Features};
+use gear::{
+    damage::DamageType,
+    weapons::{Weapon, WeaponCategory},
+};
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::Spellcasting;
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
+    armor_class::UnarmoredDefense,
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Monastic traditions a monk can follow, chosen at 3rd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum MonasticTradition {
+    #[strum(serialize = "Way of the Open Hand")]
+    OpenHand,
+    #[strum(serialize = "Way of Shadow")]
+    Shadow,
+    #[strum(serialize = "Way of the Four Elements")]
+    FourElements,
+}
+
+impl AlignmentInfluences for MonasticTradition {
+    fn attitude(&self) -> Vec<Attitude> {
+        match self {
+            Self::OpenHand => vec![Attitude::Lawful],
+            Self::Shadow | Self::FourElements => vec![],
+        }
+    }
+}
+
+impl Citations for MonasticTradition {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::OpenHand => Citation(Book::Phb, 79),
+            Self::Shadow | Self::FourElements => Citation(Book::Phb, 80),
+        }])
+    }
+}
+
+impl Features for MonasticTradition {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::OpenHand => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Open Hand Technique",
+                        description: "When you hit with Flurry of Blows, you can knock the target prone, push it 15 feet away, or stop it taking reactions.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Wholeness of Body",
+                        description: "You can use your action to regain hit points equal to three times your monk level.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Tranquility",
+                        description: "At the end of a long rest, you gain the effect of a sanctuary spell.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Quivering Palm",
+                        description: "You can set up lethal vibrations in a creature you hit with an unarmed strike, and later end them to reduce it to 0 hit points.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Shadow => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Shadow Arts",
+                        description: "You can spend 2 ki points to cast darkness, darkvision, pass without trace or silence.",
+                        citation: Citation(Book::Phb, 80),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Shadow Step",
+                        description: "When you are in dim light or darkness, you can teleport up to 60 feet to another unoccupied space in dim light or darkness.",
+                        citation: Citation(Book::Phb, 80),
+                        scaling: None,
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Cloak of Shadows",
+                        description: "When you are in an area of dim light or darkness, you can use your action to become invisible.",
+                        citation: Citation(Book::Phb, 80),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Opportunist",
+                        description: "When a creature within 5 feet is hit by an attack made by someone else, you can use your reaction to attack it.",
+                        citation: Citation(Book::Phb, 80),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::FourElements => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Disciple of the Elements",
+                        description: "You learn elemental disciplines that you can fuel with ki points, learning more at 6th, 11th and 17th level.",
+                        citation: Citation(Book::Phb, 80),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
 
-use super::Class;
+impl Subclass for MonasticTradition {
+    fn level() -> u8 {
+        3
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::OpenHand => (
+                vec![AbilityScoreType::Dexterity],
+                vec![Skill::Athletics, Skill::Insight],
+            ),
+            Self::Shadow => (vec![AbilityScoreType::Dexterity], vec![Skill::Stealth]),
+            Self::FourElements => (
+                vec![AbilityScoreType::Wisdom],
+                vec![Skill::Arcana, Skill::Nature],
+            ),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Monk;
+pub struct Monk {
+    /// Monastic tradition, chosen at 3rd level
+    tradition: Option<MonasticTradition>,
+}
+
+impl AlignmentInfluences for Monk {
+    fn attitude(&self) -> Vec<Attitude> {
+        self.tradition
+            .map(|tradition| tradition.attitude())
+            .unwrap_or_default()
+    }
+}
 
 impl Backstory for Monk {}
 
 impl Citations for Monk {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 76)])
+        let mut citations = vec![Citation(Book::Phb, 76)];
+        if let Some(tradition) = self.tradition {
+            citations.extend(tradition.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Monk {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -38,9 +207,257 @@ impl Class for Monk {
             vec![AbilityScoreType::Wisdom],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D8
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![
+            vec![AbilityScoreType::Dexterity],
+            vec![AbilityScoreType::Wisdom],
+        ]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Simple)),
+            Proficiency::Weapon(WeaponProficiency::Specific(Weapon::Shortsword)),
+        ]
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.tradition = MonasticTradition::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(5, Die::D4), 1)
+    }
+
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        Some(UnarmoredDefense {
+            name: "Unarmored Defense",
+            base: 10,
+            abilities: vec![AbilityScoreType::Dexterity, AbilityScoreType::Wisdom],
+            shield: false,
+        })
+    }
 }
 
-impl Features for Monk {}
+impl Features for Monk {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Unarmored Defense",
+                        description: "While you are wearing no armor and not wielding a shield, your AC equals 10 + your Dexterity modifier + your Wisdom modifier.",
+                        citation: Citation(Book::Phb, 78),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Martial Arts",
+                        description: "While unarmed or wielding only monk weapons, you can use Dexterity for attacks, roll a Martial Arts die for damage, and make an unarmed strike as a bonus action.",
+                        citation: Citation(Book::Phb, 78),
+                        scaling: scaling_at(
+                            level,
+                            &[(1, "d4"), (5, "d6"), (11, "d8"), (17, "d10")],
+                        ),
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Ki",
+                        description: "You can spend ki points to fuel Flurry of Blows, Patient Defense and Step of the Wind, regaining them when you finish a short or long rest.",
+                        citation: Citation(Book::Phb, 78),
+                        scaling: Some(format!("{level} points")),
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Unarmored Movement",
+                        description: "Your speed increases while you aren't wearing armor or wielding a shield. From 9th level you can move along vertical surfaces and across liquids on your turn.",
+                        citation: Citation(Book::Phb, 78),
+                        scaling: scaling_at(
+                            level,
+                            &[
+                                (2, "+10 ft."),
+                                (6, "+15 ft."),
+                                (10, "+20 ft."),
+                                (14, "+25 ft."),
+                                (18, "+30 ft."),
+                            ],
+                        ),
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Deflect Missiles",
+                        description: "You can use your reaction to reduce the damage from a ranged weapon attack, and catch and throw back the missile.",
+                        citation: Citation(Book::Phb, 78),
+                        scaling: None,
+                    },
+                ),
+                (
+                    4,
+                    Feature {
+                        title: "Slow Fall",
+                        description: "You can use your reaction when you fall to reduce any falling damage you take by five times your monk level.",
+                        citation: Citation(Book::Phb, 78),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Extra Attack",
+                        description: "You can attack twice, instead of once, whenever you take the Attack action on your turn.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Stunning Strike",
+                        description: "When you hit with a melee weapon attack, you can spend 1 ki point to force the target to make a Constitution saving throw or be stunned.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Ki-Empowered Strikes",
+                        description: "Your unarmed strikes count as magical.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Evasion",
+                        description: "When you are subjected to an effect that allows a Dexterity saving throw for half damage, you take no damage on a success and half on a failure.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Stillness of Mind",
+                        description: "You can use your action to end one effect on yourself that is causing you to be charmed or frightened.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Purity of Body",
+                        description: "You are immune to disease and poison.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    13,
+                    Feature {
+                        title: "Tongue of the Sun and Moon",
+                        description: "You understand all spoken languages, and any creature that can understand a language can understand what you say.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Diamond Soul",
+                        description: "You gain proficiency in all saving throws, and can spend 1 ki point to reroll a failed one.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Timeless Body",
+                        description: "You no longer suffer the frailty of old age, and you don't need food or water.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Empty Body",
+                        description: "You can spend 4 ki points to become invisible for 1 minute, or 8 ki points to cast astral projection.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Perfect Self",
+                        description: "When you roll for initiative and have no ki points remaining, you regain 4 ki points.",
+                        citation: Citation(Book::Phb, 79),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(tradition) = self.tradition {
+            features.extend(tradition.features(level));
+        }
+        features
+    }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![];
+        // Unarmored Movement
+        let speed_bonus = match level {
+            0..=1 => None,
+            2..=5 => Some(10),
+            6..=9 => Some(15),
+            10..=13 => Some(20),
+            14..=17 => Some(25),
+            _ => Some(30),
+        };
+        effects.extend(speed_bonus.map(FeatureEffect::UnarmoredSpeedBonus));
+        // Purity of Body
+        if level >= 10 {
+            effects.extend([
+                FeatureEffect::DamageImmunity(DamageType::Poison),
+                FeatureEffect::ConditionImmunity(Condition::Poisoned),
+            ]);
+        }
+        // Diamond Soul
+        if level >= 14 {
+            effects.extend(
+                AbilityScoreType::iter()
+                    .map(|a| FeatureEffect::Proficiency(Proficiency::SavingThrow(a))),
+            );
+        }
+        effects
+    }
+}
 
 impl Languages for Monk {}
 
@@ -80,6 +497,10 @@ impl Proficiencies for Monk {
     }
 }
 
+impl ProficiencyLevels for Monk {}
+
+impl Spellcasting for Monk {}
+
 impl StartingEquipment for Monk {
     fn equipment(&self) -> Vec<Equipment> {
         vec![Equipment::new(Item::Weapon(Weapon::Dart), 10)]
@@ -104,7 +525,11 @@ impl StartingEquipment for Monk {
 
 impl fmt::Display for Monk {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Monk")
+        write!(f, "Monk")?;
+        if let Some(tradition) = self.tradition {
+            write!(f, ", {tradition}")?;
+        }
+        Ok(())
     }
 }
 
@@ -133,33 +558,64 @@ mod tests {
         insta::assert_yaml_snapshot!(Monk::ability_rank());
     }
 
+    #[test]
+    fn test_effects() {
+        let class = Monk::default();
+        assert!(class.effects(1).is_empty());
+        assert_eq!(
+            class.effects(9),
+            vec![FeatureEffect::UnarmoredSpeedBonus(15)]
+        );
+        assert_eq!(
+            class.effects(10),
+            vec![
+                FeatureEffect::UnarmoredSpeedBonus(20),
+                FeatureEffect::DamageImmunity(DamageType::Poison),
+                FeatureEffect::ConditionImmunity(Condition::Poisoned),
+            ]
+        );
+        assert_eq!(class.effects(14).len(), 9);
+        assert_eq!(class.effects(18)[0], FeatureEffect::UnarmoredSpeedBonus(30));
+    }
+
+    #[test]
+    fn test_multiclass_prerequisites() {
+        assert_eq!(
+            Monk::multiclass_prerequisites(),
+            vec![
+                vec![AbilityScoreType::Dexterity],
+                vec![AbilityScoreType::Wisdom]
+            ]
+        );
+    }
+
     #[test]
     fn test_snapshot_citations() {
-        let class = Monk;
+        let class = Monk::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Monk;
+        let class = Monk::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Monk;
+        let class = Monk::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Monk;
+        let class = Monk::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Monk;
+        let class = Monk::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
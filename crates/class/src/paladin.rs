@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Attitude, Morality};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Condition, Feature, FeatureEffect, Features};
 use gear::{
     armor::{Armor, ArmorType},
     weapons::{Weapon, WeaponCategory, WeaponClassification},
@@ -12,29 +14,286 @@ use itertools::Itertools;
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
-use strum::IntoEnumIterator;
+use strum::{Display, EnumIter, IntoEnumIterator};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Sacred oaths a paladin can swear, chosen at 3rd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum SacredOath {
+    #[strum(serialize = "Oath of Devotion")]
+    Devotion,
+    #[strum(serialize = "Oath of the Ancients")]
+    Ancients,
+    #[strum(serialize = "Oath of Vengeance")]
+    Vengeance,
+}
+
+impl AlignmentInfluences for SacredOath {
+    fn attitude(&self) -> Vec<Attitude> {
+        match self {
+            Self::Devotion => vec![Attitude::Lawful],
+            Self::Ancients | Self::Vengeance => vec![],
+        }
+    }
+
+    fn morality(&self) -> Vec<Morality> {
+        match self {
+            Self::Devotion | Self::Ancients => vec![Morality::Good],
+            Self::Vengeance => vec![],
+        }
+    }
+}
+
+impl Citations for SacredOath {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Devotion => Citation(Book::Phb, 85),
+            Self::Ancients => Citation(Book::Phb, 86),
+            Self::Vengeance => Citation(Book::Phb, 87),
+        }])
+    }
+}
+
+impl Features for SacredOath {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Devotion => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Tenets of Devotion",
+                        description: "You uphold the ideals of honesty, courage, compassion, honor and duty.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Channel Divinity: Sacred Weapon",
+                        description: "You can use your Channel Divinity to add your Charisma modifier to attack rolls with a weapon for 1 minute.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Channel Divinity: Turn the Unholy",
+                        description: "You can use your Channel Divinity to turn fiends and undead within 30 feet.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Aura of Devotion",
+                        description: "You and friendly creatures within 10 feet can't be charmed while you are conscious.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Purity of Spirit",
+                        description: "You are always under the effects of a protection from evil and good spell.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Holy Nimbus",
+                        description: "You can use your action to emanate an aura of sunlight that damages enemies for 1 minute.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Ancients => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Tenets of the Ancients",
+                        description: "You uphold the ideals of kindling the light, sheltering the light, preserving your own light and being the light.",
+                        citation: Citation(Book::Phb, 86),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Channel Divinity: Nature's Wrath",
+                        description: "You can use your Channel Divinity to restrain a creature within 10 feet with spectral vines.",
+                        citation: Citation(Book::Phb, 86),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Channel Divinity: Turn the Faithless",
+                        description: "You can use your Channel Divinity to turn fey and fiends within 30 feet.",
+                        citation: Citation(Book::Phb, 86),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Aura of Warding",
+                        description: "You and friendly creatures within 10 feet have resistance to damage from spells.",
+                        citation: Citation(Book::Phb, 86),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Undying Sentinel",
+                        description: "When you are reduced to 0 hit points, you can drop to 1 hit point instead once per long rest, and you no longer suffer the frailty of old age.",
+                        citation: Citation(Book::Phb, 86),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Elder Champion",
+                        description: "You can use your action to assume the form of an ancient force of nature for 1 minute.",
+                        citation: Citation(Book::Phb, 86),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Vengeance => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Tenets of Vengeance",
+                        description: "You uphold the ideals of fighting the greater evil, showing no mercy to the wicked, and winning by any means necessary.",
+                        citation: Citation(Book::Phb, 87),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Channel Divinity: Abjure Enemy",
+                        description: "You can use your Channel Divinity to frighten a creature within 60 feet and reduce its speed to 0.",
+                        citation: Citation(Book::Phb, 87),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Channel Divinity: Vow of Enmity",
+                        description: "You can use your Channel Divinity to gain advantage on attack rolls against a creature within 10 feet for 1 minute.",
+                        citation: Citation(Book::Phb, 87),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Relentless Avenger",
+                        description: "When you hit a creature with an opportunity attack, you can move up to half your speed immediately afterward.",
+                        citation: Citation(Book::Phb, 87),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Soul of Vengeance",
+                        description: "When a creature under your Vow of Enmity makes an attack, you can use your reaction to make a melee weapon attack against it.",
+                        citation: Citation(Book::Phb, 87),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Avenging Angel",
+                        description: "You can use your action to sprout wings and emanate an aura of menace for 1 hour.",
+                        citation: Citation(Book::Phb, 87),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        match self {
+            // Aura of Devotion
+            Self::Devotion if level >= 7 => {
+                vec![FeatureEffect::ConditionImmunity(Condition::Charmed)]
+            }
+            Self::Devotion | Self::Ancients | Self::Vengeance => vec![],
+        }
+    }
+}
+
+impl Subclass for SacredOath {
+    fn level() -> u8 {
+        3
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Devotion => (vec![], vec![Skill::Persuasion, Skill::Religion]),
+            Self::Ancients => (vec![], vec![Skill::AnimalHandling, Skill::Nature]),
+            Self::Vengeance => (vec![], vec![Skill::Intimidation, Skill::Investigation]),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Paladin;
+pub struct Paladin {
+    /// Sacred oath, chosen at 3rd level
+    oath: Option<SacredOath>,
+}
+
+impl AlignmentInfluences for Paladin {
+    fn attitude(&self) -> Vec<Attitude> {
+        self.oath.map(|oath| oath.attitude()).unwrap_or_default()
+    }
+
+    fn morality(&self) -> Vec<Morality> {
+        self.oath.map(|oath| oath.morality()).unwrap_or_default()
+    }
+}
 
 impl Backstory for Paladin {}
 
 impl Citations for Paladin {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 82)])
+        let mut citations = vec![Citation(Book::Phb, 82)];
+        if let Some(oath) = self.oath {
+            citations.extend(oath.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Paladin {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -43,9 +302,153 @@ impl Class for Paladin {
             vec![AbilityScoreType::Charisma],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D10
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![
+            vec![AbilityScoreType::Strength],
+            vec![AbilityScoreType::Charisma],
+        ]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![
+            Proficiency::Armor(ArmorType::Light),
+            Proficiency::Armor(ArmorType::Medium),
+            Proficiency::Armor(ArmorType::Shield),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Simple)),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Martial)),
+        ]
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.oath = SacredOath::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(5, Die::D4), 10)
+    }
 }
 
-impl Features for Paladin {}
+impl Features for Paladin {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Divine Sense",
+                        description: "As an action, you can detect the presence of celestials, fiends and undead within 60 feet, a number of times equal to 1 + your Charisma modifier per long rest.",
+                        citation: Citation(Book::Phb, 84),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Lay on Hands",
+                        description: "You have a pool of healing power that can restore hit points or cure diseases and poisons with a touch, replenished when you finish a long rest.",
+                        citation: Citation(Book::Phb, 84),
+                        scaling: Some(format!("{} hit points", 5 * u16::from(level))),
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Fighting Style",
+                        description: "You adopt a particular style of fighting as your specialty.",
+                        citation: Citation(Book::Phb, 84),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Divine Smite",
+                        description: "When you hit with a melee weapon attack, you can expend a spell slot to deal extra radiant damage.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Divine Health",
+                        description: "You are immune to disease.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Extra Attack",
+                        description: "You can attack twice, instead of once, whenever you take the Attack action on your turn.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Aura of Protection",
+                        description: "Whenever you or a friendly creature within range of your aura must make a saving throw, it gains a bonus equal to your Charisma modifier.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: scaling_at(level, &[(6, "10 ft."), (18, "30 ft.")]),
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Aura of Courage",
+                        description: "You and friendly creatures within range of your aura can't be frightened while you are conscious.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: scaling_at(level, &[(10, "10 ft."), (18, "30 ft.")]),
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Improved Divine Smite",
+                        description: "Whenever you hit a creature with a melee weapon, it takes an extra 1d8 radiant damage.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Cleansing Touch",
+                        description: "You can use your action to end one spell on yourself or a willing creature you touch.",
+                        citation: Citation(Book::Phb, 85),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(oath) = self.oath {
+            features.extend(oath.features(level));
+        }
+        features
+    }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        self.oath
+            .map(|oath| oath.effects(level))
+            .unwrap_or_default()
+    }
+}
 
 impl Languages for Paladin {}
 
@@ -83,6 +486,20 @@ impl Proficiencies for Paladin {
     }
 }
 
+impl ProficiencyLevels for Paladin {}
+
+impl Spellcasting for Paladin {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Charisma,
+            caster_type: CasterType::Half,
+            cantrips: 0,
+            list: SpellList::Paladin,
+            preparation: Preparation::PreparedHalfLevel,
+        })
+    }
+}
+
 impl StartingEquipment for Paladin {
     fn equipment(&self) -> Vec<Equipment> {
         vec![Equipment::new(Item::Armor(Armor::ChainMail), 1)]
@@ -120,7 +537,11 @@ impl StartingEquipment for Paladin {
 
 impl fmt::Display for Paladin {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Paladin")
+        write!(f, "Paladin")?;
+        if let Some(oath) = self.oath {
+            write!(f, ", {oath}")?;
+        }
+        Ok(())
     }
 }
 
@@ -151,37 +572,37 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Paladin;
+        let class = Paladin::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_deity_required() {
-        let class = Paladin;
+        let class = Paladin::default();
         insta::assert_yaml_snapshot!(class.deity_required());
     }
 
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Paladin;
+        let class = Paladin::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Paladin;
+        let class = Paladin::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Paladin;
+        let class = Paladin::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Paladin;
+        let class = Paladin::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::AlignmentInfluences;
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, Features};
 use gear::{
     adventuring_gear::{Gear, OtherGear},
     armor::{Armor, ArmorType},
@@ -12,28 +14,159 @@ use gear::{
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Spells known at each level
+const SPELLS_KNOWN: [usize; 20] = [
+    0, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11,
+];
+
+/// Ranger archetypes a ranger can emulate, chosen at 3rd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum RangerArchetype {
+    Hunter,
+    #[strum(serialize = "Beast Master")]
+    BeastMaster,
+}
+
+impl Citations for RangerArchetype {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Hunter | Self::BeastMaster => Citation(Book::Phb, 93),
+        }])
+    }
+}
+
+impl Features for RangerArchetype {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Hunter => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Hunter's Prey",
+                        description: "You choose Colossus Slayer, Giant Killer or Horde Breaker.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Defensive Tactics",
+                        description: "You choose Escape the Horde, Multiattack Defense or Steel Will.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Multiattack",
+                        description: "You choose Volley or Whirlwind Attack.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Superior Hunter's Defense",
+                        description: "You choose Evasion, Stand Against the Tide or Uncanny Dodge.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::BeastMaster => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Ranger's Companion",
+                        description: "You gain a beast companion that accompanies you on your adventures and fights alongside you.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Exceptional Training",
+                        description: "Your beast companion can Dash, Disengage, Dodge or Help as a bonus action, and its attacks count as magical.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Bestial Fury",
+                        description: "Your beast companion can make two attacks when you command it to use the Attack action.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Share Spells",
+                        description: "When you cast a spell targeting yourself, you can also affect your beast companion if it is within 30 feet.",
+                        citation: Citation(Book::Phb, 93),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Subclass for RangerArchetype {
+    fn level() -> u8 {
+        3
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Hunter => (vec![AbilityScoreType::Dexterity], vec![Skill::Survival]),
+            Self::BeastMaster => (vec![AbilityScoreType::Wisdom], vec![Skill::AnimalHandling]),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Ranger;
+pub struct Ranger {
+    /// Ranger archetype, chosen at 3rd level
+    archetype: Option<RangerArchetype>,
+}
+
+impl AlignmentInfluences for Ranger {}
 
 impl Backstory for Ranger {}
 
 impl Citations for Ranger {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 89)])
+        let mut citations = vec![Citation(Book::Phb, 89)];
+        if let Some(archetype) = self.archetype {
+            citations.extend(archetype.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Ranger {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -42,9 +175,164 @@ impl Class for Ranger {
             vec![AbilityScoreType::Wisdom],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D10
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![
+            vec![AbilityScoreType::Dexterity],
+            vec![AbilityScoreType::Wisdom],
+        ]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![
+            Proficiency::Armor(ArmorType::Light),
+            Proficiency::Armor(ArmorType::Medium),
+            Proficiency::Armor(ArmorType::Shield),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Simple)),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Martial)),
+        ]
+    }
+
+    fn multiclass_addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        // One skill from the class's skill list
+        self.addl_proficiencies()
+            .into_iter()
+            .map(|option| match option {
+                ProficiencyOption::Skill(skills, _) => ProficiencyOption::Skill(skills, 1),
+                option => option,
+            })
+            .collect()
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.archetype = RangerArchetype::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(5, Die::D4), 10)
+    }
 }
 
-impl Features for Ranger {}
+impl Features for Ranger {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Favored Enemy",
+                        description: "You have advantage on Wisdom (Survival) checks to track your favored enemies, and on Intelligence checks to recall information about them.",
+                        citation: Citation(Book::Phb, 91),
+                        scaling: scaling_at(
+                            level,
+                            &[(1, "1 type"), (6, "2 types"), (14, "3 types")],
+                        ),
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Natural Explorer",
+                        description: "You are particularly familiar with one type of natural environment and are adept at traveling and surviving in it.",
+                        citation: Citation(Book::Phb, 91),
+                        scaling: scaling_at(
+                            level,
+                            &[(1, "1 terrain"), (6, "2 terrains"), (10, "3 terrains")],
+                        ),
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Fighting Style",
+                        description: "You adopt a particular style of fighting as your specialty.",
+                        citation: Citation(Book::Phb, 91),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Primeval Awareness",
+                        description: "You can expend a spell slot to sense whether certain types of creatures are within 1 mile of you.",
+                        citation: Citation(Book::Phb, 92),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Extra Attack",
+                        description: "You can attack twice, instead of once, whenever you take the Attack action on your turn.",
+                        citation: Citation(Book::Phb, 92),
+                        scaling: None,
+                    },
+                ),
+                (
+                    8,
+                    Feature {
+                        title: "Land's Stride",
+                        description: "Moving through nonmagical difficult terrain costs you no extra movement, and you have advantage against magically created plants.",
+                        citation: Citation(Book::Phb, 92),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Hide in Plain Sight",
+                        description: "You can spend 1 minute creating camouflage, gaining a +10 bonus to Stealth checks as long as you remain still.",
+                        citation: Citation(Book::Phb, 92),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Vanish",
+                        description: "You can use the Hide action as a bonus action, and you can't be tracked by nonmagical means.",
+                        citation: Citation(Book::Phb, 92),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Feral Senses",
+                        description: "Being unable to see a creature doesn't impose disadvantage on your attacks against it, and you are aware of invisible creatures within 30 feet.",
+                        citation: Citation(Book::Phb, 92),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Foe Slayer",
+                        description: "Once on each of your turns, you can add your Wisdom modifier to an attack or damage roll against one of your favored enemies.",
+                        citation: Citation(Book::Phb, 92),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(archetype) = self.archetype {
+            features.extend(archetype.features(level));
+        }
+        features
+    }
+}
 
 impl Languages for Ranger {}
 
@@ -80,6 +368,20 @@ impl Proficiencies for Ranger {
     }
 }
 
+impl ProficiencyLevels for Ranger {}
+
+impl Spellcasting for Ranger {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Wisdom,
+            caster_type: CasterType::Half,
+            cantrips: 0,
+            list: SpellList::Ranger,
+            preparation: Preparation::Known(SPELLS_KNOWN),
+        })
+    }
+}
+
 impl StartingEquipment for Ranger {
     fn equipment(&self) -> Vec<Equipment> {
         vec![
@@ -119,7 +421,11 @@ impl StartingEquipment for Ranger {
 
 impl fmt::Display for Ranger {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Ranger")
+        write!(f, "Ranger")?;
+        if let Some(archetype) = self.archetype {
+            write!(f, ", {archetype}")?;
+        }
+        Ok(())
     }
 }
 
@@ -150,31 +456,31 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Ranger;
+        let class = Ranger::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Ranger;
+        let class = Ranger::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Ranger;
+        let class = Ranger::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Ranger;
+        let class = Ranger::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Ranger;
+        let class = Ranger::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
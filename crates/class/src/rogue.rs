@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Morality};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, FeatureEffect, Features};
 use gear::{
     armor::{Armor, ArmorType},
     tools::Tool,
@@ -12,28 +14,263 @@ use gear::{
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        ExpertiseOption, Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption,
+        WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter, IntoEnumIterator};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Spells known at each level as an Arcane Trickster
+const SPELLS_KNOWN: [usize; 20] = [
+    0, 0, 3, 4, 4, 4, 5, 6, 6, 7, 8, 8, 9, 10, 10, 11, 11, 11, 12, 13,
+];
+
+/// Roguish archetypes a rogue can emulate, chosen at 3rd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum RoguishArchetype {
+    Thief,
+    Assassin,
+    #[strum(serialize = "Arcane Trickster")]
+    ArcaneTrickster,
+}
+
+impl AlignmentInfluences for RoguishArchetype {
+    fn morality(&self) -> Vec<Morality> {
+        match self {
+            Self::Thief | Self::ArcaneTrickster => vec![],
+            Self::Assassin => vec![Morality::Evil],
+        }
+    }
+}
+
+impl Citations for RoguishArchetype {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Thief | Self::Assassin | Self::ArcaneTrickster => Citation(Book::Phb, 97),
+        }])
+    }
+}
+
+impl Features for RoguishArchetype {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Thief => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Fast Hands",
+                        description: "You can use the bonus action granted by Cunning Action to make a Sleight of Hand check, use thieves' tools, or take the Use an Object action.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Second-Story Work",
+                        description: "Climbing no longer costs you extra movement, and your running jump distance increases by your Dexterity modifier.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    9,
+                    Feature {
+                        title: "Supreme Sneak",
+                        description: "You have advantage on Stealth checks if you move no more than half your speed on the same turn.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    13,
+                    Feature {
+                        title: "Use Magic Device",
+                        description: "You ignore all class, race and level requirements on the use of magic items.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Thief's Reflexes",
+                        description: "You can take two turns during the first round of any combat.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Assassin => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Bonus Proficiencies",
+                        description: "You gain proficiency with the disguise kit and the poisoner's kit.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Assassinate",
+                        description: "You have advantage on attack rolls against creatures that haven't taken a turn yet, and any hit against a surprised creature is a critical hit.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    9,
+                    Feature {
+                        title: "Infiltration Expertise",
+                        description: "You can unfailingly create false identities for yourself.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    13,
+                    Feature {
+                        title: "Impostor",
+                        description: "You can unerringly mimic another person's speech, writing and behavior.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Death Strike",
+                        description: "When you hit a surprised creature, it must make a Constitution saving throw or take double damage.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::ArcaneTrickster => vec![
+                (
+                    3,
+                    Feature {
+                        title: "Spellcasting",
+                        description: "You can cast wizard spells, mostly from the enchantment and illusion schools, using Intelligence.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Mage Hand Legerdemain",
+                        description: "Your mage hand is invisible, and you can use it to stow or retrieve objects, pick locks and disarm traps.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    9,
+                    Feature {
+                        title: "Magical Ambush",
+                        description: "If you are hidden when you cast a spell on a creature, it has disadvantage on any saving throw against the spell.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    13,
+                    Feature {
+                        title: "Versatile Trickster",
+                        description: "You can use your mage hand to gain advantage on attack rolls against a creature within 5 feet of it.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+                (
+                    17,
+                    Feature {
+                        title: "Spell Thief",
+                        description: "When a creature casts a spell targeting you, you can use your reaction to steal the knowledge of it.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Proficiencies for RoguishArchetype {
+    fn proficiencies(&self) -> Vec<Proficiency> {
+        match self {
+            Self::Thief | Self::ArcaneTrickster => vec![],
+            Self::Assassin => vec![
+                Proficiency::Tool(Tool::DisguiseKit),
+                Proficiency::Tool(Tool::PoisonerKit),
+            ],
+        }
+    }
+}
+
+impl Subclass for RoguishArchetype {
+    fn level() -> u8 {
+        3
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Thief => (
+                vec![AbilityScoreType::Dexterity],
+                vec![Skill::Athletics, Skill::SleightOfHand],
+            ),
+            Self::Assassin => (
+                vec![AbilityScoreType::Dexterity],
+                vec![Skill::Deception, Skill::Stealth],
+            ),
+            Self::ArcaneTrickster => (
+                vec![AbilityScoreType::Intelligence],
+                vec![Skill::Arcana, Skill::SleightOfHand],
+            ),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Rogue;
+pub struct Rogue {
+    /// Roguish archetype, chosen at 3rd level
+    archetype: Option<RoguishArchetype>,
+}
+
+impl AlignmentInfluences for Rogue {
+    fn morality(&self) -> Vec<Morality> {
+        self.archetype
+            .map(|archetype| archetype.morality())
+            .unwrap_or_default()
+    }
+}
 
 impl Backstory for Rogue {}
 
 impl Citations for Rogue {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 94)])
+        let mut citations = vec![Citation(Book::Phb, 94)];
+        if let Some(archetype) = self.archetype {
+            citations.extend(archetype.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Rogue {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -42,9 +279,183 @@ impl Class for Rogue {
             vec![AbilityScoreType::Charisma, AbilityScoreType::Intelligence],
         )
     }
+
+    fn ability_score_improvement_levels() -> Vec<u8> {
+        vec![4, 8, 10, 12, 16, 19]
+    }
+
+    fn hit_die() -> Die {
+        Die::D8
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Dexterity]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        let mut proficiencies = vec![
+            Proficiency::Armor(ArmorType::Light),
+            Proficiency::Tool(Tool::ThievesTools),
+        ];
+        if let Some(archetype) = self.archetype {
+            proficiencies.extend(archetype.proficiencies());
+        }
+        proficiencies
+    }
+
+    fn multiclass_addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        // One skill from the class's skill list
+        self.addl_proficiencies()
+            .into_iter()
+            .map(|option| match option {
+                ProficiencyOption::Skill(skills, _) => ProficiencyOption::Skill(skills, 1),
+                option => option,
+            })
+            .collect()
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.archetype = RoguishArchetype::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(4, Die::D4), 10)
+    }
 }
 
-impl Features for Rogue {}
+impl Features for Rogue {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Expertise",
+                        description: "Your proficiency bonus is doubled for ability checks with your chosen skill proficiencies or thieves' tools.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: scaling_at(
+                            level,
+                            &[(1, "2 proficiencies"), (6, "4 proficiencies")],
+                        ),
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Sneak Attack",
+                        description: "Once per turn, you can deal extra damage to a creature you hit with a finesse or ranged weapon if you have advantage on the attack, or another enemy of the target is within 5 feet of it.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: Some(format!("{}d6", level.div_ceil(2))),
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Thieves' Cant",
+                        description: "You know thieves' cant, a secret mix of dialect, jargon and code that allows you to hide messages in seemingly normal conversation.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Cunning Action",
+                        description: "You can take a bonus action on each of your turns to Dash, Disengage or Hide.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    5,
+                    Feature {
+                        title: "Uncanny Dodge",
+                        description: "When an attacker that you can see hits you with an attack, you can use your reaction to halve the attack's damage.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    7,
+                    Feature {
+                        title: "Evasion",
+                        description: "When you are subjected to an effect that allows a Dexterity saving throw for half damage, you take no damage on a success and half on a failure.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Reliable Talent",
+                        description: "Whenever you make an ability check that lets you add your proficiency bonus, you can treat a d20 roll of 9 or lower as a 10.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Blindsense",
+                        description: "If you are able to hear, you are aware of the location of any hidden or invisible creature within 10 feet of you.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    15,
+                    Feature {
+                        title: "Slippery Mind",
+                        description: "You gain proficiency in Wisdom saving throws.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Elusive",
+                        description: "No attack roll has advantage against you while you aren't incapacitated.",
+                        citation: Citation(Book::Phb, 96),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Stroke of Luck",
+                        description: "You can turn a missed attack into a hit, or treat a failed ability check as a roll of 20, once per short or long rest.",
+                        citation: Citation(Book::Phb, 97),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(archetype) = self.archetype {
+            features.extend(archetype.features(level));
+        }
+        features
+    }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        // Slippery Mind
+        if level >= 15 {
+            vec![FeatureEffect::Proficiency(Proficiency::SavingThrow(
+                AbilityScoreType::Wisdom,
+            ))]
+        } else {
+            vec![]
+        }
+    }
+}
 
 impl Languages for Rogue {}
 
@@ -52,7 +463,7 @@ impl Pantheons for Rogue {}
 
 impl Proficiencies for Rogue {
     fn proficiencies(&self) -> Vec<Proficiency> {
-        vec![
+        let mut proficiencies = vec![
             Proficiency::Armor(ArmorType::Light),
             Proficiency::SavingThrow(AbilityScoreType::Dexterity),
             Proficiency::SavingThrow(AbilityScoreType::Intelligence),
@@ -62,7 +473,11 @@ impl Proficiencies for Rogue {
             Proficiency::Weapon(WeaponProficiency::Specific(Weapon::Longsword)),
             Proficiency::Weapon(WeaponProficiency::Specific(Weapon::Rapier)),
             Proficiency::Weapon(WeaponProficiency::Specific(Weapon::Shortsword)),
-        ]
+        ];
+        if let Some(archetype) = self.archetype {
+            proficiencies.extend(archetype.proficiencies());
+        }
+        proficiencies
     }
 
     fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
@@ -85,6 +500,32 @@ impl Proficiencies for Rogue {
     }
 }
 
+impl ProficiencyLevels for Rogue {
+    fn expertise(&self, level: u8) -> Vec<ExpertiseOption> {
+        let options = Skill::iter()
+            .map(Proficiency::Skill)
+            .chain([Proficiency::Tool(Tool::ThievesTools)])
+            .collect::<Vec<_>>();
+        [1, 6]
+            .into_iter()
+            .filter(|&gained| gained <= level)
+            .map(|_| ExpertiseOption::From(options.clone(), 2))
+            .collect()
+    }
+}
+
+impl Spellcasting for Rogue {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        matches!(self.archetype, Some(RoguishArchetype::ArcaneTrickster)).then_some(Spellcaster {
+            ability: AbilityScoreType::Intelligence,
+            caster_type: CasterType::Third,
+            cantrips: 3,
+            list: SpellList::Wizard,
+            preparation: Preparation::Known(SPELLS_KNOWN),
+        })
+    }
+}
+
 impl StartingEquipment for Rogue {
     fn equipment(&self) -> Vec<Equipment> {
         vec![
@@ -112,7 +553,11 @@ impl StartingEquipment for Rogue {
 
 impl fmt::Display for Rogue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Rogue")
+        write!(f, "Rogue")?;
+        if let Some(archetype) = self.archetype {
+            write!(f, ", {archetype}")?;
+        }
+        Ok(())
     }
 }
 
@@ -141,33 +586,75 @@ mod tests {
         insta::assert_yaml_snapshot!(Rogue::ability_rank());
     }
 
+    #[test]
+    fn test_ability_score_improvement_levels() {
+        assert_eq!(
+            Rogue::ability_score_improvement_levels(),
+            vec![4, 8, 10, 12, 16, 19]
+        );
+    }
+
     #[test]
     fn test_snapshot_citations() {
-        let class = Rogue;
+        let class = Rogue::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
+    #[test]
+    fn test_sneak_attack() {
+        let class = Rogue::default();
+        let sneak_attack = |level| {
+            class
+                .features(level)
+                .into_iter()
+                .find(|f| f.title == "Sneak Attack")
+                .and_then(|f| f.scaling)
+        };
+        assert_eq!(sneak_attack(1), Some("1d6".to_string()));
+        assert_eq!(sneak_attack(5), Some("3d6".to_string()));
+        assert_eq!(sneak_attack(20), Some("10d6".to_string()));
+    }
+
+    #[test]
+    fn test_expertise() {
+        let class = Rogue::default();
+        assert_eq!(class.expertise(1).len(), 1);
+        assert_eq!(class.expertise(6).len(), 2);
+        assert!(class.expertise(1).iter().all(|option| matches!(
+            option,
+            ExpertiseOption::From(options, 2)
+                if options.contains(&Proficiency::Tool(Tool::ThievesTools))
+        )));
+    }
+
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Rogue;
+        let class = Rogue::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Rogue;
+        let class = Rogue::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
+    #[test]
+    fn test_snapshot_multiclass_proficiencies() {
+        let class = Rogue::default();
+        insta::assert_yaml_snapshot!(class.multiclass_proficiencies());
+        insta::assert_yaml_snapshot!(class.multiclass_addl_proficiencies());
+    }
+
     #[test]
     fn test_snapshot_equipment() {
-        let class = Rogue;
+        let class = Rogue::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Rogue;
+        let class = Rogue::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
@@ -1,38 +1,219 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Attitude};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, Features};
 use gear::{
     adventuring_gear::{Gear, OtherGear},
     weapons::{Weapon, WeaponCategory},
 };
-use languages::Languages;
+use languages::{Language, Languages};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
+    armor_class::UnarmoredDefense,
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Spells known at each level
+const SPELLS_KNOWN: [usize; 20] = [
+    2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 12, 13, 13, 14, 14, 15, 15, 15, 15,
+];
+
+/// Sorcerous origins a sorcerer can draw power from, chosen at 1st level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum SorcerousOrigin {
+    #[strum(serialize = "Draconic Bloodline")]
+    DraconicBloodline,
+    #[strum(serialize = "Wild Magic")]
+    WildMagic,
+}
+
+impl AlignmentInfluences for SorcerousOrigin {
+    fn attitude(&self) -> Vec<Attitude> {
+        match self {
+            Self::DraconicBloodline => vec![],
+            Self::WildMagic => vec![Attitude::Chaotic],
+        }
+    }
+}
+
+impl Citations for SorcerousOrigin {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::DraconicBloodline => Citation(Book::Phb, 102),
+            Self::WildMagic => Citation(Book::Phb, 103),
+        }])
+    }
+}
+
+impl Features for SorcerousOrigin {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::DraconicBloodline => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Dragon Ancestor",
+                        description: "You choose a type of dragon as your ancestor, and can speak, read and write Draconic.",
+                        citation: Citation(Book::Phb, 102),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Draconic Resilience",
+                        description: "Your hit point maximum increases by 1 for each sorcerer level, and your AC equals 13 + your Dexterity modifier when you aren't wearing armor.",
+                        citation: Citation(Book::Phb, 102),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Elemental Affinity",
+                        description: "When you cast a spell that deals damage of the type associated with your draconic ancestry, you add your Charisma modifier to one damage roll.",
+                        citation: Citation(Book::Phb, 102),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Dragon Wings",
+                        description: "You can use a bonus action to sprout dragon wings, gaining a flying speed equal to your current speed.",
+                        citation: Citation(Book::Phb, 102),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Draconic Presence",
+                        description: "You can spend 5 sorcery points to exude an aura of awe or fear.",
+                        citation: Citation(Book::Phb, 102),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::WildMagic => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Wild Magic Surge",
+                        description: "Casting a sorcerer spell of 1st level or higher can cause a surge of wild magic.",
+                        citation: Citation(Book::Phb, 103),
+                        scaling: None,
+                    },
+                ),
+                (
+                    1,
+                    Feature {
+                        title: "Tides of Chaos",
+                        description: "You can gain advantage on one attack roll, ability check or saving throw once per long rest.",
+                        citation: Citation(Book::Phb, 103),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Bend Luck",
+                        description: "You can use your reaction and spend 2 sorcery points to add or subtract 1d4 from another creature's roll.",
+                        citation: Citation(Book::Phb, 103),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Controlled Chaos",
+                        description: "Whenever you roll on the Wild Magic Surge table, you can roll twice and use either number.",
+                        citation: Citation(Book::Phb, 103),
+                        scaling: None,
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Spell Bombardment",
+                        description: "When you roll the highest number possible on a damage die for a spell, you can roll that die again and add it to the damage.",
+                        citation: Citation(Book::Phb, 103),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Languages for SorcerousOrigin {
+    fn languages(&self) -> Vec<Language> {
+        match self {
+            Self::DraconicBloodline => vec![Language::Draconic],
+            Self::WildMagic => vec![],
+        }
+    }
+}
+
+impl Subclass for SorcerousOrigin {
+    fn level() -> u8 {
+        1
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::DraconicBloodline => (
+                vec![AbilityScoreType::Constitution],
+                vec![Skill::Intimidation],
+            ),
+            Self::WildMagic => (vec![], vec![]),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Sorcerer;
+pub struct Sorcerer {
+    /// Sorcerous origin, chosen at 1st level
+    origin: Option<SorcerousOrigin>,
+}
+
+impl AlignmentInfluences for Sorcerer {
+    fn attitude(&self) -> Vec<Attitude> {
+        self.origin
+            .map(|origin| origin.attitude())
+            .unwrap_or_default()
+    }
+}
 
 impl Backstory for Sorcerer {}
 
 impl Citations for Sorcerer {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 99)])
+        let mut citations = vec![Citation(Book::Phb, 99)];
+        if let Some(origin) = self.origin {
+            citations.extend(origin.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Sorcerer {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -41,11 +222,102 @@ impl Class for Sorcerer {
             vec![AbilityScoreType::Constitution],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D6
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Charisma]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![]
+    }
+
+    fn hit_points_per_level(&self) -> i16 {
+        match self.origin {
+            Some(SorcerousOrigin::DraconicBloodline) => 1,
+            Some(SorcerousOrigin::WildMagic) | None => 0,
+        }
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(3, Die::D4), 10)
+    }
+
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        matches!(self.origin, Some(SorcerousOrigin::DraconicBloodline)).then(|| UnarmoredDefense {
+            name: "Draconic Resilience",
+            base: 13,
+            abilities: vec![AbilityScoreType::Dexterity],
+            shield: true,
+        })
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.origin = SorcerousOrigin::gen(rng, level, ability_scores, proficiencies, domains);
+    }
 }
 
-impl Features for Sorcerer {}
+impl Features for Sorcerer {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    2,
+                    Feature {
+                        title: "Font of Magic",
+                        description: "You can use sorcery points to create spell slots, or expend spell slots to gain sorcery points.",
+                        citation: Citation(Book::Phb, 101),
+                        scaling: Some(format!("{level} sorcery points")),
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Metamagic",
+                        description: "You can spend sorcery points to twist your spells to suit your needs.",
+                        citation: Citation(Book::Phb, 101),
+                        scaling: scaling_at(
+                            level,
+                            &[(3, "2 options"), (10, "3 options"), (17, "4 options")],
+                        ),
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Sorcerous Restoration",
+                        description: "You regain 4 expended sorcery points whenever you finish a short rest.",
+                        citation: Citation(Book::Phb, 102),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(origin) = self.origin {
+            features.extend(origin.features(level));
+        }
+        features
+    }
+}
 
-impl Languages for Sorcerer {}
+impl Languages for Sorcerer {
+    fn languages(&self) -> Vec<Language> {
+        self.origin
+            .map(|origin| origin.languages())
+            .unwrap_or_default()
+    }
+}
 
 impl Pantheons for Sorcerer {}
 
@@ -77,6 +349,20 @@ impl Proficiencies for Sorcerer {
     }
 }
 
+impl ProficiencyLevels for Sorcerer {}
+
+impl Spellcasting for Sorcerer {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Charisma,
+            caster_type: CasterType::Full,
+            cantrips: 4,
+            list: SpellList::Sorcerer,
+            preparation: Preparation::Known(SPELLS_KNOWN),
+        })
+    }
+}
+
 impl StartingEquipment for Sorcerer {
     fn equipment(&self) -> Vec<Equipment> {
         vec![Equipment::new(Item::Weapon(Weapon::Dagger), 2)]
@@ -114,7 +400,11 @@ impl StartingEquipment for Sorcerer {
 
 impl fmt::Display for Sorcerer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Sorcerer")
+        write!(f, "Sorcerer")?;
+        if let Some(origin) = self.origin {
+            write!(f, ", {origin}")?;
+        }
+        Ok(())
     }
 }
 
@@ -145,31 +435,31 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Sorcerer;
+        let class = Sorcerer::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Sorcerer;
+        let class = Sorcerer::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Sorcerer;
+        let class = Sorcerer::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Sorcerer;
+        let class = Sorcerer::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Sorcerer;
+        let class = Sorcerer::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Attitude, Morality};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, Features};
 use gear::{
     adventuring_gear::{Gear, OtherGear},
     armor::{Armor, ArmorType},
@@ -12,28 +14,236 @@ use gear::{
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, scaling_at, Class, Subclass};
+
+/// Spells known at each level
+const SPELLS_KNOWN: [usize; 20] = [
+    2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 11, 11, 12, 12, 13, 13, 14, 14, 15, 15,
+];
+
+/// Otherworldly patrons a warlock can make a pact with, chosen at 1st level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum OtherworldlyPatron {
+    #[strum(serialize = "The Archfey")]
+    Archfey,
+    #[strum(serialize = "The Fiend")]
+    Fiend,
+    #[strum(serialize = "The Great Old One")]
+    GreatOldOne,
+}
+
+impl AlignmentInfluences for OtherworldlyPatron {
+    fn attitude(&self) -> Vec<Attitude> {
+        match self {
+            Self::Archfey | Self::GreatOldOne => vec![Attitude::Chaotic],
+            Self::Fiend => vec![],
+        }
+    }
+
+    fn morality(&self) -> Vec<Morality> {
+        match self {
+            Self::Archfey | Self::GreatOldOne => vec![],
+            Self::Fiend => vec![Morality::Evil],
+        }
+    }
+}
+
+impl Citations for OtherworldlyPatron {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Archfey => Citation(Book::Phb, 108),
+            Self::Fiend | Self::GreatOldOne => Citation(Book::Phb, 109),
+        }])
+    }
+}
+
+impl Features for OtherworldlyPatron {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Archfey => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Fey Presence",
+                        description: "You can use your action to charm or frighten creatures in a 10-foot cube.",
+                        citation: Citation(Book::Phb, 108),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Misty Escape",
+                        description: "When you take damage, you can use your reaction to turn invisible and teleport up to 60 feet.",
+                        citation: Citation(Book::Phb, 108),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Beguiling Defenses",
+                        description: "You are immune to being charmed, and can turn a charm back on the creature that tried it.",
+                        citation: Citation(Book::Phb, 108),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Dark Delirium",
+                        description: "You can use your action to plunge a creature into an illusory realm, charming or frightening it for 1 minute.",
+                        citation: Citation(Book::Phb, 108),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Fiend => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Dark One's Blessing",
+                        description: "When you reduce a hostile creature to 0 hit points, you gain temporary hit points equal to your Charisma modifier + your warlock level.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Dark One's Own Luck",
+                        description: "You can add a d10 to an ability check or saving throw once per short or long rest.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Fiendish Resilience",
+                        description: "When you finish a short or long rest, you choose a damage type to gain resistance to.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Hurl Through Hell",
+                        description: "When you hit a creature with an attack, you can send it through the lower planes, dealing 10d10 psychic damage.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::GreatOldOne => vec![
+                (
+                    1,
+                    Feature {
+                        title: "Awakened Mind",
+                        description: "You can speak telepathically to any creature you can see within 30 feet.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Entropic Ward",
+                        description: "You can use your reaction to impose disadvantage on an attack roll against you, and gain advantage on your next attack if it misses.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Thought Shield",
+                        description: "Your thoughts can't be read by telepathy, and you have resistance to psychic damage.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Create Thrall",
+                        description: "You can use your action to touch an incapacitated humanoid and charm it indefinitely.",
+                        citation: Citation(Book::Phb, 109),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Subclass for OtherworldlyPatron {
+    fn level() -> u8 {
+        1
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Archfey => (vec![], vec![Skill::Deception, Skill::Persuasion]),
+            Self::Fiend => (
+                vec![AbilityScoreType::Constitution],
+                vec![Skill::Intimidation],
+            ),
+            Self::GreatOldOne => (
+                vec![AbilityScoreType::Intelligence],
+                vec![Skill::Arcana, Skill::Insight],
+            ),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Warlock;
+pub struct Warlock {
+    /// Otherworldly patron, chosen at 1st level
+    patron: Option<OtherworldlyPatron>,
+}
+
+impl AlignmentInfluences for Warlock {
+    fn attitude(&self) -> Vec<Attitude> {
+        self.patron
+            .map(|patron| patron.attitude())
+            .unwrap_or_default()
+    }
+
+    fn morality(&self) -> Vec<Morality> {
+        self.patron
+            .map(|patron| patron.morality())
+            .unwrap_or_default()
+    }
+}
 
 impl Backstory for Warlock {}
 
 impl Citations for Warlock {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 105)])
+        let mut citations = vec![Citation(Book::Phb, 105)];
+        if let Some(patron) = self.patron {
+            citations.extend(patron.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Warlock {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -42,9 +252,106 @@ impl Class for Warlock {
             vec![AbilityScoreType::Constitution],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D8
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Charisma]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![
+            Proficiency::Armor(ArmorType::Light),
+            Proficiency::Weapon(WeaponProficiency::Category(WeaponCategory::Simple)),
+        ]
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.patron = OtherworldlyPatron::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(4, Die::D4), 10)
+    }
 }
 
-impl Features for Warlock {}
+impl Features for Warlock {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    2,
+                    Feature {
+                        title: "Eldritch Invocations",
+                        description: "Fragments of forbidden knowledge that imbue you with an abiding magical ability.",
+                        citation: Citation(Book::Phb, 107),
+                        scaling: scaling_at(
+                            level,
+                            &[
+                                (2, "2 known"),
+                                (5, "3 known"),
+                                (7, "4 known"),
+                                (9, "5 known"),
+                                (12, "6 known"),
+                                (15, "7 known"),
+                                (18, "8 known"),
+                            ],
+                        ),
+                    },
+                ),
+                (
+                    3,
+                    Feature {
+                        title: "Pact Boon",
+                        description: "Your otherworldly patron bestows a gift upon you for your loyal service: a Pact of the Chain, Blade or Tome.",
+                        citation: Citation(Book::Phb, 107),
+                        scaling: None,
+                    },
+                ),
+                (
+                    11,
+                    Feature {
+                        title: "Mystic Arcanum",
+                        description: "You can cast one spell of each arcanum level once without expending a spell slot, regaining the ability when you finish a long rest.",
+                        citation: Citation(Book::Phb, 108),
+                        scaling: scaling_at(
+                            level,
+                            &[
+                                (11, "6th level"),
+                                (13, "6th and 7th level"),
+                                (15, "6th to 8th level"),
+                                (17, "6th to 9th level"),
+                            ],
+                        ),
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Eldritch Master",
+                        description: "You can spend 1 minute entreating your patron to regain all your expended Pact Magic spell slots once per long rest.",
+                        citation: Citation(Book::Phb, 108),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(patron) = self.patron {
+            features.extend(patron.features(level));
+        }
+        features
+    }
+}
 
 impl Languages for Warlock {}
 
@@ -76,6 +383,20 @@ impl Proficiencies for Warlock {
     }
 }
 
+impl ProficiencyLevels for Warlock {}
+
+impl Spellcasting for Warlock {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Charisma,
+            caster_type: CasterType::Pact,
+            cantrips: 2,
+            list: SpellList::Warlock,
+            preparation: Preparation::Known(SPELLS_KNOWN),
+        })
+    }
+}
+
 impl StartingEquipment for Warlock {
     fn equipment(&self) -> Vec<Equipment> {
         vec![
@@ -117,7 +438,11 @@ impl StartingEquipment for Warlock {
 
 impl fmt::Display for Warlock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Warlock")
+        write!(f, "Warlock")?;
+        if let Some(patron) = self.patron {
+            write!(f, ", {patron}")?;
+        }
+        Ok(())
     }
 }
 
@@ -148,31 +473,31 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Warlock;
+        let class = Warlock::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Warlock;
+        let class = Warlock::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Warlock;
+        let class = Warlock::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Warlock;
+        let class = Warlock::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Warlock;
+        let class = Warlock::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
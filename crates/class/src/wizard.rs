@@ -1,9 +1,11 @@
 use std::fmt;
 
+use alignment::{AlignmentInfluences, Morality};
 use backstory::Backstory;
 use citation::{Book, Citation, CitationList, Citations};
-use deities::Pantheons;
-use features::Features;
+use deities::{Domain, Pantheons};
+use dice_roller::{Die, RollCmd};
+use features::{Feature, Features};
 use gear::{
     adventuring_gear::{Gear, OtherGear},
     weapons::Weapon,
@@ -11,28 +13,503 @@ use gear::{
 use languages::Languages;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use spells::{CasterType, Preparation, SpellList, Spellcaster, Spellcasting};
 use stats::{
     ability::{AbilityScoreType, AbilityScores, Skill},
     equipment::{Equipment, EquipmentOption, Item, Pack, StartingEquipment},
-    proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
+    proficiencies::{
+        Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption, WeaponProficiency,
+    },
 };
+use strum::{Display, EnumIter};
 
-use super::Class;
+use super::{features_by_level, Class, Subclass};
+
+/// Arcane traditions a wizard can study, chosen at 2nd level
+#[derive(Clone, Copy, Deserialize, Display, EnumIter, Serialize)]
+enum ArcaneTradition {
+    #[strum(serialize = "School of Abjuration")]
+    Abjuration,
+    #[strum(serialize = "School of Conjuration")]
+    Conjuration,
+    #[strum(serialize = "School of Divination")]
+    Divination,
+    #[strum(serialize = "School of Enchantment")]
+    Enchantment,
+    #[strum(serialize = "School of Evocation")]
+    Evocation,
+    #[strum(serialize = "School of Illusion")]
+    Illusion,
+    #[strum(serialize = "School of Necromancy")]
+    Necromancy,
+    #[strum(serialize = "School of Transmutation")]
+    Transmutation,
+}
+
+impl AlignmentInfluences for ArcaneTradition {
+    fn morality(&self) -> Vec<Morality> {
+        match self {
+            Self::Abjuration
+            | Self::Conjuration
+            | Self::Divination
+            | Self::Enchantment
+            | Self::Evocation
+            | Self::Illusion
+            | Self::Transmutation => vec![],
+            Self::Necromancy => vec![Morality::Evil],
+        }
+    }
+}
+
+impl Citations for ArcaneTradition {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![match self {
+            Self::Abjuration => Citation(Book::Phb, 115),
+            Self::Conjuration | Self::Divination => Citation(Book::Phb, 116),
+            Self::Enchantment | Self::Evocation => Citation(Book::Phb, 117),
+            Self::Illusion | Self::Necromancy => Citation(Book::Phb, 118),
+            Self::Transmutation => Citation(Book::Phb, 119),
+        }])
+    }
+}
+
+impl Features for ArcaneTradition {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let features = match self {
+            Self::Abjuration => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Abjuration Savant",
+                        description: "The gold and time you must spend to copy an abjuration spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Arcane Ward",
+                        description: "When you cast an abjuration spell of 1st level or higher, you create a magical ward that absorbs damage.",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Projected Ward",
+                        description: "When a creature within 30 feet takes damage, you can use your reaction to have your Arcane Ward absorb it.",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Improved Abjuration",
+                        description: "When you cast an abjuration spell that requires an ability check, you add your proficiency bonus to it.",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Spell Resistance",
+                        description: "You have advantage on saving throws against spells and resistance against their damage.",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Conjuration => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Conjuration Savant",
+                        description: "The gold and time you must spend to copy a conjuration spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Minor Conjuration",
+                        description: "You can use your action to conjure up an inanimate object in your hand or on the ground.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Benign Transposition",
+                        description: "You can use your action to teleport up to 30 feet, or swap places with a willing Small or Medium creature.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Focused Conjuration",
+                        description: "Your concentration can't be broken as a result of taking damage while concentrating on a conjuration spell.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Durable Summons",
+                        description: "Any creature you summon or create with a conjuration spell has 30 temporary hit points.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Divination => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Divination Savant",
+                        description: "The gold and time you must spend to copy a divination spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Portent",
+                        description: "When you finish a long rest, you roll two d20s and can replace any attack roll, saving throw or ability check with one of them.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Expert Divination",
+                        description: "When you cast a divination spell of 2nd level or higher, you regain a lower level spell slot.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "The Third Eye",
+                        description: "You can use your action to gain darkvision, ethereal sight, greater comprehension or see invisibility.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Greater Portent",
+                        description: "You roll three d20s for your Portent feature, rather than two.",
+                        citation: Citation(Book::Phb, 116),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Enchantment => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Enchantment Savant",
+                        description: "The gold and time you must spend to copy an enchantment spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Hypnotic Gaze",
+                        description: "You can use your action to charm a creature within 5 feet, incapacitating it.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Instinctive Charm",
+                        description: "When a creature within 30 feet attacks you, you can use your reaction to divert the attack to another creature.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Split Enchantment",
+                        description: "When you cast an enchantment spell that targets only one creature, you can have it target a second creature.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Alter Memories",
+                        description: "When you charm creatures with an enchantment spell, you can make them unaware they were charmed.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Evocation => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Evocation Savant",
+                        description: "The gold and time you must spend to copy an evocation spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Sculpt Spells",
+                        description: "When you cast an evocation spell, you can protect some creatures from its effects.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Potent Cantrip",
+                        description: "Creatures that succeed on a saving throw against your damaging cantrips take half damage.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Empowered Evocation",
+                        description: "You add your Intelligence modifier to one damage roll of any wizard evocation spell you cast.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Overchannel",
+                        description: "You can deal maximum damage with a wizard spell of 5th level or lower.",
+                        citation: Citation(Book::Phb, 117),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Illusion => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Illusion Savant",
+                        description: "The gold and time you must spend to copy an illusion spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Improved Minor Illusion",
+                        description: "You learn the minor illusion cantrip, and can create both a sound and an image with it.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Malleable Illusions",
+                        description: "You can use your action to change the nature of an illusion spell with a duration of 1 minute or longer.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Illusory Self",
+                        description: "You can use your reaction to have an attack automatically miss you once per short or long rest.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Illusory Reality",
+                        description: "You can make one inanimate, nonmagical object that is part of an illusion spell real for 1 minute.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Necromancy => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Necromancy Savant",
+                        description: "The gold and time you must spend to copy a necromancy spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Grim Harvest",
+                        description: "When you kill a creature with a spell of 1st level or higher, you regain hit points equal to twice the spell's level.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Undead Thralls",
+                        description: "You add animate dead to your spellbook, and undead you create with necromancy spells are stronger.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Inured to Undeath",
+                        description: "You have resistance to necrotic damage, and your hit point maximum can't be reduced.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Command Undead",
+                        description: "You can use magic to bring undead under your control.",
+                        citation: Citation(Book::Phb, 118),
+                        scaling: None,
+                    },
+                ),
+            ],
+            Self::Transmutation => vec![
+                (
+                    2,
+                    Feature {
+                        title: "Transmutation Savant",
+                        description: "The gold and time you must spend to copy a transmutation spell into your spellbook is halved.",
+                        citation: Citation(Book::Phb, 119),
+                        scaling: None,
+                    },
+                ),
+                (
+                    2,
+                    Feature {
+                        title: "Minor Alchemy",
+                        description: "You can temporarily alter the physical properties of one nonmagical object.",
+                        citation: Citation(Book::Phb, 119),
+                        scaling: None,
+                    },
+                ),
+                (
+                    6,
+                    Feature {
+                        title: "Transmuter's Stone",
+                        description: "You can spend 8 hours creating a stone that grants darkvision, speed, a saving throw proficiency or resistance.",
+                        citation: Citation(Book::Phb, 119),
+                        scaling: None,
+                    },
+                ),
+                (
+                    10,
+                    Feature {
+                        title: "Shapechanger",
+                        description: "You can cast polymorph on yourself without expending a spell slot once per short or long rest.",
+                        citation: Citation(Book::Phb, 119),
+                        scaling: None,
+                    },
+                ),
+                (
+                    14,
+                    Feature {
+                        title: "Master Transmuter",
+                        description: "You can consume your transmuter's stone to perform a major transmutation, remove curses and diseases, or restore life.",
+                        citation: Citation(Book::Phb, 119),
+                        scaling: None,
+                    },
+                ),
+            ],
+        };
+        features_by_level(features, level)
+    }
+}
+
+impl Subclass for ArcaneTradition {
+    fn level() -> u8 {
+        2
+    }
+
+    fn affinities(self) -> (Vec<AbilityScoreType>, Vec<Skill>) {
+        match self {
+            Self::Abjuration => (vec![AbilityScoreType::Constitution], vec![]),
+            Self::Conjuration | Self::Evocation => (vec![], vec![]),
+            Self::Divination => (
+                vec![AbilityScoreType::Wisdom],
+                vec![Skill::Insight, Skill::Perception],
+            ),
+            Self::Enchantment => (
+                vec![AbilityScoreType::Charisma],
+                vec![Skill::Deception, Skill::Persuasion],
+            ),
+            Self::Illusion => (vec![], vec![Skill::Deception, Skill::Performance]),
+            Self::Necromancy => (vec![], vec![Skill::Medicine, Skill::Religion]),
+            Self::Transmutation => (vec![], vec![Skill::Investigation, Skill::Nature]),
+        }
+    }
+}
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Wizard;
+pub struct Wizard {
+    /// Arcane tradition, chosen at 2nd level
+    tradition: Option<ArcaneTradition>,
+}
+
+impl AlignmentInfluences for Wizard {
+    fn morality(&self) -> Vec<Morality> {
+        self.tradition
+            .map(|tradition| tradition.morality())
+            .unwrap_or_default()
+    }
+}
 
 impl Backstory for Wizard {}
 
 impl Citations for Wizard {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 112)])
+        let mut citations = vec![Citation(Book::Phb, 112)];
+        if let Some(tradition) = self.tradition {
+            citations.extend(tradition.citations().0);
+        }
+        CitationList(citations)
     }
 }
 
 impl Class for Wizard {
     fn gen(_: &mut impl Rng, _: &AbilityScores) -> Self {
-        Self
+        Self::default()
     }
 
     fn ability_rank() -> (Vec<AbilityScoreType>, Vec<AbilityScoreType>) {
@@ -45,9 +522,75 @@ impl Class for Wizard {
             ],
         )
     }
+
+    fn hit_die() -> Die {
+        Die::D6
+    }
+
+    fn multiclass_prerequisites() -> Vec<Vec<AbilityScoreType>> {
+        vec![vec![AbilityScoreType::Intelligence]]
+    }
+
+    fn multiclass_proficiencies(&self) -> Vec<Proficiency> {
+        vec![]
+    }
+
+    fn gen_subclass(
+        &mut self,
+        rng: &mut impl Rng,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        domains: &[Domain],
+    ) {
+        self.tradition = ArcaneTradition::gen(rng, level, ability_scores, proficiencies, domains);
+    }
+
+    fn starting_wealth() -> (RollCmd, u32) {
+        (RollCmd(4, Die::D4), 10)
+    }
 }
 
-impl Features for Wizard {}
+impl Features for Wizard {
+    fn features(&self, level: u8) -> Vec<Feature> {
+        let mut features = features_by_level(
+            vec![
+                (
+                    1,
+                    Feature {
+                        title: "Arcane Recovery",
+                        description: "Once per day when you finish a short rest, you can recover expended spell slots with a combined level of up to half your wizard level (rounded up).",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: Some(format!("{} levels of spell slots", level.div_ceil(2))),
+                    },
+                ),
+                (
+                    18,
+                    Feature {
+                        title: "Spell Mastery",
+                        description: "You can cast a chosen 1st-level and 2nd-level wizard spell at their lowest level without expending a spell slot.",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: None,
+                    },
+                ),
+                (
+                    20,
+                    Feature {
+                        title: "Signature Spells",
+                        description: "You always have two chosen 3rd-level wizard spells prepared, and can cast each of them once at 3rd level without expending a spell slot per short or long rest.",
+                        citation: Citation(Book::Phb, 115),
+                        scaling: None,
+                    },
+                ),
+            ],
+            level,
+        );
+        if let Some(tradition) = self.tradition {
+            features.extend(tradition.features(level));
+        }
+        features
+    }
+}
 
 impl Languages for Wizard {}
 
@@ -81,6 +624,20 @@ impl Proficiencies for Wizard {
     }
 }
 
+impl ProficiencyLevels for Wizard {}
+
+impl Spellcasting for Wizard {
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        Some(Spellcaster {
+            ability: AbilityScoreType::Intelligence,
+            caster_type: CasterType::Full,
+            cantrips: 3,
+            list: SpellList::Wizard,
+            preparation: Preparation::Spellbook,
+        })
+    }
+}
+
 impl StartingEquipment for Wizard {
     fn equipment(&self) -> Vec<Equipment> {
         vec![Equipment::new(
@@ -118,7 +675,11 @@ impl StartingEquipment for Wizard {
 
 impl fmt::Display for Wizard {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Wizard")
+        write!(f, "Wizard")?;
+        if let Some(tradition) = self.tradition {
+            write!(f, ", {tradition}")?;
+        }
+        Ok(())
     }
 }
 
@@ -149,31 +710,31 @@ mod tests {
 
     #[test]
     fn test_snapshot_citations() {
-        let class = Wizard;
+        let class = Wizard::default();
         insta::assert_yaml_snapshot!(class.citations());
     }
 
     #[test]
     fn test_snapshot_proficiencies() {
-        let class = Wizard;
+        let class = Wizard::default();
         insta::assert_yaml_snapshot!(class.proficiencies());
     }
 
     #[test]
     fn test_snapshot_addl_proficiencies() {
-        let class = Wizard;
+        let class = Wizard::default();
         insta::assert_yaml_snapshot!(class.addl_proficiencies());
     }
 
     #[test]
     fn test_snapshot_equipment() {
-        let class = Wizard;
+        let class = Wizard::default();
         insta::assert_yaml_snapshot!(class.equipment());
     }
 
     #[test]
     fn test_snapshot_addl_equipment() {
-        let class = Wizard;
+        let class = Wizard::default();
         insta::assert_yaml_snapshot!(class.addl_equipment());
     }
 }
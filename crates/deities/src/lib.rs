@@ -22,7 +22,7 @@ use std::{f64::consts::E, fmt};
 use alignment::{Alignment, Attitude, Morality};
 use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
-use strum::Display;
+use strum::{Display, EnumIter, EnumString};
 
 use self::{
     dragon::Dragon,
@@ -112,7 +112,21 @@ impl PantheonWeight {
     }
 }
 
-#[derive(Copy, Clone, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[strum(ascii_case_insensitive)]
 pub enum Pantheon {
     Bugbear,
     Celtic,
@@ -180,6 +194,14 @@ impl Pantheon {
         )
     }
 
+    /// Find a deity in this pantheon by name, ignoring case
+    #[must_use]
+    pub fn find_deity<'a>(self, name: &str) -> Option<Deity<'a>> {
+        self.all_deities()
+            .into_iter()
+            .find(|d| d.name.eq_ignore_ascii_case(name))
+    }
+
     /// # Panics
     ///
     /// Will panic if no Pantheons available
@@ -233,4 +255,8 @@ pub trait Pantheons {
     fn deity_required(&self) -> bool {
         false
     }
+
+    fn domain(&self) -> Option<Domain> {
+        None
+    }
 }
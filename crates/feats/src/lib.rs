@@ -0,0 +1,847 @@
+#![deny(clippy::all)]
+#![warn(clippy::pedantic)]
+
+use std::fmt;
+
+use characteristics::Size;
+use citation::{Book, Citation, CitationList, Citations};
+use features::{Feature, FeatureEffect, Features, SaveAdvantage, Threat};
+use gear::{armor::ArmorType, damage::DamageType};
+use languages::{Language, LanguageType, Languages};
+use race::{RaceType, Subrace};
+use rand::{prelude::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+use spells::Spell;
+use stats::{
+    ability::{exp_weight, AbilityScoreType, AbilityScores, Skill, MAX_SCORE},
+    proficiencies::{
+        ExpertiseOption, Proficiencies, Proficiency, ProficiencyLevels, ProficiencyOption,
+    },
+};
+use strum::{Display, EnumIter, IntoEnumIterator, IntoStaticStr};
+
+/// Something a character must have (or be) before they can take a feat
+#[derive(Debug, Eq, PartialEq)]
+pub enum Prerequisite {
+    /// Minimum score in an ability
+    Ability(AbilityScoreType, i16),
+    /// At least one of the prerequisites must be met
+    Any(Vec<Prerequisite>),
+    /// Must already have the proficiency
+    Proficiency(Proficiency),
+    /// Must be this race
+    Race(RaceType),
+    /// Must be this size
+    Size(Size),
+    /// Must be able to cast at least one spell
+    Spellcasting,
+    /// Must be this subrace of a race
+    Subrace(RaceType, Subrace),
+}
+
+impl Prerequisite {
+    /// Whether a character meets this prerequisite
+    #[must_use]
+    pub fn met(&self, character: &FeatCandidate<'_>) -> bool {
+        match self {
+            Self::Ability(ability, min) => character.ability_scores.score(*ability) >= *min,
+            Self::Any(prerequisites) => prerequisites.iter().any(|p| p.met(character)),
+            Self::Proficiency(proficiency) => character.proficiencies.contains(proficiency),
+            Self::Race(race) => character.race == Some(*race),
+            Self::Size(size) => character.size == Some(size),
+            Self::Spellcasting => character.spellcasting,
+            Self::Subrace(race, subrace) => {
+                character.race == Some(*race) && character.subrace == Some(*subrace)
+            }
+        }
+    }
+
+    /// Whether the prerequisite depends on the character's race
+    fn racial(&self) -> bool {
+        match self {
+            Self::Any(prerequisites) => prerequisites.iter().any(Self::racial),
+            Self::Race(_) | Self::Subrace(..) => true,
+            Self::Ability(..) | Self::Proficiency(_) | Self::Size(_) | Self::Spellcasting => false,
+        }
+    }
+}
+
+/// What is known about a character when deciding which feats they can take
+pub struct FeatCandidate<'a> {
+    /// Current ability scores
+    pub ability_scores: &'a AbilityScores,
+    /// Proficiencies the character is known to have
+    pub proficiencies: &'a [Proficiency],
+    /// Race of the character, if known
+    pub race: Option<RaceType>,
+    /// Size of the character, if known
+    pub size: Option<&'a Size>,
+    /// Whether the character can cast at least one spell
+    pub spellcasting: bool,
+    /// Subrace of the character, if they have one
+    pub subrace: Option<Subrace>,
+}
+
+/// Feats from the Player's Handbook, along with racial feats from Xanathar's Guide to Everything
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, IntoStaticStr, PartialEq, Serialize,
+)]
+pub enum Feat {
+    Actor,
+    Alert,
+    Athlete,
+    #[strum(serialize = "Bountiful Luck")]
+    BountifulLuck,
+    Charger,
+    #[strum(serialize = "Crossbow Expert")]
+    CrossbowExpert,
+    #[strum(serialize = "Defensive Duelist")]
+    DefensiveDuelist,
+    #[strum(serialize = "Dragon Fear")]
+    DragonFear,
+    #[strum(serialize = "Dragon Hide")]
+    DragonHide,
+    #[strum(serialize = "Drow High Magic")]
+    DrowHighMagic,
+    #[strum(serialize = "Dual Wielder")]
+    DualWielder,
+    #[strum(serialize = "Dungeon Delver")]
+    DungeonDelver,
+    Durable,
+    #[strum(serialize = "Dwarven Fortitude")]
+    DwarvenFortitude,
+    #[strum(serialize = "Elemental Adept")]
+    ElementalAdept,
+    #[strum(serialize = "Elven Accuracy")]
+    ElvenAccuracy,
+    #[strum(serialize = "Fade Away")]
+    FadeAway,
+    #[strum(serialize = "Fey Teleportation")]
+    FeyTeleportation,
+    #[strum(serialize = "Flames of Phlegethos")]
+    FlamesOfPhlegethos,
+    Grappler,
+    #[strum(serialize = "Great Weapon Master")]
+    GreatWeaponMaster,
+    Healer,
+    #[strum(serialize = "Heavily Armored")]
+    HeavilyArmored,
+    #[strum(serialize = "Heavy Armor Master")]
+    HeavyArmorMaster,
+    #[strum(serialize = "Infernal Constitution")]
+    InfernalConstitution,
+    #[strum(serialize = "Inspiring Leader")]
+    InspiringLeader,
+    #[strum(serialize = "Keen Mind")]
+    KeenMind,
+    #[strum(serialize = "Lightly Armored")]
+    LightlyArmored,
+    Linguist,
+    Lucky,
+    #[strum(serialize = "Mage Slayer")]
+    MageSlayer,
+    #[strum(serialize = "Magic Initiate")]
+    MagicInitiate,
+    #[strum(serialize = "Martial Adept")]
+    MartialAdept,
+    #[strum(serialize = "Medium Armor Master")]
+    MediumArmorMaster,
+    Mobile,
+    #[strum(serialize = "Moderately Armored")]
+    ModeratelyArmored,
+    #[strum(serialize = "Mounted Combatant")]
+    MountedCombatant,
+    Observant,
+    #[strum(serialize = "Orcish Fury")]
+    OrcishFury,
+    #[strum(serialize = "Polearm Master")]
+    PolearmMaster,
+    Prodigy,
+    Resilient,
+    #[strum(serialize = "Ritual Caster")]
+    RitualCaster,
+    #[strum(serialize = "Savage Attacker")]
+    SavageAttacker,
+    #[strum(serialize = "Second Chance")]
+    SecondChance,
+    Sentinel,
+    Sharpshooter,
+    #[strum(serialize = "Shield Master")]
+    ShieldMaster,
+    Skilled,
+    Skulker,
+    #[strum(serialize = "Spell Sniper")]
+    SpellSniper,
+    #[strum(serialize = "Squat Nimbleness")]
+    SquatNimbleness,
+    #[strum(serialize = "Tavern Brawler")]
+    TavernBrawler,
+    Tough,
+    #[strum(serialize = "War Caster")]
+    WarCaster,
+    #[strum(serialize = "Weapon Master")]
+    WeaponMaster,
+    #[strum(serialize = "Wood Elf Magic")]
+    WoodElfMagic,
+}
+
+impl Feat {
+    /// Short summary of the rules for the feat
+    #[must_use]
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Actor => "You have advantage on Deception and Performance checks when trying to pass yourself off as a different person, and you can mimic the speech of a person or the sounds made by other creatures.",
+            Self::Alert => "You gain a +5 bonus to initiative, you can't be surprised while you are conscious, and other creatures don't gain advantage on attack rolls against you as a result of being unseen by you.",
+            Self::Athlete => "Standing up from prone and climbing use less movement, and you can make a running long jump or a running high jump after moving only 5 feet on foot.",
+            Self::BountifulLuck => "When an ally you can see within 30 feet of you rolls a 1 on an attack roll, ability check, or saving throw, you can use your reaction to let the ally reroll the die.",
+            Self::Charger => "When you use your action to Dash, you can use a bonus action to make one melee weapon attack or to shove a creature, gaining +5 to the damage or pushing the target up to 10 feet if you moved at least 10 feet in a straight line.",
+            Self::CrossbowExpert => "You ignore the loading quality of crossbows, being within 5 feet of a hostile creature doesn't impose disadvantage on your ranged attack rolls, and you can attack with a hand crossbow as a bonus action after attacking with a one-handed weapon.",
+            Self::DefensiveDuelist => "When you are wielding a finesse weapon with which you are proficient and another creature hits you with a melee attack, you can use your reaction to add your proficiency bonus to your AC for that attack.",
+            Self::DragonFear => "When angered, you radiate menace. You can use your breath weapon to instead roar, forcing each creature of your choice within 30 feet of you to succeed on a Wisdom saving throw or become frightened of you for 1 minute.",
+            Self::DragonHide => "Your scales harden. While you aren't wearing armor, your AC equals 13 + your Dexterity modifier, and your retractable claws deal 1d4 slashing damage with unarmed strikes.",
+            Self::DrowHighMagic => "You can cast detect magic at will, without expending a spell slot, and you can cast levitate and dispel magic once each per long rest without expending a spell slot.",
+            Self::DualWielder => "You gain a +1 bonus to AC while wielding a separate melee weapon in each hand, you can two-weapon fight with weapons that aren't light, and you can draw or stow two one-handed weapons at once.",
+            Self::DungeonDelver => "You have advantage on checks made to detect secret doors and on saving throws made to avoid or resist traps, you have resistance to damage dealt by traps, and you can search for traps at a normal pace.",
+            Self::Durable => "When you roll a Hit Die to regain hit points, the minimum number of hit points you regain equals twice your Constitution modifier.",
+            Self::DwarvenFortitude => "Whenever you take the Dodge action in combat, you can spend one Hit Die to heal yourself.",
+            Self::ElementalAdept => "Spells you cast ignore resistance to damage of the chosen type, and you can treat any 1 on a damage die for those spells as a 2.",
+            Self::ElvenAccuracy => "Whenever you have advantage on an attack roll using Dexterity, Intelligence, Wisdom, or Charisma, you can reroll one of the dice once.",
+            Self::FadeAway => "Immediately after you take damage, you can use a reaction to magically become invisible until the end of your next turn or until you attack, deal damage, or force someone to make a saving throw. Once per short or long rest.",
+            Self::FeyTeleportation => "You learn to speak, read, and write Sylvan, and you can cast the misty step spell once per short or long rest without expending a spell slot.",
+            Self::FlamesOfPhlegethos => "When you roll fire damage for a spell, you can reroll any roll of 1 on the fire damage dice, and you can wreathe yourself in flames that shed light and burn creatures that hit you with a melee attack.",
+            Self::Grappler => "You have advantage on attack rolls against a creature you are grappling, and you can use your action to try to pin a creature grappled by you, restraining you both.",
+            Self::GreatWeaponMaster => "When you score a critical hit or reduce a creature to 0 hit points with a melee weapon, you can make one melee weapon attack as a bonus action. Before attacking with a heavy weapon, you can take a -5 penalty to the attack roll for +10 damage.",
+            Self::Healer => "When you use a healer's kit to stabilize a dying creature, it also regains 1 hit point, and you can use an action and a use of a healer's kit to restore 1d6 + 4 hit points plus the creature's maximum number of Hit Dice.",
+            Self::HeavilyArmored => "You gain proficiency with heavy armor.",
+            Self::HeavyArmorMaster => "While you are wearing heavy armor, bludgeoning, piercing, and slashing damage that you take from nonmagical weapons is reduced by 3.",
+            Self::InfernalConstitution => "You have resistance to cold and poison damage, and you have advantage on saving throws against being poisoned.",
+            Self::InspiringLeader => "You can spend 10 minutes inspiring your companions, giving up to six friendly creatures within 30 feet temporary hit points equal to your level + your Charisma modifier.",
+            Self::KeenMind => "You always know which way is north and the number of hours left before the next sunrise or sunset, and you can accurately recall anything you have seen or heard within the past month.",
+            Self::LightlyArmored => "You gain proficiency with light armor.",
+            Self::Linguist => "You learn three languages of your choice, and you can ably create written ciphers that others can't decipher unless you teach them or they succeed on an Intelligence check.",
+            Self::Lucky => "You have 3 luck points per long rest. Whenever you make an attack roll, ability check, or saving throw, or are attacked, you can spend one to roll an additional d20 and choose which die is used.",
+            Self::MageSlayer => "You can use your reaction to attack a creature within 5 feet of you that casts a spell, creatures you damage have disadvantage on concentration saves, and you have advantage on saving throws against spells cast by creatures within 5 feet of you.",
+            Self::MagicInitiate => "Choose a class: you learn two of its cantrips, and one 1st-level spell from its list that you can cast once per long rest.",
+            Self::MartialAdept => "You learn two maneuvers from the Battle Master archetype, and you gain one superiority die (a d6) that is regained on a short or long rest.",
+            Self::MediumArmorMaster => "Wearing medium armor doesn't impose disadvantage on your Dexterity (Stealth) checks, and it lets you add up to 3 from your Dexterity to your AC.",
+            Self::Mobile => "Your speed increases by 10 feet, difficult terrain doesn't cost you extra movement when you Dash, and creatures you make a melee attack against can't make opportunity attacks against you for the rest of the turn.",
+            Self::ModeratelyArmored => "You gain proficiency with medium armor and shields.",
+            Self::MountedCombatant => "While mounted, you have advantage on melee attacks against unmounted creatures smaller than your mount, you can force attacks targeting your mount to target you instead, and your mount takes no damage from successful Dexterity saves.",
+            Self::Observant => "You can read lips if you can see a creature's mouth and know its language, and you have a +5 bonus to your passive Wisdom (Perception) and passive Intelligence (Investigation) scores.",
+            Self::OrcishFury => "Once per short or long rest, you can roll one of the weapon's damage dice an additional time and add it as extra damage when you hit with a simple or martial weapon. After using Relentless Endurance, you can make one weapon attack with your reaction.",
+            Self::PolearmMaster => "When you take the Attack action with a glaive, halberd, quarterstaff, or spear, you can use a bonus action to attack with the opposite end, and creatures provoke opportunity attacks from you when they enter your reach.",
+            Self::Prodigy => "You gain one skill proficiency, one tool proficiency, and fluency in one language of your choice, and you gain expertise in one skill you are proficient with.",
+            Self::Resilient => "You gain proficiency in saving throws using the chosen ability.",
+            Self::RitualCaster => "You have a ritual book holding two 1st-level ritual spells from a class of your choice, which you can cast as rituals. You can copy other ritual spells you find into the book.",
+            Self::SavageAttacker => "Once per turn when you roll damage for a melee weapon attack, you can reroll the weapon's damage dice and use either total.",
+            Self::SecondChance => "When a creature you can see hits you with an attack roll, you can use your reaction to force that creature to reroll. Once per short or long rest, or when you roll initiative.",
+            Self::Sentinel => "Creatures you hit with opportunity attacks have their speed reduced to 0, creatures provoke opportunity attacks from you even if they Disengage, and you can attack a creature that attacks someone other than you within 5 feet of you.",
+            Self::Sharpshooter => "Attacking at long range doesn't impose disadvantage on your ranged weapon attacks, they ignore half and three-quarters cover, and you can take a -5 penalty to the attack roll for +10 damage.",
+            Self::ShieldMaster => "You can shove a creature with your shield as a bonus action after attacking, add your shield's AC bonus to Dexterity saves against effects that target only you, and take no damage on a successful Dexterity save for half damage.",
+            Self::Skilled => "You gain proficiency in any combination of three skills or tools of your choice.",
+            Self::Skulker => "You can try to hide when you are lightly obscured, missing with a ranged weapon attack doesn't reveal your position, and dim light doesn't impose disadvantage on your Wisdom (Perception) checks relying on sight.",
+            Self::SpellSniper => "The range of your spells that require an attack roll is doubled, your ranged spell attacks ignore half and three-quarters cover, and you learn one cantrip that requires an attack roll.",
+            Self::SquatNimbleness => "Your walking speed increases by 5 feet, you gain proficiency in Acrobatics or Athletics, and you have advantage on checks made to escape from being grappled.",
+            Self::TavernBrawler => "You are proficient with improvised weapons, your unarmed strike uses a d4 for damage, and when you hit with an unarmed strike or improvised weapon you can use a bonus action to attempt to grapple the target.",
+            Self::Tough => "Your hit point maximum increases by an amount equal to twice your level, and increases by 2 every time you gain a level.",
+            Self::WarCaster => "You have advantage on Constitution saving throws to maintain concentration, you can perform somatic components with your hands full, and you can cast a spell instead of making an opportunity attack.",
+            Self::WeaponMaster => "You gain proficiency with four weapons of your choice.",
+            Self::WoodElfMagic => "You learn one druid cantrip, and you can cast longstrider and pass without trace once each per long rest without expending a spell slot.",
+        }
+    }
+
+    /// Where to find the feat
+    #[must_use]
+    pub fn citation(self) -> Citation {
+        match self {
+            Self::Actor
+            | Self::Alert
+            | Self::Athlete
+            | Self::Charger
+            | Self::CrossbowExpert
+            | Self::DefensiveDuelist
+            | Self::DualWielder => Citation(Book::Phb, 165),
+            Self::DungeonDelver | Self::Durable | Self::ElementalAdept => Citation(Book::Phb, 166),
+            Self::Grappler
+            | Self::GreatWeaponMaster
+            | Self::Healer
+            | Self::HeavilyArmored
+            | Self::HeavyArmorMaster
+            | Self::InspiringLeader
+            | Self::KeenMind
+            | Self::LightlyArmored
+            | Self::Linguist
+            | Self::Lucky => Citation(Book::Phb, 167),
+            Self::MageSlayer
+            | Self::MagicInitiate
+            | Self::MartialAdept
+            | Self::MediumArmorMaster
+            | Self::Mobile
+            | Self::ModeratelyArmored
+            | Self::MountedCombatant
+            | Self::Observant
+            | Self::PolearmMaster
+            | Self::Resilient => Citation(Book::Phb, 168),
+            Self::RitualCaster | Self::SavageAttacker | Self::Sentinel => Citation(Book::Phb, 169),
+            Self::Sharpshooter
+            | Self::ShieldMaster
+            | Self::Skilled
+            | Self::Skulker
+            | Self::SpellSniper
+            | Self::TavernBrawler
+            | Self::Tough
+            | Self::WarCaster
+            | Self::WeaponMaster => Citation(Book::Phb, 170),
+            Self::BountifulLuck | Self::DragonFear => Citation(Book::Xgte, 73),
+            Self::DragonHide
+            | Self::DrowHighMagic
+            | Self::DwarvenFortitude
+            | Self::ElvenAccuracy
+            | Self::FadeAway
+            | Self::FeyTeleportation
+            | Self::FlamesOfPhlegethos => Citation(Book::Xgte, 74),
+            Self::InfernalConstitution
+            | Self::OrcishFury
+            | Self::Prodigy
+            | Self::SecondChance
+            | Self::SquatNimbleness
+            | Self::WoodElfMagic => Citation(Book::Xgte, 75),
+        }
+    }
+
+    /// Requirements a character must meet to take the feat
+    #[must_use]
+    pub fn prerequisites(self) -> Vec<Prerequisite> {
+        match self {
+            Self::BountifulLuck | Self::SecondChance => {
+                vec![Prerequisite::Race(RaceType::Halfling)]
+            }
+            Self::DefensiveDuelist | Self::Skulker => {
+                vec![Prerequisite::Ability(AbilityScoreType::Dexterity, 13)]
+            }
+            Self::DragonFear | Self::DragonHide => vec![Prerequisite::Race(RaceType::Dragonborn)],
+            Self::DrowHighMagic => vec![Prerequisite::Subrace(RaceType::Elf, Subrace::Dark)],
+            Self::DwarvenFortitude => vec![Prerequisite::Race(RaceType::Dwarf)],
+            Self::ElementalAdept | Self::SpellSniper | Self::WarCaster => {
+                vec![Prerequisite::Spellcasting]
+            }
+            // Matches half-elves as well
+            Self::ElvenAccuracy => vec![Prerequisite::Any(vec![
+                Prerequisite::Race(RaceType::Elf),
+                Prerequisite::Race(RaceType::HalfElf),
+            ])],
+            Self::FadeAway => vec![Prerequisite::Race(RaceType::Gnome)],
+            Self::FeyTeleportation => {
+                vec![Prerequisite::Any(vec![
+                    Prerequisite::Subrace(RaceType::Elf, Subrace::High),
+                    Prerequisite::Subrace(RaceType::Elf, Subrace::Moon),
+                    Prerequisite::Subrace(RaceType::Elf, Subrace::Sun),
+                ])]
+            }
+            Self::FlamesOfPhlegethos | Self::InfernalConstitution => {
+                vec![Prerequisite::Race(RaceType::Tiefling)]
+            }
+            Self::Grappler => vec![Prerequisite::Ability(AbilityScoreType::Strength, 13)],
+            Self::HeavilyArmored | Self::MediumArmorMaster => {
+                vec![Prerequisite::Proficiency(Proficiency::Armor(
+                    ArmorType::Medium,
+                ))]
+            }
+            Self::HeavyArmorMaster => vec![Prerequisite::Proficiency(Proficiency::Armor(
+                ArmorType::Heavy,
+            ))],
+            Self::InspiringLeader => vec![Prerequisite::Ability(AbilityScoreType::Charisma, 13)],
+            Self::ModeratelyArmored => vec![Prerequisite::Proficiency(Proficiency::Armor(
+                ArmorType::Light,
+            ))],
+            Self::OrcishFury => vec![Prerequisite::Race(RaceType::HalfOrc)],
+            Self::Prodigy => vec![Prerequisite::Any(vec![
+                Prerequisite::Race(RaceType::HalfElf),
+                Prerequisite::Race(RaceType::HalfOrc),
+                Prerequisite::Race(RaceType::Human),
+            ])],
+            Self::RitualCaster => vec![Prerequisite::Any(vec![
+                Prerequisite::Ability(AbilityScoreType::Intelligence, 13),
+                Prerequisite::Ability(AbilityScoreType::Wisdom, 13),
+            ])],
+            Self::SquatNimbleness => vec![Prerequisite::Any(vec![
+                Prerequisite::Race(RaceType::Dwarf),
+                Prerequisite::Size(Size::Small),
+            ])],
+            Self::WoodElfMagic => vec![Prerequisite::Subrace(RaceType::Elf, Subrace::Wood)],
+            Self::Actor
+            | Self::Alert
+            | Self::Athlete
+            | Self::Charger
+            | Self::CrossbowExpert
+            | Self::DualWielder
+            | Self::DungeonDelver
+            | Self::Durable
+            | Self::GreatWeaponMaster
+            | Self::Healer
+            | Self::KeenMind
+            | Self::LightlyArmored
+            | Self::Linguist
+            | Self::Lucky
+            | Self::MageSlayer
+            | Self::MagicInitiate
+            | Self::MartialAdept
+            | Self::Mobile
+            | Self::MountedCombatant
+            | Self::Observant
+            | Self::PolearmMaster
+            | Self::Resilient
+            | Self::SavageAttacker
+            | Self::Sentinel
+            | Self::Sharpshooter
+            | Self::ShieldMaster
+            | Self::Skilled
+            | Self::TavernBrawler
+            | Self::Tough
+            | Self::WeaponMaster => vec![],
+        }
+    }
+
+    /// Abilities the feat can increase by 1 (only one of them is chosen)
+    #[must_use]
+    pub fn ability_increases(self) -> Vec<AbilityScoreType> {
+        match self {
+            Self::Actor => vec![AbilityScoreType::Charisma],
+            Self::Athlete
+            | Self::LightlyArmored
+            | Self::ModeratelyArmored
+            | Self::SquatNimbleness
+            | Self::WeaponMaster => vec![AbilityScoreType::Strength, AbilityScoreType::Dexterity],
+            Self::DragonFear | Self::DragonHide => vec![
+                AbilityScoreType::Strength,
+                AbilityScoreType::Constitution,
+                AbilityScoreType::Charisma,
+            ],
+            Self::Durable | Self::DwarvenFortitude | Self::InfernalConstitution => {
+                vec![AbilityScoreType::Constitution]
+            }
+            Self::ElvenAccuracy => vec![
+                AbilityScoreType::Dexterity,
+                AbilityScoreType::Intelligence,
+                AbilityScoreType::Wisdom,
+                AbilityScoreType::Charisma,
+            ],
+            Self::FadeAway => vec![AbilityScoreType::Dexterity, AbilityScoreType::Intelligence],
+            Self::FeyTeleportation | Self::FlamesOfPhlegethos => {
+                vec![AbilityScoreType::Intelligence, AbilityScoreType::Charisma]
+            }
+            Self::HeavilyArmored | Self::HeavyArmorMaster => vec![AbilityScoreType::Strength],
+            Self::KeenMind | Self::Linguist => vec![AbilityScoreType::Intelligence],
+            Self::Observant => vec![AbilityScoreType::Intelligence, AbilityScoreType::Wisdom],
+            Self::OrcishFury | Self::TavernBrawler => {
+                vec![AbilityScoreType::Strength, AbilityScoreType::Constitution]
+            }
+            Self::Resilient => AbilityScoreType::iter().collect(),
+            Self::SecondChance => vec![
+                AbilityScoreType::Dexterity,
+                AbilityScoreType::Constitution,
+                AbilityScoreType::Charisma,
+            ],
+            Self::Alert
+            | Self::BountifulLuck
+            | Self::Charger
+            | Self::CrossbowExpert
+            | Self::DefensiveDuelist
+            | Self::DrowHighMagic
+            | Self::DualWielder
+            | Self::DungeonDelver
+            | Self::ElementalAdept
+            | Self::Grappler
+            | Self::GreatWeaponMaster
+            | Self::Healer
+            | Self::InspiringLeader
+            | Self::Lucky
+            | Self::MageSlayer
+            | Self::MagicInitiate
+            | Self::MartialAdept
+            | Self::MediumArmorMaster
+            | Self::Mobile
+            | Self::MountedCombatant
+            | Self::PolearmMaster
+            | Self::Prodigy
+            | Self::RitualCaster
+            | Self::SavageAttacker
+            | Self::Sentinel
+            | Self::Sharpshooter
+            | Self::ShieldMaster
+            | Self::Skilled
+            | Self::Skulker
+            | Self::SpellSniper
+            | Self::Tough
+            | Self::WarCaster
+            | Self::WoodElfMagic => vec![],
+        }
+    }
+
+    /// Additional hit points gained each level
+    #[must_use]
+    pub fn hit_points_per_level(self) -> i16 {
+        match self {
+            Self::Tough => 2,
+            _ => 0,
+        }
+    }
+
+    /// Bonus added to initiative
+    #[must_use]
+    pub fn initiative_bonus(self) -> i16 {
+        match self {
+            Self::Alert => 5,
+            _ => 0,
+        }
+    }
+
+    /// Bonus added to the passive score of a skill
+    #[must_use]
+    pub fn passive_bonus(self, skill: Skill) -> i16 {
+        match (self, skill) {
+            (Self::Observant, Skill::Investigation | Skill::Perception) => 5,
+            _ => 0,
+        }
+    }
+
+    /// Whether the feat is only available to certain races
+    fn racial(self) -> bool {
+        self.prerequisites().iter().any(Prerequisite::racial)
+    }
+
+    /// Whether the character meets every prerequisite of the feat
+    #[must_use]
+    pub fn available(self, character: &FeatCandidate<'_>) -> bool {
+        self.prerequisites().iter().all(|p| p.met(character))
+    }
+
+    /// Choose a feat the character qualifies for and hasn't taken yet. Racial feats, and feats that
+    /// increase one of the character's first two priority abilities, are more likely.
+    pub fn gen(
+        rng: &mut impl Rng,
+        character: &FeatCandidate<'_>,
+        taken: &[ChosenFeat],
+        priority: &[AbilityScoreType],
+    ) -> Option<ChosenFeat> {
+        let options = Self::iter()
+            .filter(|f| f.available(character) && !taken.iter().any(|t| t.feat == *f))
+            .collect::<Vec<_>>();
+        let feat = *options
+            .choose_weighted(rng, |f| {
+                let increases = f.ability_increases();
+                let useful = priority.iter().take(2).any(|a| increases.contains(a));
+                exp_weight(i16::from(f.racial()) + i16::from(useful), 0)
+            })
+            .ok()?;
+        Some(ChosenFeat::new(
+            feat,
+            character.ability_scores,
+            character.proficiencies,
+            priority,
+        ))
+    }
+}
+
+/// A feat taken by a character, along with the ability it increased (if any)
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChosenFeat {
+    pub feat: Feat,
+    pub ability: Option<AbilityScoreType>,
+}
+
+impl ChosenFeat {
+    /// Take a feat, increasing the first ability by priority that isn't already at the maximum.
+    /// Resilient avoids saving throws the character is already proficient in.
+    #[must_use]
+    pub fn new(
+        feat: Feat,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        priority: &[AbilityScoreType],
+    ) -> Self {
+        let mut increases = feat.ability_increases();
+        if feat == Feat::Resilient {
+            increases.retain(|&a| !proficiencies.contains(&Proficiency::SavingThrow(a)));
+        }
+        increases.sort_by_key(|a| priority.iter().position(|p| p == a).unwrap_or(usize::MAX));
+        let ability = increases
+            .iter()
+            .find(|&&a| ability_scores.score(a) < MAX_SCORE)
+            .or_else(|| increases.first())
+            .copied();
+        Self { feat, ability }
+    }
+}
+
+impl Citations for ChosenFeat {
+    fn citations(&self) -> CitationList {
+        CitationList(vec![self.feat.citation()])
+    }
+}
+
+impl Features for ChosenFeat {
+    fn features(&self, _: u8) -> Vec<Feature> {
+        vec![Feature {
+            title: self.feat.into(),
+            description: self.feat.description(),
+            citation: self.feat.citation(),
+            // Only Resilient's benefits depend on the ability chosen
+            scaling: match self.feat {
+                Feat::Resilient => self.ability.map(|a| a.to_string()),
+                _ => None,
+            },
+        }]
+    }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        match self.feat {
+            Feat::DrowHighMagic => vec![
+                FeatureEffect::Spell(Spell::DetectMagic),
+                FeatureEffect::Spell(Spell::Levitate),
+                FeatureEffect::Spell(Spell::DispelMagic),
+            ],
+            Feat::FeyTeleportation => vec![FeatureEffect::Spell(Spell::MistyStep)],
+            Feat::InfernalConstitution => vec![
+                FeatureEffect::DamageResistance(DamageType::Cold),
+                FeatureEffect::DamageResistance(DamageType::Poison),
+                FeatureEffect::SaveAdvantage(SaveAdvantage {
+                    abilities: None,
+                    against: Threat::Poison,
+                }),
+            ],
+            Feat::Mobile => vec![FeatureEffect::SpeedBonus(10)],
+            Feat::SquatNimbleness => vec![FeatureEffect::SpeedBonus(5)],
+            Feat::WoodElfMagic => vec![
+                FeatureEffect::Spell(Spell::Longstrider),
+                FeatureEffect::Spell(Spell::PassWithoutTrace),
+            ],
+            _ => vec![],
+        }
+    }
+}
+
+impl Languages for ChosenFeat {
+    fn languages(&self) -> Vec<Language> {
+        match self.feat {
+            Feat::FeyTeleportation => vec![Language::Sylvan],
+            _ => vec![],
+        }
+    }
+
+    fn addl_languages(&self) -> (usize, Option<LanguageType>) {
+        match self.feat {
+            Feat::Linguist => (3, None),
+            Feat::Prodigy => (1, None),
+            _ => (0, None),
+        }
+    }
+}
+
+impl Proficiencies for ChosenFeat {
+    fn proficiencies(&self) -> Vec<Proficiency> {
+        match self.feat {
+            Feat::HeavilyArmored => vec![Proficiency::Armor(ArmorType::Heavy)],
+            Feat::LightlyArmored => vec![Proficiency::Armor(ArmorType::Light)],
+            Feat::ModeratelyArmored => vec![
+                Proficiency::Armor(ArmorType::Medium),
+                Proficiency::Armor(ArmorType::Shield),
+            ],
+            Feat::Resilient => self
+                .ability
+                .map(Proficiency::SavingThrow)
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        match self.feat {
+            Feat::Prodigy => vec![
+                ProficiencyOption::Skill(None, 1),
+                ProficiencyOption::Tool(1),
+            ],
+            Feat::Skilled => vec![ProficiencyOption::Skill(None, 3)],
+            Feat::SquatNimbleness => vec![ProficiencyOption::Skill(
+                Some(vec![Skill::Acrobatics, Skill::Athletics]),
+                1,
+            )],
+            Feat::WeaponMaster => vec![ProficiencyOption::Weapon(None, None, 4)],
+            _ => vec![],
+        }
+    }
+}
+
+impl ProficiencyLevels for ChosenFeat {
+    fn expertise(&self, _: u8) -> Vec<ExpertiseOption> {
+        match self.feat {
+            Feat::Prodigy => vec![ExpertiseOption::Skill(1)],
+            _ => vec![],
+        }
+    }
+}
+
+impl fmt::Display for ChosenFeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.feat)?;
+        if let Some(ability) = self.ability {
+            write!(f, " (+1 {ability})")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+
+    fn ability_scores(score: i16) -> AbilityScores {
+        AbilityScores(AbilityScoreType::iter().map(|a| (a, score)).collect())
+    }
+
+    fn candidate<'a>(
+        ability_scores: &'a AbilityScores,
+        proficiencies: &'a [Proficiency],
+        race: RaceType,
+        subrace: Option<Subrace>,
+    ) -> FeatCandidate<'a> {
+        FeatCandidate {
+            ability_scores,
+            proficiencies,
+            race: Some(race),
+            size: Some(&Size::Medium),
+            spellcasting: false,
+            subrace,
+        }
+    }
+
+    #[test]
+    fn test_ability_prerequisite() {
+        let low = ability_scores(10);
+        let high = ability_scores(13);
+        let human = RaceType::Human;
+        assert!(!Feat::Grappler.available(&candidate(&low, &[], human, None)));
+        assert!(Feat::Grappler.available(&candidate(&high, &[], human, None)));
+        assert!(Feat::RitualCaster.available(&candidate(&high, &[], human, None)));
+    }
+
+    #[test]
+    fn test_proficiency_prerequisite() {
+        let scores = ability_scores(10);
+        let proficiencies = [Proficiency::Armor(ArmorType::Heavy)];
+        let human = RaceType::Human;
+        assert!(!Feat::HeavyArmorMaster.available(&candidate(&scores, &[], human, None)));
+        assert!(Feat::HeavyArmorMaster.available(&candidate(&scores, &proficiencies, human, None)));
+    }
+
+    #[test]
+    fn test_race_prerequisite() {
+        let scores = ability_scores(10);
+        let high_elf = candidate(&scores, &[], RaceType::Elf, Some(Subrace::High));
+        let wood_elf = candidate(&scores, &[], RaceType::Elf, Some(Subrace::Wood));
+        assert!(Feat::ElvenAccuracy.available(&high_elf));
+        assert!(Feat::ElvenAccuracy.available(&candidate(&scores, &[], RaceType::HalfElf, None)));
+        assert!(!Feat::ElvenAccuracy.available(&candidate(&scores, &[], RaceType::Human, None)));
+        assert!(Feat::WoodElfMagic.available(&wood_elf));
+        assert!(!Feat::WoodElfMagic.available(&high_elf));
+        assert!(Feat::FeyTeleportation.available(&high_elf));
+        assert!(!Feat::FeyTeleportation.available(&wood_elf));
+        let dark_elf = candidate(&scores, &[], RaceType::Elf, Some(Subrace::Dark));
+        assert!(Feat::DrowHighMagic.available(&dark_elf));
+        assert!(!Feat::DrowHighMagic.available(&wood_elf));
+        assert!(Feat::Prodigy.available(&candidate(
+            &scores,
+            &[],
+            RaceType::Human,
+            Some(Subrace::Variant)
+        )));
+        assert!(!Feat::OrcishFury.available(&candidate(&scores, &[], RaceType::Orc, None)));
+        let mut halfling = candidate(&scores, &[], RaceType::Halfling, Some(Subrace::Lightfoot));
+        halfling.size = Some(&Size::Small);
+        assert!(Feat::SquatNimbleness.available(&halfling));
+    }
+
+    #[test]
+    fn test_alert_and_observant() {
+        assert_eq!(Feat::Alert.initiative_bonus(), 5);
+        assert_eq!(Feat::Observant.initiative_bonus(), 0);
+        assert_eq!(Feat::Observant.passive_bonus(Skill::Perception), 5);
+        assert_eq!(Feat::Observant.passive_bonus(Skill::Insight), 0);
+    }
+
+    #[test]
+    fn test_chosen_ability() {
+        let mut scores = ability_scores(10);
+        let priority = [AbilityScoreType::Dexterity, AbilityScoreType::Strength];
+        let feat = ChosenFeat::new(Feat::Athlete, &scores, &[], &priority);
+        assert_eq!(feat.ability, Some(AbilityScoreType::Dexterity));
+        scores.0.insert(AbilityScoreType::Dexterity, 20);
+        let feat = ChosenFeat::new(Feat::Athlete, &scores, &[], &priority);
+        assert_eq!(feat.ability, Some(AbilityScoreType::Strength));
+        assert_eq!(
+            ChosenFeat::new(Feat::Lucky, &scores, &[], &priority).ability,
+            None
+        );
+        let saves = [Proficiency::SavingThrow(AbilityScoreType::Strength)];
+        let feat = ChosenFeat::new(Feat::Resilient, &scores, &saves, &priority);
+        assert_eq!(feat.ability, Some(AbilityScoreType::Constitution));
+    }
+
+    #[test]
+    fn test_resilient() {
+        let feat = ChosenFeat {
+            feat: Feat::Resilient,
+            ability: Some(AbilityScoreType::Constitution),
+        };
+        assert_eq!(
+            feat.proficiencies(),
+            vec![Proficiency::SavingThrow(AbilityScoreType::Constitution)]
+        );
+        assert_eq!(feat.to_string(), "Resilient (+1 CON)");
+    }
+
+    #[test]
+    fn test_prodigy() {
+        let feat = ChosenFeat {
+            feat: Feat::Prodigy,
+            ability: None,
+        };
+        assert_eq!(feat.expertise(1), vec![ExpertiseOption::Skill(1)]);
+    }
+
+    #[test]
+    fn test_drow_high_magic() {
+        let feat = ChosenFeat {
+            feat: Feat::DrowHighMagic,
+            ability: None,
+        };
+        assert_eq!(
+            feat.effects(1),
+            vec![
+                FeatureEffect::Spell(Spell::DetectMagic),
+                FeatureEffect::Spell(Spell::Levitate),
+                FeatureEffect::Spell(Spell::DispelMagic),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gen() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let scores = ability_scores(10);
+        let character = candidate(&scores, &[], RaceType::Human, None);
+        let taken = [ChosenFeat {
+            feat: Feat::Alert,
+            ability: None,
+        }];
+        for _ in 0..20 {
+            let feat = Feat::gen(&mut rng, &character, &taken, &[]).unwrap();
+            assert!(feat.feat.available(&character));
+            assert_ne!(feat.feat, Feat::Alert);
+        }
+        insta::assert_yaml_snapshot!(Feat::gen(&mut rng, &character, &taken, &[]));
+    }
+}
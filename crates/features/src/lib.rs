@@ -1,27 +1,161 @@
 use std::fmt;
 
 use citation::Citation;
+use gear::damage::DamageType;
 use serde::{Deserialize, Serialize};
+use spells::Spell;
+use stats::{ability::AbilityScoreType, proficiencies::Proficiency};
+use strum::Display;
+
+/// Conditions that can affect a character
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Condition {
+    Blinded,
+    Charmed,
+    Deafened,
+    Exhaustion,
+    Frightened,
+    Grappled,
+    Incapacitated,
+    Invisible,
+    Paralyzed,
+    Petrified,
+    Poisoned,
+    Prone,
+    Restrained,
+    Stunned,
+    Unconscious,
+}
+
+/// Special senses a character can perceive with
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Sense {
+    Blindsight,
+    Darkvision,
+    Tremorsense,
+    Truesight,
+}
+
+/// What a saving throw made with advantage is against
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Threat {
+    Charmed,
+    Frightened,
+    Illusions,
+    Magic,
+    Paralyzed,
+    Poison,
+}
+
+impl fmt::Display for Threat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Charmed => write!(f, "being charmed"),
+            Self::Frightened => write!(f, "being frightened"),
+            Self::Illusions => write!(f, "illusions"),
+            Self::Magic => write!(f, "magic"),
+            Self::Paralyzed => write!(f, "being paralyzed"),
+            Self::Poison => write!(f, "poison"),
+        }
+    }
+}
+
+/// Advantage on saving throws against a given threat
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SaveAdvantage {
+    /// Saving throws it applies to. Applies to every saving throw if `None`.
+    pub abilities: Option<Vec<AbilityScoreType>>,
+    /// What the saving throws are against
+    pub against: Threat,
+}
+
+impl SaveAdvantage {
+    /// Advantage on every saving throw against a given threat
+    #[must_use]
+    pub fn against(against: Threat) -> Self {
+        Self {
+            abilities: None,
+            against,
+        }
+    }
+}
+
+impl fmt::Display for SaveAdvantage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Advantage on ")?;
+        if let Some(abilities) = &self.abilities {
+            let abilities = abilities
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "{abilities} ")?;
+        }
+        write!(f, "saving throws against {}", self.against)
+    }
+}
+
+/// Mechanical effect of a feature or trait, which is applied to the character's derived stats
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum FeatureEffect {
+    /// Immunity to a condition
+    ConditionImmunity(Condition),
+    /// Immunity to a type of damage
+    DamageImmunity(DamageType),
+    /// Resistance to a type of damage
+    DamageResistance(DamageType),
+    /// Count as one size larger when determining carrying capacity and the weight that can be pushed,
+    /// dragged or lifted
+    PowerfulBuild,
+    /// Proficiency gained through the feature
+    Proficiency(Proficiency),
+    /// Advantage on some saving throws
+    SaveAdvantage(SaveAdvantage),
+    /// A special sense, with its range in feet
+    Sense(Sense, u16),
+    /// Increase to walking speed, in feet
+    SpeedBonus(u8),
+    /// Increase to walking speed, in feet, while not wearing heavy armor
+    SpeedBonusWithoutHeavyArmor(u8),
+    /// A spell that can be cast through the feature
+    Spell(Spell),
+    /// Increase to walking speed, in feet, while not wearing armor or wielding a shield
+    UnarmoredSpeedBonus(u8),
+}
 
 /// A feature or trait a character has.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Feature {
     /// Name of the feature or trait.
     pub title: &'static str,
+    /// Short summary of the rules for the feature or trait.
+    pub description: &'static str,
     /// Citation for where more information about this feature is available.
     pub citation: Citation,
+    /// Value that changes with the character's level, such as damage dice or number of uses.
+    pub scaling: Option<String>,
 }
 
 impl fmt::Display for Feature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} ({})", self.title, self.citation)
+        write!(f, "{}", self.title)?;
+        if let Some(scaling) = &self.scaling {
+            write!(f, ": {scaling}")?;
+        }
+        write!(f, " ({})", self.citation)?;
+        write!(f, "\n  {}", self.description)
     }
 }
 
 /// Trait for objects that provide features to a character.
 pub trait Features {
-    // Return a list of features this thing provides
-    fn features(&self) -> Vec<Feature> {
+    // Return a list of features this thing provides at a given character level
+    fn features(&self, _: u8) -> Vec<Feature> {
+        vec![]
+    }
+
+    /// Return the mechanical effects of the features this thing provides at a given character level
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
         vec![]
     }
 }
@@ -35,7 +169,40 @@ mod tests {
     fn test_display() {
         let feature = Feature {
             title: "Title",
+            description: "Description of the rules.",
+            citation: Citation(Book::Phb, 1),
+            scaling: None,
+        };
+        insta::assert_snapshot!(format!("{}", feature));
+    }
+
+    #[test]
+    fn test_save_advantage_display() {
+        assert_eq!(
+            SaveAdvantage::against(Threat::Poison).to_string(),
+            "Advantage on saving throws against poison"
+        );
+        let gnome_cunning = SaveAdvantage {
+            abilities: Some(vec![
+                AbilityScoreType::Intelligence,
+                AbilityScoreType::Wisdom,
+                AbilityScoreType::Charisma,
+            ]),
+            against: Threat::Magic,
+        };
+        assert_eq!(
+            gnome_cunning.to_string(),
+            "Advantage on INT, WIS, CHA saving throws against magic"
+        );
+    }
+
+    #[test]
+    fn test_display_scaling() {
+        let feature = Feature {
+            title: "Title",
+            description: "Description of the rules.",
             citation: Citation(Book::Phb, 1),
+            scaling: Some("2d6".into()),
         };
         insta::assert_snapshot!(format!("{}", feature));
     }
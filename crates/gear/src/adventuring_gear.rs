@@ -3,6 +3,8 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter};
 
+use crate::currency::Coin;
+
 #[derive(Copy, Clone, Debug, Deserialize, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum ArcaneFocus {
     Crystal,
@@ -25,6 +27,29 @@ impl fmt::Display for ArcaneFocus {
     }
 }
 
+impl ArcaneFocus {
+    /// Price of the arcane focus (PHB p150)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Crystal | Self::Rod | Self::Wand => (Coin::Gold, 10),
+            Self::Orb => (Coin::Gold, 20),
+            Self::Staff => (Coin::Gold, 5),
+        }
+    }
+
+    /// Weight of the arcane focus in pounds (PHB p150)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Crystal | Self::Wand => 1.0,
+            Self::Orb => 3.0,
+            Self::Rod => 2.0,
+            Self::Staff => 4.0,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum DruidicFocus {
     SprigOfMistletoe,
@@ -45,6 +70,28 @@ impl fmt::Display for DruidicFocus {
     }
 }
 
+impl DruidicFocus {
+    /// Price of the druidic focus (PHB p150)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::SprigOfMistletoe | Self::Totem => (Coin::Gold, 1),
+            Self::WoodenStaff => (Coin::Gold, 5),
+            Self::YewWand => (Coin::Gold, 10),
+        }
+    }
+
+    /// Weight of the druidic focus in pounds (PHB p150)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::SprigOfMistletoe | Self::Totem => 0.0,
+            Self::WoodenStaff => 4.0,
+            Self::YewWand => 1.0,
+        }
+    }
+}
+
 #[derive(
     Copy, Clone, Debug, Deserialize, Display, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -54,6 +101,26 @@ pub enum HolySymbol {
     Reliquary,
 }
 
+impl HolySymbol {
+    /// Price of the holy symbol (PHB p150)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Amulet | Self::Emblem | Self::Reliquary => (Coin::Gold, 5),
+        }
+    }
+
+    /// Weight of the holy symbol in pounds (PHB p150)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Amulet => 1.0,
+            Self::Emblem => 0.0,
+            Self::Reliquary => 2.0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum OtherGear {
     Abacus,
@@ -193,6 +260,167 @@ pub enum OtherGear {
     Whetstone,
 }
 
+impl OtherGear {
+    /// Price of the item (PHB p150)
+    #[must_use]
+    pub fn cost(&self) -> (Coin, u32) {
+        match self {
+            Self::Abacus
+            | Self::Backpack
+            | Self::Barrel
+            | Self::BottleGlass
+            | Self::ClothesTravelers
+            | Self::Crowbar
+            | Self::GrapplingHook
+            | Self::HammerSledge
+            | Self::Manacles
+            | Self::PickMiners
+            | Self::PotIron
+            | Self::Shovel
+            | Self::TentTwoPerson => (Coin::Gold, 2),
+            Self::Acid
+            | Self::Book
+            | Self::ClimbersKit
+            | Self::ComponentPouch
+            | Self::HolyWater
+            | Self::Hourglass => (Coin::Gold, 25),
+            Self::AlchemistsFire | Self::Antitoxin | Self::PotionOfHealing | Self::Spellbook => {
+                (Coin::Gold, 50)
+            }
+            Self::BallBearings
+            | Self::Bedroll
+            | Self::Bell
+            | Self::BlockAndTackle
+            | Self::Caltrops
+            | Self::CaseCrossbowBolt
+            | Self::CaseMapOrScroll
+            | Self::FishingTackle
+            | Self::Hammer
+            | Self::Quiver
+            | Self::Robes
+            | Self::RopeHempen
+            | Self::SpikesIron
+            | Self::Vial => (Coin::Gold, 1),
+            Self::Basket => (Coin::Silver, 4),
+            Self::Blanket
+            | Self::ClothesCommon
+            | Self::Lamp
+            | Self::Pouch
+            | Self::Rations
+            | Self::SealingWax
+            | Self::Tinderbox => (Coin::Silver, 5),
+            Self::Bucket | Self::Piton | Self::Pole | Self::SignalWhistle => (Coin::Copper, 5),
+            Self::Candle | Self::Chalk | Self::Sack | Self::Torch | Self::Whetstone => {
+                (Coin::Copper, 1)
+            }
+            Self::Chain
+            | Self::Chest
+            | Self::ClothesCostume
+            | Self::HealersKit
+            | Self::HuntingTrap
+            | Self::LanternHooded
+            | Self::MirrorSteel
+            | Self::Perfume
+            | Self::ScaleMerchants
+            | Self::SignetRing => (Coin::Gold, 5),
+            Self::ClothesFine => (Coin::Gold, 15),
+            Self::FlaskOrTankard | Self::InkPen | Self::JugOrPitcher | Self::Soap => {
+                (Coin::Copper, 2)
+            }
+            Self::Ink | Self::LanternBullseye | Self::Lock | Self::RopeSilk => (Coin::Gold, 10),
+            Self::Ladder | Self::Oil | Self::Parchment => (Coin::Silver, 1),
+            Self::MagnifyingGlass | Self::PoisonBasic => (Coin::Gold, 100),
+            Self::MessKit | Self::Paper | Self::Waterskin => (Coin::Silver, 2),
+            Self::RamPortable => (Coin::Gold, 4),
+            Self::Spyglass => (Coin::Gold, 1000),
+        }
+    }
+
+    /// Weight of the item in pounds (PHB p150)
+    #[must_use]
+    pub fn weight(&self) -> f32 {
+        match self {
+            Self::Abacus
+            | Self::BallBearings
+            | Self::Basket
+            | Self::BottleGlass
+            | Self::Bucket
+            | Self::Caltrops
+            | Self::ComponentPouch
+            | Self::LanternBullseye
+            | Self::LanternHooded
+            | Self::Rations => 2.0,
+            Self::Acid
+            | Self::AlchemistsFire
+            | Self::CaseCrossbowBolt
+            | Self::CaseMapOrScroll
+            | Self::FlaskOrTankard
+            | Self::HolyWater
+            | Self::Hourglass
+            | Self::Lamp
+            | Self::Lock
+            | Self::MessKit
+            | Self::Oil
+            | Self::Pouch
+            | Self::Quiver
+            | Self::Spyglass
+            | Self::Tinderbox
+            | Self::Torch
+            | Self::Whetstone => 1.0,
+            Self::Antitoxin
+            | Self::Bell
+            | Self::Candle
+            | Self::Chalk
+            | Self::Ink
+            | Self::InkPen
+            | Self::MagnifyingGlass
+            | Self::Paper
+            | Self::Parchment
+            | Self::Perfume
+            | Self::PoisonBasic
+            | Self::SealingWax
+            | Self::SignalWhistle
+            | Self::SignetRing
+            | Self::Soap
+            | Self::Vial => 0.0,
+            Self::Backpack
+            | Self::BlockAndTackle
+            | Self::Book
+            | Self::Crowbar
+            | Self::RopeSilk
+            | Self::Shovel
+            | Self::SpikesIron
+            | Self::Waterskin => 5.0,
+            Self::Barrel => 70.0,
+            Self::Bedroll | Self::Pole => 7.0,
+            Self::Blanket
+            | Self::ClothesCommon
+            | Self::Hammer
+            | Self::HealersKit
+            | Self::ScaleMerchants
+            | Self::Spellbook => 3.0,
+            Self::Chain
+            | Self::HammerSledge
+            | Self::PickMiners
+            | Self::PotIron
+            | Self::RopeHempen => 10.0,
+            Self::Chest | Self::HuntingTrap | Self::Ladder => 25.0,
+            Self::ClimbersKit => 12.0,
+            Self::ClothesCostume
+            | Self::ClothesTravelers
+            | Self::FishingTackle
+            | Self::GrapplingHook
+            | Self::JugOrPitcher
+            | Self::Robes => 4.0,
+            Self::ClothesFine | Self::Manacles => 6.0,
+            Self::MirrorSteel | Self::PotionOfHealing | Self::Sack => 0.5,
+            Self::Piton => 0.25,
+            Self::RamPortable => 35.0,
+            Self::TentTwoPerson => 20.0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Gear {
     ArcaneFocus(ArcaneFocus),
@@ -211,3 +439,27 @@ impl fmt::Display for Gear {
         }
     }
 }
+
+impl Gear {
+    /// Price of the item
+    #[must_use]
+    pub fn cost(&self) -> (Coin, u32) {
+        match self {
+            Self::ArcaneFocus(g) => g.cost(),
+            Self::DruidicFocus(g) => g.cost(),
+            Self::HolySymbol(g) => g.cost(),
+            Self::Other(g) => g.cost(),
+        }
+    }
+
+    /// Weight of the item in pounds
+    #[must_use]
+    pub fn weight(&self) -> f32 {
+        match self {
+            Self::ArcaneFocus(g) => g.weight(),
+            Self::DruidicFocus(g) => g.weight(),
+            Self::HolySymbol(g) => g.weight(),
+            Self::Other(g) => g.weight(),
+        }
+    }
+}
@@ -3,6 +3,8 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter};
 
+use crate::currency::Coin;
+
 /// Classes of armor different items fall under
 #[derive(
     Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize,
@@ -51,6 +53,70 @@ impl fmt::Display for Armor {
 }
 
 impl Armor {
+    /// Price of the armor (PHB p145)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Padded => (Coin::Gold, 5),
+            Self::Leather | Self::Hide | Self::Shield => (Coin::Gold, 10),
+            Self::StuddedLeather => (Coin::Gold, 45),
+            Self::ChainShirt | Self::ScaleMail => (Coin::Gold, 50),
+            Self::Breastplate => (Coin::Gold, 400),
+            Self::HalfPlate => (Coin::Gold, 750),
+            Self::RingMail => (Coin::Gold, 30),
+            Self::ChainMail => (Coin::Gold, 75),
+            Self::Splint => (Coin::Gold, 200),
+            Self::Plate => (Coin::Gold, 1500),
+        }
+    }
+
+    /// Armor Class provided by the armor, or the bonus provided by a shield
+    pub fn armor_class(self) -> i16 {
+        match self {
+            Self::Shield => 2,
+            Self::Padded | Self::Leather => 11,
+            Self::StuddedLeather | Self::Hide => 12,
+            Self::ChainShirt => 13,
+            Self::ScaleMail | Self::Breastplate | Self::RingMail => 14,
+            Self::HalfPlate => 15,
+            Self::ChainMail => 16,
+            Self::Splint => 17,
+            Self::Plate => 18,
+        }
+    }
+
+    /// Maximum Dexterity modifier that can be added to the Armor Class, if there is one
+    pub fn max_dex_modifier(self) -> Option<i16> {
+        match self.armor_type() {
+            ArmorType::Light | ArmorType::Shield => None,
+            ArmorType::Medium => Some(2),
+            ArmorType::Heavy => Some(0),
+        }
+    }
+
+    /// Strength score needed to avoid having speed reduced by 10 feet
+    pub fn strength_requirement(self) -> Option<i16> {
+        match self {
+            Self::ChainMail => Some(13),
+            Self::Splint | Self::Plate => Some(15),
+            _ => None,
+        }
+    }
+
+    /// Whether the armor gives disadvantage on Dexterity (Stealth) checks
+    pub fn stealth_disadvantage(self) -> bool {
+        matches!(
+            self,
+            Self::Padded
+                | Self::ScaleMail
+                | Self::HalfPlate
+                | Self::RingMail
+                | Self::ChainMail
+                | Self::Splint
+                | Self::Plate
+        )
+    }
+
     pub fn armor_type(self) -> ArmorType {
         match self {
             Self::Padded | Self::Leather | Self::StuddedLeather => ArmorType::Light,
@@ -63,4 +129,22 @@ impl Armor {
             Self::Shield => ArmorType::Shield,
         }
     }
+
+    /// Weight of the armor in pounds (PHB p145)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Padded => 8.0,
+            Self::Leather => 10.0,
+            Self::StuddedLeather => 13.0,
+            Self::Hide => 12.0,
+            Self::ChainShirt | Self::Breastplate => 20.0,
+            Self::ScaleMail => 45.0,
+            Self::HalfPlate | Self::RingMail => 40.0,
+            Self::ChainMail => 55.0,
+            Self::Splint => 60.0,
+            Self::Plate => 65.0,
+            Self::Shield => 6.0,
+        }
+    }
 }
@@ -1,7 +1,11 @@
+use std::{collections::BTreeMap, fmt, iter};
+
 use serde::{Deserialize, Serialize};
-use strum::Display;
+use strum::{Display, EnumIter, IntoEnumIterator};
 
-#[derive(Copy, Clone, Debug, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(
+    Copy, Clone, Debug, Deserialize, Display, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize,
+)]
 pub enum Coin {
     #[strum(serialize = "cp")]
     Copper,
@@ -15,8 +19,189 @@ pub enum Coin {
     Platinum,
 }
 
+impl Coin {
+    /// How many copper pieces one of this coin is worth
+    #[must_use]
+    pub fn copper_value(self) -> u32 {
+        match self {
+            Self::Copper => 1,
+            Self::Silver => 10,
+            Self::Electrum => 50,
+            Self::Gold => 100,
+            Self::Platinum => 1000,
+        }
+    }
+}
+
 impl Default for Coin {
     fn default() -> Self {
         Self::Gold
     }
 }
+
+/// Coins of every denomination a character is carrying
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Purse(BTreeMap<Coin, u32>);
+
+impl Purse {
+    /// Make change for a value in copper pieces, using as few coins as possible.
+    ///
+    /// Electrum is left out, since most merchants don't make change with it.
+    #[must_use]
+    pub fn from_copper(mut value: u32) -> Self {
+        let mut purse = Self::default();
+        for coin in [Coin::Platinum, Coin::Gold, Coin::Silver, Coin::Copper] {
+            purse.add(coin, value / coin.copper_value());
+            value %= coin.copper_value();
+        }
+        purse
+    }
+
+    /// Add an amount of a given coin
+    pub fn add(&mut self, coin: Coin, amount: u32) {
+        if amount > 0 {
+            *self.0.entry(coin).or_default() += amount;
+        }
+    }
+
+    /// Add all of the coins in another purse
+    pub fn extend(&mut self, other: &Self) {
+        for (coin, amount) in &other.0 {
+            self.add(*coin, *amount);
+        }
+    }
+
+    /// Remove an amount of a given coin
+    fn remove(&mut self, coin: Coin, amount: u32) {
+        if let Some(held) = self.0.get_mut(&coin) {
+            *held -= amount;
+            if *held == 0 {
+                self.0.remove(&coin);
+            }
+        }
+    }
+
+    /// Pay for something costing an amount of a given coin with the coins in the purse. Coins of
+    /// that denomination are used first, then the rest from smallest to largest, only breaking a
+    /// larger coin for change if they don't add up to the exact cost.
+    /// Returns false, leaving the purse untouched, if there isn't enough money.
+    pub fn spend(&mut self, coin: Coin, amount: u32) -> bool {
+        let mut remaining = coin.copper_value() * amount;
+        if remaining > self.copper_value() {
+            return false;
+        }
+        for coin in iter::once(coin).chain(Coin::iter()) {
+            let paid = self.amount(coin).min(remaining / coin.copper_value());
+            self.remove(coin, paid);
+            remaining -= paid * coin.copper_value();
+        }
+        // Every coin left is worth more than what's still owed, so break the smallest one
+        if remaining > 0 {
+            if let Some(coin) = Coin::iter().find(|&coin| self.amount(coin) > 0) {
+                self.remove(coin, 1);
+                self.extend(&Self::from_copper(coin.copper_value() - remaining));
+            }
+        }
+        true
+    }
+
+    /// How many of a given coin are in the purse
+    #[must_use]
+    pub fn amount(&self, coin: Coin) -> u32 {
+        self.0.get(&coin).copied().unwrap_or_default()
+    }
+
+    /// Total value of all coins, in copper pieces
+    #[must_use]
+    pub fn copper_value(&self) -> u32 {
+        self.0
+            .iter()
+            .map(|(coin, amount)| coin.copper_value() * amount)
+            .sum()
+    }
+
+    /// Total value of all coins in a given denomination, rounded down
+    #[must_use]
+    pub fn value_in(&self, coin: Coin) -> u32 {
+        self.copper_value() / coin.copper_value()
+    }
+
+    /// Exchange all coins for as few coins as possible of the same total value
+    #[must_use]
+    pub fn consolidated(&self) -> Self {
+        Self::from_copper(self.copper_value())
+    }
+}
+
+impl fmt::Display for Purse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "0{}", Coin::Gold);
+        }
+        let coins = Coin::iter()
+            .rev()
+            .filter(|coin| self.amount(*coin) > 0)
+            .map(|coin| format!("{}{coin}", self.amount(coin)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{coins}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        let mut purse = Purse::default();
+        purse.add(Coin::Gold, 200);
+        purse.add(Coin::Gold, 100);
+        purse.add(Coin::Silver, 5);
+        assert_eq!(purse.amount(Coin::Gold), 300);
+        assert_eq!(purse.amount(Coin::Copper), 0);
+        assert_eq!(purse.copper_value(), 30_050);
+        assert_eq!(purse.value_in(Coin::Gold), 300);
+        assert_eq!(purse.to_string(), "300gp, 5sp");
+    }
+
+    #[test]
+    fn test_change() {
+        let purse = Purse::from_copper(1234);
+        assert_eq!(purse.to_string(), "1pp, 2gp, 3sp, 4cp");
+
+        let mut purse = Purse::default();
+        purse.add(Coin::Electrum, 3);
+        purse.add(Coin::Copper, 60);
+        assert_eq!(purse.consolidated().to_string(), "2gp, 1sp");
+    }
+
+    #[test]
+    fn test_spend() {
+        let mut purse = Purse::default();
+        purse.add(Coin::Gold, 10);
+        assert!(purse.spend(Coin::Silver, 5));
+        assert_eq!(purse.to_string(), "9gp, 5sp");
+        assert!(!purse.spend(Coin::Gold, 10));
+        assert_eq!(purse.copper_value(), 950);
+    }
+
+    #[test]
+    fn test_spend_keeps_coins() {
+        let mut purse = Purse::default();
+        purse.add(Coin::Gold, 2000);
+        purse.add(Coin::Electrum, 3);
+        assert!(purse.spend(Coin::Gold, 10));
+        assert_eq!(purse.to_string(), "1990gp, 3ep");
+        assert!(purse.spend(Coin::Electrum, 2));
+        assert_eq!(purse.to_string(), "1990gp, 1ep");
+        // Smaller coins are used before breaking a larger one
+        assert!(purse.spend(Coin::Silver, 7));
+        assert_eq!(purse.to_string(), "1989gp, 8sp");
+    }
+
+    #[test]
+    fn test_empty_display() {
+        assert_eq!(Purse::default().to_string(), "0gp");
+    }
+}
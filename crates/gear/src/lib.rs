@@ -1,6 +1,7 @@
 pub mod adventuring_gear;
 pub mod armor;
 pub mod currency;
+pub mod damage;
 pub mod tools;
 pub mod vehicles;
 pub mod weapons;
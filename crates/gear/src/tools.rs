@@ -4,6 +4,8 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter};
 
+use crate::currency::Coin;
+
 #[derive(
     Copy, Clone, Debug, Deserialize, Display, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -44,6 +46,50 @@ pub enum ArtisansTools {
     WoodcarversTools,
 }
 
+impl ArtisansTools {
+    /// Price of the artisan's tools (PHB p154)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::AlchemistsSupplies | Self::TinkersTools => (Coin::Gold, 50),
+            Self::BrewersSupplies | Self::SmithsTools => (Coin::Gold, 20),
+            Self::CalligraphersSupplies
+            | Self::MasonsTools
+            | Self::PaintersSupplies
+            | Self::PottersTools => (Coin::Gold, 10),
+            Self::CarpentersTools => (Coin::Gold, 8),
+            Self::CartographersTools => (Coin::Gold, 15),
+            Self::CobblersTools | Self::LeatherworkersTools => (Coin::Gold, 5),
+            Self::CooksUtensils | Self::WeaversTools | Self::WoodcarversTools => (Coin::Gold, 1),
+            Self::GlassblowersTools => (Coin::Gold, 30),
+            Self::JewelersTools => (Coin::Gold, 25),
+        }
+    }
+
+    /// Weight of the artisan's tools in pounds (PHB p154)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::AlchemistsSupplies
+            | Self::CooksUtensils
+            | Self::MasonsTools
+            | Self::SmithsTools => 8.0,
+            Self::BrewersSupplies => 9.0,
+            Self::CalligraphersSupplies
+            | Self::CobblersTools
+            | Self::GlassblowersTools
+            | Self::LeatherworkersTools
+            | Self::PaintersSupplies
+            | Self::WeaversTools
+            | Self::WoodcarversTools => 5.0,
+            Self::CarpentersTools | Self::CartographersTools => 6.0,
+            Self::JewelersTools => 2.0,
+            Self::PottersTools => 3.0,
+            Self::TinkersTools => 10.0,
+        }
+    }
+}
+
 impl Default for ArtisansTools {
     fn default() -> Self {
         Self::AlchemistsSupplies
@@ -64,6 +110,27 @@ pub enum GamingSet {
     ThreeDragonAnte,
 }
 
+impl GamingSet {
+    /// Price of the gaming set (PHB p154)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Dice => (Coin::Silver, 1),
+            Self::Dragonchess | Self::ThreeDragonAnte => (Coin::Gold, 1),
+            Self::PlayingCard => (Coin::Silver, 5),
+        }
+    }
+
+    /// Weight of the gaming set in pounds (PHB p154)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Dice | Self::PlayingCard | Self::ThreeDragonAnte => 0.0,
+            Self::Dragonchess => 0.5,
+        }
+    }
+}
+
 impl Default for GamingSet {
     fn default() -> Self {
         Self::Dice
@@ -99,6 +166,46 @@ pub enum MusicalInstrument {
     Zulkoon,
 }
 
+impl MusicalInstrument {
+    /// Price of the instrument (PHB p154). Instruments without a listed price cost the same as
+    /// the most similar instrument in the Player's Handbook.
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Bagpipes | Self::Lyre | Self::Tocken | Self::Viol | Self::Zulkoon => {
+                (Coin::Gold, 30)
+            }
+            Self::Birdpipes | Self::PanFlute => (Coin::Gold, 12),
+            Self::Drum | Self::HandDrum | Self::Tantan | Self::Wargong => (Coin::Gold, 6),
+            Self::Dulcimer => (Coin::Gold, 25),
+            Self::Flute | Self::Shawm | Self::Songhorn | Self::Thelarr => (Coin::Gold, 2),
+            Self::Glaur | Self::Longhorn | Self::Horn => (Coin::Gold, 3),
+            Self::Lute | Self::Yarting => (Coin::Gold, 35),
+        }
+    }
+
+    /// Weight of the instrument in pounds (PHB p154). Instruments without a listed weight weigh
+    /// the same as the most similar instrument in the Player's Handbook.
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Bagpipes | Self::Zulkoon => 6.0,
+            Self::Birdpipes
+            | Self::Glaur
+            | Self::Longhorn
+            | Self::Lute
+            | Self::Lyre
+            | Self::Horn
+            | Self::PanFlute
+            | Self::Tocken
+            | Self::Yarting => 2.0,
+            Self::Drum | Self::HandDrum | Self::Tantan | Self::Wargong => 3.0,
+            Self::Dulcimer => 10.0,
+            Self::Flute | Self::Shawm | Self::Songhorn | Self::Thelarr | Self::Viol => 1.0,
+        }
+    }
+}
+
 impl Default for MusicalInstrument {
     fn default() -> Self {
         Self::Bagpipes
@@ -133,3 +240,33 @@ impl fmt::Display for Tool {
         }
     }
 }
+
+impl Tool {
+    /// Price of the tool (PHB p154)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::ArtisansTools(t) => t.cost(),
+            Self::DisguiseKit | Self::ThievesTools | Self::NavigatorsTools => (Coin::Gold, 25),
+            Self::ForgerySet => (Coin::Gold, 15),
+            Self::GamingSet(t) => t.cost(),
+            Self::HerbalismKit => (Coin::Gold, 5),
+            Self::MusicalInstrument(t) => t.cost(),
+            Self::PoisonerKit => (Coin::Gold, 50),
+        }
+    }
+
+    /// Weight of the tool in pounds (PHB p154)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::ArtisansTools(t) => t.weight(),
+            Self::DisguiseKit | Self::HerbalismKit => 3.0,
+            Self::ForgerySet => 5.0,
+            Self::GamingSet(t) => t.weight(),
+            Self::MusicalInstrument(t) => t.weight(),
+            Self::NavigatorsTools | Self::PoisonerKit => 2.0,
+            Self::ThievesTools => 1.0,
+        }
+    }
+}
@@ -3,6 +3,8 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter};
 
+use crate::currency::Coin;
+
 #[derive(
     Copy, Clone, Debug, Deserialize, Display, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -26,6 +28,22 @@ pub enum Mount {
     Warhorse,
 }
 
+impl Mount {
+    /// Price of the mount (PHB p157)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Camel | Self::DraftHorse => (Coin::Gold, 50),
+            Self::Donkey | Self::Mule => (Coin::Gold, 8),
+            Self::Elephant => (Coin::Gold, 200),
+            Self::Mastiff => (Coin::Gold, 25),
+            Self::Pony => (Coin::Gold, 30),
+            Self::RidingHorse => (Coin::Gold, 75),
+            Self::Warhorse => (Coin::Gold, 400),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum LandVehicle {
     Carriage,
@@ -35,6 +53,32 @@ pub enum LandVehicle {
     Wagon,
 }
 
+impl LandVehicle {
+    /// Price of the vehicle (PHB p157)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Carriage => (Coin::Gold, 100),
+            Self::Cart => (Coin::Gold, 15),
+            Self::Chariot => (Coin::Gold, 250),
+            Self::Sled => (Coin::Gold, 20),
+            Self::Wagon => (Coin::Gold, 35),
+        }
+    }
+
+    /// Weight of the vehicle in pounds (PHB p157)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Carriage => 600.0,
+            Self::Cart => 200.0,
+            Self::Chariot => 100.0,
+            Self::Sled => 300.0,
+            Self::Wagon => 400.0,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum WaterVehicle {
     Galley,
@@ -46,6 +90,20 @@ pub enum WaterVehicle {
     Warship,
 }
 
+impl WaterVehicle {
+    /// Price of the vessel (PHB p157)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Galley => (Coin::Gold, 30000),
+            Self::Keelboat => (Coin::Gold, 3000),
+            Self::Longship | Self::SailingShip => (Coin::Gold, 10000),
+            Self::Rowboat => (Coin::Gold, 50),
+            Self::Warship => (Coin::Gold, 25000),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Vehicle {
     Land(LandVehicle),
@@ -62,3 +120,24 @@ impl fmt::Display for Vehicle {
         }
     }
 }
+
+impl Vehicle {
+    /// Price of the mount or vehicle (PHB p157)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Land(v) => v.cost(),
+            Self::Mount(v) => v.cost(),
+            Self::Water(v) => v.cost(),
+        }
+    }
+
+    /// Weight of the vehicle in pounds, if it has one. Mounts and ships don't.
+    #[must_use]
+    pub fn weight(self) -> Option<f32> {
+        match self {
+            Self::Land(v) => Some(v.weight()),
+            Self::Mount(_) | Self::Water(_) => None,
+        }
+    }
+}
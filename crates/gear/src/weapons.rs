@@ -2,6 +2,8 @@ use dice_roller::{Die, RollCmd};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter};
 
+use crate::{currency::Coin, damage::DamageType};
+
 #[derive(
     Clone, Debug, Deserialize, Display, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -81,6 +83,36 @@ pub enum Weapon {
 }
 
 impl Weapon {
+    /// Price of the weapon (PHB p149)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Self::Battleaxe
+            | Self::Blowgun
+            | Self::Flail
+            | Self::Lance
+            | Self::Maul
+            | Self::Shortsword => (Coin::Gold, 10),
+            Self::Club | Self::Sling => (Coin::Silver, 1),
+            Self::CrossbowHand => (Coin::Gold, 75),
+            Self::CrossbowHeavy | Self::Greatsword | Self::Longbow => (Coin::Gold, 50),
+            Self::CrossbowLight | Self::Rapier | Self::Scimitar | Self::Shortbow => {
+                (Coin::Gold, 25)
+            }
+            Self::Dagger | Self::LightHammer | Self::Whip => (Coin::Gold, 2),
+            Self::Dart => (Coin::Copper, 5),
+            Self::Glaive | Self::Halberd => (Coin::Gold, 20),
+            Self::Greataxe => (Coin::Gold, 30),
+            Self::Greatclub | Self::Quarterstaff => (Coin::Silver, 2),
+            Self::Handaxe | Self::Mace | Self::Pike | Self::Trident | Self::WarPick => {
+                (Coin::Gold, 5)
+            }
+            Self::Javelin => (Coin::Silver, 5),
+            Self::Longsword | Self::Morningstar | Self::Warhammer => (Coin::Gold, 15),
+            Self::Net | Self::Sickle | Self::Spear => (Coin::Gold, 1),
+        }
+    }
+
     pub fn category(self) -> WeaponCategory {
         match self {
             Self::Club
@@ -165,6 +197,88 @@ impl Weapon {
         }
     }
 
+    /// Dice rolled for damage. Weapons without any (a blowgun) deal a flat 1 damage instead.
+    pub fn damage_dice(self) -> Option<RollCmd> {
+        match self {
+            Self::Blowgun | Self::Net => None,
+            Self::Club
+            | Self::Dagger
+            | Self::LightHammer
+            | Self::Sickle
+            | Self::Dart
+            | Self::Sling
+            | Self::Whip => Some(RollCmd(1, Die::D4)),
+            Self::Handaxe
+            | Self::Javelin
+            | Self::Mace
+            | Self::Quarterstaff
+            | Self::Spear
+            | Self::Shortbow
+            | Self::Scimitar
+            | Self::Shortsword
+            | Self::Trident
+            | Self::CrossbowHand => Some(RollCmd(1, Die::D6)),
+            Self::Greatclub
+            | Self::CrossbowLight
+            | Self::Battleaxe
+            | Self::Flail
+            | Self::Longsword
+            | Self::Morningstar
+            | Self::Rapier
+            | Self::WarPick
+            | Self::Warhammer
+            | Self::Longbow => Some(RollCmd(1, Die::D8)),
+            Self::Glaive | Self::Halberd | Self::Pike | Self::CrossbowHeavy => {
+                Some(RollCmd(1, Die::D10))
+            }
+            Self::Greataxe | Self::Lance => Some(RollCmd(1, Die::D12)),
+            Self::Greatsword | Self::Maul => Some(RollCmd(2, Die::D6)),
+        }
+    }
+
+    /// Type of damage dealt, if the weapon deals any
+    pub fn damage_type(self) -> Option<DamageType> {
+        match self {
+            Self::Net => None,
+            Self::Club
+            | Self::Greatclub
+            | Self::LightHammer
+            | Self::Mace
+            | Self::Quarterstaff
+            | Self::Sling
+            | Self::Flail
+            | Self::Maul
+            | Self::Warhammer => Some(DamageType::Bludgeoning),
+            Self::Dagger
+            | Self::Javelin
+            | Self::Spear
+            | Self::CrossbowLight
+            | Self::Dart
+            | Self::Shortbow
+            | Self::Lance
+            | Self::Morningstar
+            | Self::Pike
+            | Self::Rapier
+            | Self::Shortsword
+            | Self::Trident
+            | Self::WarPick
+            | Self::Blowgun
+            | Self::CrossbowHand
+            | Self::CrossbowHeavy
+            | Self::Longbow => Some(DamageType::Piercing),
+            Self::Handaxe
+            | Self::Sickle
+            | Self::Battleaxe
+            | Self::Glaive
+            | Self::Greataxe
+            | Self::Greatsword
+            | Self::Halberd
+            | Self::Longsword
+            | Self::Scimitar
+            | Self::Whip => Some(DamageType::Slashing),
+        }
+    }
+
     pub fn default_ammunition(&self) -> Option<(Ammunition, usize)> {
         self.properties().into_iter().find_map(|p| match p {
             WeaponProperty::Ammunition(ammo, _, _) => {
@@ -265,6 +379,44 @@ impl Weapon {
             Self::Whip => vec![WeaponProperty::Finesse, WeaponProperty::Reach],
         }
     }
+
+    /// Weight of the weapon in pounds (PHB p149)
+    #[must_use]
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Battleaxe
+            | Self::Mace
+            | Self::Morningstar
+            | Self::Quarterstaff
+            | Self::Trident => 4.0,
+            Self::Blowgun | Self::Dagger => 1.0,
+            Self::Club
+            | Self::Flail
+            | Self::Handaxe
+            | Self::Javelin
+            | Self::Longbow
+            | Self::LightHammer
+            | Self::Rapier
+            | Self::Shortbow
+            | Self::Shortsword
+            | Self::Sickle
+            | Self::WarPick
+            | Self::Warhammer => 2.0,
+            Self::CrossbowHand
+            | Self::Longsword
+            | Self::Net
+            | Self::Scimitar
+            | Self::Spear
+            | Self::Whip => 3.0,
+            Self::CrossbowHeavy | Self::Pike => 18.0,
+            Self::CrossbowLight => 5.0,
+            Self::Dart => 0.25,
+            Self::Glaive | Self::Greatsword | Self::Halberd | Self::Lance => 6.0,
+            Self::Greataxe => 7.0,
+            Self::Greatclub | Self::Maul => 10.0,
+            Self::Sling => 0.0,
+        }
+    }
 }
 
 /// Types of ammunition available
@@ -293,7 +445,30 @@ impl Ammunition {
         }
     }
 
-    fn default_amount(&self) -> usize {
+    /// Price of a bundle of the ammunition, in the amount it is usually sold in (PHB p150)
+    #[must_use]
+    pub fn cost(&self) -> (Coin, u32) {
+        match self {
+            Ammunition::Arrows | Ammunition::BlowgunNeedles | Ammunition::CrossbowBolts => {
+                (Coin::Gold, 1)
+            }
+            Ammunition::SlingBullets => (Coin::Copper, 4),
+        }
+    }
+
+    /// Weight of a single piece of ammunition in pounds (PHB p150)
+    #[must_use]
+    pub fn weight(&self) -> f32 {
+        match self {
+            Ammunition::Arrows => 0.05,
+            Ammunition::BlowgunNeedles => 0.02,
+            Ammunition::CrossbowBolts | Ammunition::SlingBullets => 0.075,
+        }
+    }
+
+    /// How many pieces of ammunition are usually sold together
+    #[must_use]
+    pub fn default_amount(&self) -> usize {
         match self {
             Ammunition::Arrows | Ammunition::CrossbowBolts | Ammunition::SlingBullets => 20,
             Ammunition::BlowgunNeedles => 50,
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attacks, DamageType};
 use backstory::Backstory;
 use characteristics::{
     in_inches, names::Name, AgeRange, Appearance, CharacteristicDetails, Characteristics,
@@ -10,7 +10,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::Pantheons;
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{
@@ -18,6 +18,7 @@ use rand::{
     Rng,
 };
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::Proficiencies,
@@ -25,7 +26,7 @@ use stats::{
 use strum::{Display, EnumIter, IntoEnumIterator};
 use trinkets::Trinkets;
 
-use super::{human::Human, Race};
+use super::{human::Human, Race, Subrace};
 
 const HEIGHT_AND_WEIGHT: HeightAndWeightTable = HeightAndWeightTable {
     base_height: in_inches(4, 8),
@@ -106,6 +107,8 @@ impl AlignmentInfluences for Aasimar {
 
 impl Appearance for Aasimar {}
 
+impl Attacks for Aasimar {}
+
 impl Backstory for Aasimar {
     fn backstory(&self) -> Vec<String> {
         vec![
@@ -121,6 +124,10 @@ impl Backstory for Aasimar {
             format!("Guide's Nature: {}", self.guide.nature),
         ]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.guide = AngelicGuide::gen(rng);
+    }
 }
 
 impl Characteristics for Aasimar {
@@ -154,49 +161,61 @@ impl Citations for Aasimar {
 }
 
 impl Features for Aasimar {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, level: u8) -> Vec<Feature> {
         let mut features = vec![
-            // Blessed with a radiant soul, your vision can easily cut through darkness. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "Blessed with a radiant soul, your vision can easily cut through darkness. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 105),
+                scaling: None,
             },
-            // As an action, you can touch a creature and cause it to regain a number of hit points equal to your level. Once you use this trait, you can't use it again until you finish a long rest.
             Feature {
                 title: "Healing Hands",
+                description: "As an action, you can touch a creature and cause it to regain a number of hit points equal to your level. Once you use this trait, you can't use it again until you finish a long rest.",
                 citation: Citation(Book::Vgtm, 105),
+                scaling: Some(format!("{level} HP")),
             },
-            // You know the light cantrip. Charisma is your spellcasting ability for it.
             Feature {
                 title: "Light Bearer",
+                description: "You know the light cantrip. Charisma is your spellcasting ability for it.",
                 citation: Citation(Book::Vgtm, 105),
+                scaling: None,
             },
         ];
+        if level < 3 {
+            return features;
+        }
         features.push(match self.subrace {
-            // Starting at 3rd level, you can use your action to unleash the divine energy within yourself, causing your eyes to turn into pools of darkness and two skeletal, ghostly, flightless wings to sprout from your back. The instant you transform, other creatures within 10 feet of you that can see you must each succeed on a Charisma saving throw (DC 8 + your proficiency bonus + your Charisma modifier) or become frightened of you until the end of your next turn.
-            // Your transformation lasts for 1 minute or until you end it as a bonus action. During it, once on each of your turns, you can deal extra necrotic damage to one target when you deal damage to it with an attack or a spell. The extra necrotic damage equals your level.
-            // Once you use this trait, you can't use it again until you finish a long rest.
             AasimarSubrace::Fallen => Feature {
                 title: "Necrotic Shroud",
+                description: "Starting at 3rd level, you can use your action to unleash the divine energy within yourself, causing your eyes to turn into pools of darkness and two skeletal, ghostly, flightless wings to sprout from your back. The instant you transform, other creatures within 10 feet of you that can see you must each succeed on a Charisma saving throw (DC 8 + your proficiency bonus + your Charisma modifier) or become frightened of you until the end of your next turn. Your transformation lasts for 1 minute or until you end it as a bonus action. During it, once on each of your turns, you can deal extra necrotic damage to one target when you deal damage to it with an attack or a spell. The extra necrotic damage equals your level. Once you use this trait, you can't use it again until you finish a long rest.",
                 citation: Citation(Book::Vgtm, 105),
+                scaling: Some(format!("{level} extra necrotic damage")),
             },
-            // Starting at 3rd level, you can use your action to unleash the divine energy within yourself, causing your eyes to glimmer and two luminous, incorporeal wings to sprout from your back.
-            // Your transformation lasts for 1 minute or until you end it as a bonus action. During it, you have a flying speed of 30 feet, and once on each of your turns, you can deal extra radiant damage to one target when you deal damage to it with an attack or a spell. The extra radiant damage equals your level.
-            // Once you use this trait, you can't use it again until you finish a long rest.
             AasimarSubrace::Protector => Feature {
                 title: "Radiant Soul",
+                description: "Starting at 3rd level, you can use your action to unleash the divine energy within yourself, causing your eyes to glimmer and two luminous, incorporeal wings to sprout from your back. Your transformation lasts for 1 minute or until you end it as a bonus action. During it, you have a flying speed of 30 feet, and once on each of your turns, you can deal extra radiant damage to one target when you deal damage to it with an attack or a spell. The extra radiant damage equals your level. Once you use this trait, you can't use it again until you finish a long rest.",
                 citation: Citation(Book::Vgtm, 105),
+                scaling: Some(format!("{level} extra radiant damage")),
             },
-            // Starting at 3rd level, you can use your action to unleash the divine energy within yourself, causing a searing light to radiate from you, pour out of your eyes and mouth, and threaten to char you.
-            // Your transformation lasts for 1 minute or until you end it as a bonus action. During it, you shed bright light in a 10-foot radius and dim light for an additional 10 feet, and at the end of each of your turns, you and each creature within 10 feet of you take radiant damage equal to half your level (rounded up). In addition, once on each of your turns, you can deal extra radiant damage to one target when you deal damage to it with an attack or a spell. The extra radiant damage equals your level.
-            // Once you use this trait, you can't use it again until you finish a long rest.
             AasimarSubrace::Scourge => Feature {
                 title: "Radiant Consumption",
+                description: "Starting at 3rd level, you can use your action to unleash the divine energy within yourself, causing a searing light to radiate from you, pour out of your eyes and mouth, and threaten to char you. Your transformation lasts for 1 minute or until you end it as a bonus action. During it, you shed bright light in a 10-foot radius and dim light for an additional 10 feet, and at the end of each of your turns, you and each creature within 10 feet of you take radiant damage equal to half your level (rounded up). In addition, once on each of your turns, you can deal extra radiant damage to one target when you deal damage to it with an attack or a spell. The extra radiant damage equals your level. Once you use this trait, you can't use it again until you finish a long rest.",
                 citation: Citation(Book::Vgtm, 105),
+                scaling: Some(format!("{level} extra radiant damage")),
             },
         });
         features
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![
+            FeatureEffect::Sense(Sense::Darkvision, 60),
+            FeatureEffect::DamageResistance(DamageType::Necrotic),
+            FeatureEffect::DamageResistance(DamageType::Radiant),
+            FeatureEffect::Spell(Spell::Light),
+        ]
+    }
 }
 
 impl Languages for Aasimar {
@@ -229,6 +248,27 @@ impl Race for Aasimar {
         }
     }
 
+    fn gen_subrace(rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        let subrace = match subrace {
+            Subrace::Fallen => AasimarSubrace::Fallen,
+            Subrace::Protector => AasimarSubrace::Protector,
+            Subrace::Scourge => AasimarSubrace::Scourge,
+            _ => return None,
+        };
+        Some(Self {
+            guide: AngelicGuide::gen(rng),
+            subrace,
+        })
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        Some(match self.subrace {
+            AasimarSubrace::Fallen => Subrace::Fallen,
+            AasimarSubrace::Protector => Subrace::Protector,
+            AasimarSubrace::Scourge => Subrace::Scourge,
+        })
+    }
+
     fn abilities(&self) -> Vec<AbilityScore> {
         vec![
             AbilityScore(AbilityScoreType::Charisma, 2),
@@ -241,12 +281,6 @@ impl Race for Aasimar {
     }
 }
 
-impl Resistances for Aasimar {
-    fn resistances(&self) -> Vec<DamageType> {
-        vec![DamageType::Necrotic, DamageType::Radiant]
-    }
-}
-
 impl Trinkets for Aasimar {}
 
 impl fmt::Display for Aasimar {
@@ -352,10 +386,23 @@ mod tests {
                 guide: AngelicGuide::gen(&mut rng),
                 subrace
             }
-            .features())
+            .features(3))
             .collect::<Vec<Vec<Feature>>>())
     }
 
+    #[test]
+    fn test_transformation_starts_at_third_level() {
+        let aasimar = Aasimar {
+            guide: AngelicGuide {
+                name: "Galladia".to_string(),
+                nature: "Bookish".to_string(),
+            },
+            subrace: AasimarSubrace::Protector,
+        };
+        assert_eq!(aasimar.features(2).len(), 3);
+        assert_eq!(aasimar.features(3).len(), 4);
+    }
+
     #[test]
     fn test_snapshot_languages() {
         let aasimar = Aasimar {
@@ -410,7 +457,7 @@ mod tests {
     }
 
     #[test]
-    fn test_resistances() {
+    fn test_effects() {
         let aasimar = Aasimar {
             guide: AngelicGuide {
                 name: "Galladia".to_string(),
@@ -418,6 +465,6 @@ mod tests {
             },
             subrace: AasimarSubrace::Fallen,
         };
-        insta::assert_yaml_snapshot!(aasimar.resistances());
+        insta::assert_yaml_snapshot!(aasimar.effects(1));
     }
 }
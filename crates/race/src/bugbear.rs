@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::{Backstory, MONSTROUS_ORIGIN};
 use characteristics::{
     in_inches,
@@ -12,7 +12,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{prelude::SliceRandom, Rng};
@@ -49,10 +49,16 @@ impl AlignmentInfluences for Bugbear {
 
 impl Appearance for Bugbear {}
 
+impl Attacks for Bugbear {}
+
 impl Backstory for Bugbear {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Origin: {}", self.origin)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.origin = (*MONSTROUS_ORIGIN.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Bugbear {
@@ -80,30 +86,41 @@ impl Citations for Bugbear {
 }
 
 impl Features for Bugbear {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // When you make a melee attack on your turn, your reach for it is 5 feet greater than normal.
             Feature {
                 title: "Long-Limbed",
+                description: "When you make a melee attack on your turn, your reach for it is 5 feet greater than normal.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.
             Feature {
                 title: "Powerful Build",
+                description: "You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // If you surprise a creature and hit it with an attack on your first turn in combat, the attack deals an extra 2d6 damage to it. You can use this trait only once per combat.
             Feature {
                 title: "Surprise Attack",
+                description: "If you surprise a creature and hit it with an attack on your first turn in combat, the attack deals an extra 2d6 damage to it. You can use this trait only once per combat.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![
+            FeatureEffect::PowerfulBuild,
+            FeatureEffect::Sense(Sense::Darkvision, 60),
+        ]
+    }
 }
 
 impl Languages for Bugbear {
@@ -147,8 +164,6 @@ impl Race for Bugbear {
     }
 }
 
-impl Resistances for Bugbear {}
-
 impl Trinkets for Bugbear {}
 
 impl fmt::Display for Bugbear {
@@ -221,7 +236,7 @@ mod tests {
         let bugbear = Bugbear {
             origin: String::new(),
         };
-        insta::assert_yaml_snapshot!(bugbear.features());
+        insta::assert_yaml_snapshot!(bugbear.features(1));
     }
 
     #[test]
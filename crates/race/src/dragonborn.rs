@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attack, Attacks, Damage, DamageType, Hit};
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -15,7 +15,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{
@@ -24,7 +24,7 @@ use rand::{
 };
 use serde::{Deserialize, Serialize};
 use stats::{
-    ability::{AbilityScore, AbilityScoreType},
+    ability::{AbilityScore, AbilityScoreType, AbilityScores},
     proficiencies::Proficiencies,
 };
 use strum::{Display, EnumIter, IntoEnumIterator};
@@ -67,6 +67,19 @@ pub struct Dragonborn {
 }
 
 impl Dragonborn {
+    /// Damage dice of the breath weapon, which increases at 6th, 11th, and 16th level.
+    fn breath_weapon_roll(level: u8) -> RollCmd {
+        RollCmd(
+            match level {
+                0..=5 => 2,
+                6..=10 => 3,
+                11..=15 => 4,
+                16..=u8::MAX => 5,
+            },
+            Die::D6,
+        )
+    }
+
     fn damage_type(&self) -> DamageType {
         match self.ancestry {
             DraconicAncestry::Black | DraconicAncestry::Copper => DamageType::Acid,
@@ -78,45 +91,6 @@ impl Dragonborn {
             DraconicAncestry::Silver | DraconicAncestry::White => DamageType::Cold,
         }
     }
-
-    // fn breath_weapon(&self, character: &Character) -> Attack {
-    //     let (save_type, range) = match self.ancestry {
-    //         DraconicAncestry::Black
-    //         | DraconicAncestry::Blue
-    //         | DraconicAncestry::Brass
-    //         | DraconicAncestry::Bronze
-    //         | DraconicAncestry::Copper => (AbilityScoreType::Dexterity, "5 by 30 ft. line"),
-    //         DraconicAncestry::Gold | DraconicAncestry::Red => {
-    //             (AbilityScoreType::Dexterity, "15 ft. cone")
-    //         }
-    //         DraconicAncestry::Green | DraconicAncestry::Silver | DraconicAncestry::White => {
-    //             (AbilityScoreType::Constitution, "15 ft. cone")
-    //         }
-    //     };
-    //     Attack {
-    //         citation: Citation(Book::Phb, 34),
-    //         damage: Damage {
-    //             damage_type: self.damage_type(),
-    //             modifier: 0,
-    //             roll: RollCmd(
-    //                 match character.level {
-    //                     0..=5 => 2,
-    //                     6..=10 => 3,
-    //                     11..=15 => 4,
-    //                     16..=u8::MAX => 5,
-    //                 },
-    //                 Die::D6,
-    //             ),
-    //         },
-    //         hit: Hit::DC(
-    //             save_type,
-    //             8 + character.abilities.modifier(AbilityScoreType::Constitution)
-    //                 + character.proficiency_bonus(),
-    //         ),
-    //         name: "Breath Weapon",
-    //         range,
-    //     }
-    // }
 }
 
 impl AlignmentInfluences for Dragonborn {
@@ -139,6 +113,45 @@ impl AlignmentInfluences for Dragonborn {
 
 impl Appearance for Dragonborn {}
 
+impl Attacks for Dragonborn {
+    /// Exhale destructive energy, with the size, shape, and saving throw determined by your ancestry.
+    fn attacks(
+        &self,
+        level: u8,
+        ability_scores: &AbilityScores,
+        proficiency_bonus: i16,
+    ) -> Vec<Attack> {
+        let (save_type, range) = match self.ancestry {
+            DraconicAncestry::Black
+            | DraconicAncestry::Blue
+            | DraconicAncestry::Brass
+            | DraconicAncestry::Bronze
+            | DraconicAncestry::Copper => (AbilityScoreType::Dexterity, "5 by 30 ft. line"),
+            DraconicAncestry::Gold | DraconicAncestry::Red => {
+                (AbilityScoreType::Dexterity, "15 ft. cone")
+            }
+            DraconicAncestry::Green | DraconicAncestry::Silver | DraconicAncestry::White => {
+                (AbilityScoreType::Constitution, "15 ft. cone")
+            }
+        };
+        vec![Attack {
+            citation: Citation(Book::Phb, 34),
+            damage: Some(Damage {
+                damage_type: self.damage_type(),
+                modifier: 0,
+                roll: Some(Self::breath_weapon_roll(level)),
+            }),
+            hit: Hit::DC(
+                save_type,
+                8 + ability_scores.modifier(AbilityScoreType::Constitution) + proficiency_bonus,
+            ),
+            name: "Breath Weapon".to_string(),
+            properties: vec!["Half damage on a successful save".to_string()],
+            range: range.to_string(),
+        }]
+    }
+}
+
 impl Backstory for Dragonborn {}
 
 impl Characteristics for Dragonborn {
@@ -166,15 +179,24 @@ impl Citations for Dragonborn {
 }
 
 impl Features for Dragonborn {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, level: u8) -> Vec<Feature> {
         vec![
-            // You can use your action to exhale destructive energy. Your draconic ancestry determines the size, shape, and damage type of the exhalation. When you use your breath weapon, each creature in the area of the exhalation must make a saving throw, the type of which is determined by your draconic ancestry. The DC for this saving throw equals 8 + your Constitution modifier + your proficiency bonus. A creature takes 2d6 damage on a failed save, and half as much damage on a successful one. The damage increases to 3d6 at 6th level, 4d6 at 11th level, and 5d6 at 16th level. After you use your breath weapon, you can't use it again until you complete a short or long rest.
             Feature {
                 title: "Breath Weapon",
+                description: "You can use your action to exhale destructive energy. Your draconic ancestry determines the size, shape, and damage type of the exhalation. When you use your breath weapon, each creature in the area of the exhalation must make a saving throw, the type of which is determined by your draconic ancestry. The DC for this saving throw equals 8 + your Constitution modifier + your proficiency bonus. A creature takes 2d6 damage on a failed save, and half as much damage on a successful one. The damage increases to 3d6 at 6th level, 4d6 at 11th level, and 5d6 at 16th level. After you use your breath weapon, you can't use it again until you complete a short or long rest.",
                 citation: Citation(Book::Phb, 34),
+                scaling: Some(format!(
+                    "{} {}",
+                    Self::breath_weapon_roll(level),
+                    self.damage_type()
+                )),
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![FeatureEffect::DamageResistance(self.damage_type())]
+    }
 }
 
 impl Languages for Dragonborn {
@@ -227,12 +249,6 @@ impl Race for Dragonborn {
     }
 }
 
-impl Resistances for Dragonborn {
-    fn resistances(&self) -> Vec<DamageType> {
-        vec![self.damage_type()]
-    }
-}
-
 impl Trinkets for Dragonborn {}
 
 impl fmt::Display for Dragonborn {
@@ -297,7 +313,25 @@ mod tests {
         let dragonborn = Dragonborn {
             ancestry: DraconicAncestry::Black,
         };
-        insta::assert_yaml_snapshot!(dragonborn.features());
+        insta::assert_yaml_snapshot!(dragonborn.features(1));
+    }
+
+    #[test]
+    fn test_breath_weapon_roll() {
+        assert_eq!(Dragonborn::breath_weapon_roll(1), RollCmd(2, Die::D6));
+        assert_eq!(Dragonborn::breath_weapon_roll(6), RollCmd(3, Die::D6));
+        assert_eq!(Dragonborn::breath_weapon_roll(11), RollCmd(4, Die::D6));
+        assert_eq!(Dragonborn::breath_weapon_roll(20), RollCmd(5, Die::D6));
+    }
+
+    #[test]
+    fn test_snapshot_attacks() {
+        let dragonborn = Dragonborn {
+            ancestry: DraconicAncestry::Green,
+        };
+        let ability_scores =
+            AbilityScores([(AbilityScoreType::Constitution, 14)].into_iter().collect());
+        insta::assert_yaml_snapshot!(dragonborn.attacks(6, &ability_scores, 3));
     }
 
     #[test]
@@ -350,9 +384,9 @@ mod tests {
     }
 
     #[test]
-    fn test_resistances() {
+    fn test_effects() {
         insta::assert_yaml_snapshot!(DraconicAncestry::iter()
-            .map(|ancestry| Dragonborn { ancestry }.resistances())
-            .collect::<Vec<Vec<DamageType>>>())
+            .map(|ancestry| Dragonborn { ancestry }.effects(1))
+            .collect::<Vec<Vec<FeatureEffect>>>())
     }
 }
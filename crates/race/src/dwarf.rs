@@ -2,7 +2,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attacks, DamageType};
 use backstory::Backstory;
 use characteristics::{
     names::{
@@ -14,7 +14,7 @@ use characteristics::{
 };
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, SaveAdvantage, Sense, Threat};
 use gear::{
     armor::ArmorType,
     tools::{ArtisansTools, Tool},
@@ -27,6 +27,7 @@ use rand::{
     Rng,
 };
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
@@ -34,7 +35,7 @@ use stats::{
 use strum::{Display, EnumIter, IntoEnumIterator};
 use trinkets::Trinkets;
 
-use super::Race;
+use super::{Race, Subrace};
 
 mod height_and_weight {
     use characteristics::{in_inches, HeightAndWeightTable, WeightMod};
@@ -247,6 +248,19 @@ pub struct Dwarf {
     subrace: DwarfSubrace,
 }
 
+impl Dwarf {
+    fn gen_with_subrace(rng: &mut impl Rng, subrace: DwarfSubrace) -> Self {
+        Self {
+            clan_status: subrace.clan_status(rng),
+            clan_trait: subrace.clan_trait(rng),
+            clan_vocation: DwarfSubrace::clan_vocation(rng),
+            quirk: subrace.quirk(rng),
+            story_hook: subrace.story_hook(rng),
+            subrace,
+        }
+    }
+}
+
 impl AlignmentInfluences for Dwarf {
     fn attitude(&self) -> Vec<Attitude> {
         vec![Attitude::Lawful]
@@ -262,6 +276,8 @@ impl AlignmentInfluences for Dwarf {
 
 impl Appearance for Dwarf {}
 
+impl Attacks for Dwarf {}
+
 impl Backstory for Dwarf {
     fn backstory(&self) -> Vec<String> {
         vec![
@@ -272,6 +288,14 @@ impl Backstory for Dwarf {
             format!("Reason for Adventuring: {}", self.story_hook),
         ]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.clan_status = self.subrace.clan_status(rng);
+        self.clan_trait = self.subrace.clan_trait(rng);
+        self.clan_vocation = DwarfSubrace::clan_vocation(rng);
+        self.quirk = self.subrace.quirk(rng);
+        self.story_hook = self.subrace.story_hook(rng);
+    }
 }
 
 impl Characteristics for Dwarf {
@@ -310,59 +334,87 @@ impl Citations for Dwarf {
 }
 
 impl Features for Dwarf {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, level: u8) -> Vec<Feature> {
         let mut features = vec![
-            // Accustomed to life underground, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "Accustomed to life underground, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Phb, 20),
+                scaling: None,
             },
-            // You have advantage on saving throws against poison, and you have resistance against poison damage (explained in the \"Combat\" section).
             Feature {
                 title: "Dwarven Resilience",
+                description: "You have advantage on saving throws against poison, and you have resistance against poison damage (explained in the \"Combat\" section).",
                 citation: Citation(Book::Phb, 20),
+                scaling: None,
             },
-            // Whenever you make an Intelligence (History) check related to the origin of stonework, you are considered proficient in the History skill and add double your proficiency bonus to the check, instead of your normal proficiency bonus.
             Feature {
                 title: "Stonecunning",
+                description: "Whenever you make an Intelligence (History) check related to the origin of stonework, you are considered proficient in the History skill and add double your proficiency bonus to the check, instead of your normal proficiency bonus.",
                 citation: Citation(Book::Phb, 20),
+                scaling: None,
             },
         ];
         if matches!(self.subrace, DwarfSubrace::Hill(_)) {
             features.push(
-                // Your hit point maximum increases by 1, and it increases by 1 every time you gain a level.
                 Feature {
                     title: "Dwarven Toughness",
+                    description: "Your hit point maximum increases by 1, and it increases by 1 every time you gain a level.",
                     citation: Citation(Book::Phb, 20),
+                    scaling: Some(format!("+{level} HP")),
                 },
             );
         }
         if matches!(self.subrace, DwarfSubrace::Duergar) {
             features.extend(vec![
-                // Your darkvision has a radius of 120 feet.
                 Feature {
                     title: "Superior Darkvision",
+                    description: "Your darkvision has a radius of 120 feet.",
                     citation: Citation(Book::Mtof, 81),
+                    scaling: None,
                 },
-                // You have advantage on saving throws against illusions and against being charmed or paralyzed.
                 Feature {
                     title: "Duergar Resiliance",
+                    description: "You have advantage on saving throws against illusions and against being charmed or paralyzed.",
                     citation: Citation(Book::Mtof, 81),
+                    scaling: None,
                 },
-                // When you reach 3rd level, you can cast the Enlarge/Reduce spell on yourself once with this trait, using only the spell's enlarge option. When you reach 5th level, you can cast the Invisibility spell on yourself once with this trait. You don't need material components for either spell, and you can't cast them while you're in direct sunlight, although sunlight has no effect on them once cast. You regain the ability to cast these spells with this trait when you finish a long rest. Intelligence is your spellcasting ability for these spells.
                 Feature {
                     title: "Duergar Magic",
+                    description: "When you reach 3rd level, you can cast the Enlarge/Reduce spell on yourself once with this trait, using only the spell's enlarge option. When you reach 5th level, you can cast the Invisibility spell on yourself once with this trait. You don't need material components for either spell, and you can't cast them while you're in direct sunlight, although sunlight has no effect on them once cast. You regain the ability to cast these spells with this trait when you finish a long rest. Intelligence is your spellcasting ability for these spells.",
                     citation: Citation(Book::Mtof, 81),
+                    scaling: None,
                 },
-                // You have disadvantage on Attack rolls and Wisdom (Perception) checks that rely on sight when you, the target of your attack, or whatever you are trying to perceive is in direct sunlight.
                 Feature {
                     title: "Sunlight Sensitivity",
+                    description: "You have disadvantage on Attack rolls and Wisdom (Perception) checks that rely on sight when you, the target of your attack, or whatever you are trying to perceive is in direct sunlight.",
                     citation: Citation(Book::Mtof, 81),
+                    scaling: None,
                 },
             ]);
         }
         features
     }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![
+            FeatureEffect::DamageResistance(DamageType::Poison),
+            FeatureEffect::SaveAdvantage(SaveAdvantage::against(Threat::Poison)),
+        ];
+        if let DwarfSubrace::Duergar = self.subrace {
+            effects.push(FeatureEffect::Sense(Sense::Darkvision, 120));
+            effects.extend(
+                [Threat::Illusions, Threat::Charmed, Threat::Paralyzed]
+                    .map(|t| FeatureEffect::SaveAdvantage(SaveAdvantage::against(t))),
+            );
+            if level >= 5 {
+                effects.push(FeatureEffect::Spell(Spell::Invisibility));
+            }
+        } else {
+            effects.push(FeatureEffect::Sense(Sense::Darkvision, 60));
+        }
+        effects
+    }
 }
 
 impl Languages for Dwarf {
@@ -446,14 +498,29 @@ impl Proficiencies for Dwarf {
 impl Race for Dwarf {
     fn gen(rng: &mut impl Rng) -> Self {
         let subrace = DwarfSubrace::gen(rng);
-        Self {
-            clan_status: subrace.clan_status(rng),
-            clan_trait: subrace.clan_trait(rng),
-            clan_vocation: DwarfSubrace::clan_vocation(rng),
-            quirk: subrace.quirk(rng),
-            story_hook: subrace.story_hook(rng),
-            subrace,
-        }
+        Self::gen_with_subrace(rng, subrace)
+    }
+
+    fn gen_subrace(rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        let subrace = match subrace {
+            Subrace::Duergar => DwarfSubrace::Duergar,
+            Subrace::Gold => DwarfSubrace::Hill(HillVariant::Gold),
+            Subrace::Hill => DwarfSubrace::Hill(HillVariant::Hill),
+            Subrace::Mountain => DwarfSubrace::Mountain(MountainVariant::Mountain),
+            Subrace::Shield => DwarfSubrace::Mountain(MountainVariant::Shield),
+            _ => return None,
+        };
+        Some(Self::gen_with_subrace(rng, subrace))
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        Some(match self.subrace {
+            DwarfSubrace::Duergar => Subrace::Duergar,
+            DwarfSubrace::Hill(HillVariant::Gold) => Subrace::Gold,
+            DwarfSubrace::Hill(HillVariant::Hill) => Subrace::Hill,
+            DwarfSubrace::Mountain(MountainVariant::Mountain) => Subrace::Mountain,
+            DwarfSubrace::Mountain(MountainVariant::Shield) => Subrace::Shield,
+        })
     }
 
     fn abilities(&self) -> Vec<AbilityScore> {
@@ -466,11 +533,19 @@ impl Race for Dwarf {
             },
         ]
     }
-}
 
-impl Resistances for Dwarf {
-    fn resistances(&self) -> Vec<DamageType> {
-        vec![DamageType::Poison]
+    /// Dwarven Toughness
+    fn hit_points_per_level(&self) -> i16 {
+        if matches!(self.subrace, DwarfSubrace::Hill(_)) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Your speed is not reduced by wearing heavy armor.
+    fn heavy_armor_reduces_speed(&self) -> bool {
+        false
     }
 }
 
@@ -528,6 +603,43 @@ mod tests {
             .collect::<Vec<Vec<AbilityScore>>>());
     }
 
+    #[test]
+    fn test_hit_points_per_level() {
+        assert_eq!(
+            DwarfSubrace::iter()
+                .map(|subrace| (Dwarf {
+                    subrace,
+                    clan_status: String::new(),
+                    clan_trait: String::new(),
+                    clan_vocation: String::new(),
+                    quirk: String::new(),
+                    story_hook: String::new(),
+                })
+                .hit_points_per_level())
+                .collect::<Vec<i16>>(),
+            vec![0, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_effects() {
+        assert_eq!(
+            DwarfSubrace::iter()
+                .map(|subrace| (Dwarf {
+                    subrace,
+                    clan_status: String::new(),
+                    clan_trait: String::new(),
+                    clan_vocation: String::new(),
+                    quirk: String::new(),
+                    story_hook: String::new(),
+                })
+                .effects(5)
+                .len())
+                .collect::<Vec<usize>>(),
+            vec![7, 3, 3]
+        );
+    }
+
     #[test]
     fn test_snapshot_citations() {
         insta::assert_yaml_snapshot!(DwarfSubrace::iter()
@@ -554,7 +666,7 @@ mod tests {
                 quirk: String::new(),
                 story_hook: String::new(),
             })
-            .features())
+            .features(1))
             .collect::<Vec<Vec<Feature>>>());
     }
 
@@ -572,4 +684,21 @@ mod tests {
             .addl_pantheons())
             .collect::<Vec<Vec<(Pantheon, PantheonWeight)>>>());
     }
+
+    #[test]
+    fn test_gen_subrace() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        for (subrace, name) in [
+            (Subrace::Duergar, "Duergar Dwarf"),
+            (Subrace::Gold, "Gold Dwarf"),
+            (Subrace::Hill, "Hill Dwarf"),
+            (Subrace::Mountain, "Mountain Dwarf"),
+            (Subrace::Shield, "Shield Dwarf"),
+        ] {
+            let dwarf = Dwarf::gen_subrace(&mut rng, subrace).unwrap();
+            assert_eq!(dwarf.to_string(), name);
+            assert_eq!(dwarf.subrace(), Some(subrace));
+        }
+        assert!(Dwarf::gen_subrace(&mut rng, Subrace::Wood).is_none());
+    }
 }
@@ -2,7 +2,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attacks, DamageType};
 use backstory::Backstory;
 use characteristics::{
     names::{
@@ -14,7 +14,7 @@ use characteristics::{
 };
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, SaveAdvantage, Sense, Threat};
 use gear::weapons::Weapon;
 use languages::{Language, LanguageType, Languages};
 use personality::PersonalityOptions;
@@ -23,6 +23,7 @@ use rand::{
     Rng,
 };
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType, Skill},
     proficiencies::{Proficiencies, Proficiency, WeaponProficiency},
@@ -30,7 +31,7 @@ use stats::{
 use strum::{Display, EnumIter, IntoEnumIterator};
 use trinkets::{TrinketOption, Trinkets};
 
-use super::Race;
+use super::{Race, Subrace};
 
 mod height_and_weight {
     use characteristics::{in_inches, HeightAndWeightTable, WeightMod};
@@ -296,6 +297,19 @@ pub struct Elf {
     subrace: ElfSubrace,
 }
 
+/// Spells cast with Drow Magic, which are gained at 1st, 3rd and 5th level
+pub(crate) fn drow_magic(level: u8) -> Vec<FeatureEffect> {
+    [
+        (1, Spell::DancingLights),
+        (3, Spell::FaerieFire),
+        (5, Spell::Darkness),
+    ]
+    .into_iter()
+    .filter(|&(gained, _)| gained <= level)
+    .map(|(_, spell)| FeatureEffect::Spell(spell))
+    .collect()
+}
+
 impl Elf {
     /// Before the age of 100, elves go by their child name
     pub(crate) fn gen_first_name<'a>(
@@ -364,6 +378,8 @@ impl AlignmentInfluences for Elf {
 
 impl Appearance for Elf {}
 
+impl Attacks for Elf {}
+
 impl Backstory for Elf {
     fn backstory(&self) -> Vec<String> {
         let mut backstory = vec![format!("Reason for Adventuring: {}", self.story_hook)];
@@ -372,6 +388,13 @@ impl Backstory for Elf {
         }
         backstory
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.story_hook = self.subrace.story_hook(rng);
+        if let ElfSubrace::Dark(specialty) = &mut self.subrace {
+            *specialty = DrowHouseSpecialty::iter().choose(rng).unwrap();
+        }
+    }
 }
 
 impl Characteristics for Elf {
@@ -426,87 +449,117 @@ impl Citations for Elf {
 }
 
 impl Features for Elf {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         let mut features = vec![
-            // Accustomed to twilit forests and the night sky, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "Accustomed to twilit forests and the night sky, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Phb, 23),
+                scaling: None,
             },
-            // You have advantage on saving throws against being charmed, and magic can't put you to sleep.
             Feature {
                 title: "Fey Ancestry",
+                description: "You have advantage on saving throws against being charmed, and magic can't put you to sleep.",
                 citation: Citation(Book::Phb, 23),
+                scaling: None,
             },
-            // Elves don't need to sleep. Instead, they meditate deeply, remaining semiconscious, for 4 hours a day. (The Common word for such meditation is \"trance.\") While meditating, you can dream after a fashion; such dreams are actually mental exercises that have become reflexive through years of practice. After resting in this way, you gain the same benefit that a human does from 8 hours of sleep.
             Feature {
                 title: "Trance",
+                description: "Elves don't need to sleep. Instead, they meditate deeply, remaining semiconscious, for 4 hours a day. (The Common word for such meditation is \"trance.\") While meditating, you can dream after a fashion; such dreams are actually mental exercises that have become reflexive through years of practice. After resting in this way, you gain the same benefit that a human does from 8 hours of sleep.",
                 citation: Citation(Book::Phb, 23),
+                scaling: None,
             },
         ];
         features.extend(match self.subrace {
             ElfSubrace::Dark(_) => vec![
-                // Your darkvision has a radius of 120 feet.
                 Feature {
                     title: "Superior Darkvision",
+                    description: "Your darkvision has a radius of 120 feet.",
                     citation: Citation(Book::Phb, 24),
+                    scaling: None,
                 },
-                // You have disadvantage on attack rolls and on Wisdom (Perception) checks that rely on sight when you, the target of your attack, or whatever you are trying to perceive is in direct sunlight.
                 Feature {
                     title: "Sunlight Sensitivity",
+                    description: "You have disadvantage on attack rolls and on Wisdom (Perception) checks that rely on sight when you, the target of your attack, or whatever you are trying to perceive is in direct sunlight.",
                     citation: Citation(Book::Phb, 24),
+                    scaling: None,
                 },
-                // You know the dancing lights cantrip. When you reach 3rd level, you can cast the faerie fire spell once with this trait and regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the darkness spell once with this trait and regain the ability to do so when you finish a long rest. Charisma is your spellcasting ability for these spells.
                 Feature {
                     title: "Drow Magic",
+                    description: "You know the dancing lights cantrip. When you reach 3rd level, you can cast the faerie fire spell once with this trait and regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the darkness spell once with this trait and regain the ability to do so when you finish a long rest. Charisma is your spellcasting ability for these spells.",
                     citation: Citation(Book::Phb, 24),
+                    scaling: None,
                 },
             ],
             ElfSubrace::Eladrin(_) => vec![
-                // As a bonus action, you can magically teleport up to 30 feet to an unoccupied space you can see. Once you use this trait, you can't do so again until you finish a short or long rest.
-                // When you reach 3rd level, your Fey Step gains an additional effect based on your season; if the effect requires a saving throw, the DC equals 8 + your proficiency bonus + your Charisma modifier:
-                // Autumn. Immediately after you use your Fey Step, up to two creatures of your choice that you can see within 10 feet of you must succeed on a Wisdom saving throw or be charmed by you for 1 minute, or until you or your companions deal any damage to it.
-                // Winter. When you use your Fey Step, one creature of your choice that you can see within 5 feet of you before you teleport must succeed on a Wisdom saving throw or be frightened of you until the end of your next turn.
-                // Spring. When you use your Fey Step, you can touch one willing creature within 5 feet of you. That creature then teleports instead of you, appearing in an unoccupied space of your choice that you can see within 30 feet of you.
-                // Summer. Immediately after you use your Fey Step, each creature of your choice that you can see within 5 feet of you takes fire damage equal to your Charisma modifier (minimum of 1 damage).
                 Feature {
                     title: "Fey Step",
+                    description: "As a bonus action, you can magically teleport up to 30 feet to an unoccupied space you can see. Once you use this trait, you can't do so again until you finish a short or long rest. When you reach 3rd level, your Fey Step gains an additional effect based on your season; if the effect requires a saving throw, the DC equals 8 + your proficiency bonus + your Charisma modifier: Autumn. Immediately after you use your Fey Step, up to two creatures of your choice that you can see within 10 feet of you must succeed on a Wisdom saving throw or be charmed by you for 1 minute, or until you or your companions deal any damage to it. Winter. When you use your Fey Step, one creature of your choice that you can see within 5 feet of you before you teleport must succeed on a Wisdom saving throw or be frightened of you until the end of your next turn. Spring. When you use your Fey Step, you can touch one willing creature within 5 feet of you. That creature then teleports instead of you, appearing in an unoccupied space of your choice that you can see within 30 feet of you. Summer. Immediately after you use your Fey Step, each creature of your choice that you can see within 5 feet of you takes fire damage equal to your Charisma modifier (minimum of 1 damage).",
                     citation: Citation(Book::Mtof, 62),
+                    scaling: None,
                 },
             ],
-            // You know one cantrip of your choice from the wizard spell list. Intelligence is your spellcasting ability for it.
             ElfSubrace::High(_) => vec![Feature {
                 title: "Cantrip",
+                description: "You know one cantrip of your choice from the wizard spell list. Intelligence is your spellcasting ability for it.",
                 citation: Citation(Book::Phb, 24),
+                scaling: None,
             }],
             ElfSubrace::Sea => vec![
-                // You have a swimming speed of 30 feet, and you can breath air and water.
                 Feature {
                     title: "Child of the Sea",
+                    description: "You have a swimming speed of 30 feet, and you can breath air and water.",
                     citation: Citation(Book::Mtof, 62),
+                    scaling: None,
                 },
-                // Using gestures and sounds, you can communicate simple ideas with any beast that has an innate swimming speed.
                 Feature {
                     title: "Friend of the Sea",
+                    description: "Using gestures and sounds, you can communicate simple ideas with any beast that has an innate swimming speed.",
                     citation: Citation(Book::Mtof, 62),
+                    scaling: None,
                 },
             ],
             ElfSubrace::ShadarKai => vec![
-                // As a bonus action, you can magically teleport up to 30 feet to an unoccupied space you can see. Once you use this trait, you can't do so again until you finish a long rest.
-                // Starting at 3rd level, you also gain resistance to all damage when you teleport using this trait. The resistance lasts until the start of your next turn. During that time, you appear ghostly and translucent.
                 Feature {
                     title: "Blessing of the Raven Queen",
+                    description: "As a bonus action, you can magically teleport up to 30 feet to an unoccupied space you can see. Once you use this trait, you can't do so again until you finish a long rest. Starting at 3rd level, you also gain resistance to all damage when you teleport using this trait. The resistance lasts until the start of your next turn. During that time, you appear ghostly and translucent.",
                     citation: Citation(Book::Mtof, 63),
+                    scaling: None,
                 },
             ],
-            // You can attempt to hide even when you are only lightly obscured by foliage, heavy rain, falling snow, mist, and other natural phenomena.
             ElfSubrace::Wood => vec![Feature {
                 title: "Mask of the Wild",
+                description: "You can attempt to hide even when you are only lightly obscured by foliage, heavy rain, falling snow, mist, and other natural phenomena.",
                 citation: Citation(Book::Phb, 24),
+                scaling: None,
             }],
         });
         features
     }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![FeatureEffect::SaveAdvantage(SaveAdvantage::against(
+            Threat::Charmed,
+        ))];
+        match self.subrace {
+            ElfSubrace::Dark(_) => {
+                effects.push(FeatureEffect::Sense(Sense::Darkvision, 120));
+                effects.extend(drow_magic(level));
+            }
+            ElfSubrace::Eladrin(_) => effects.extend([
+                FeatureEffect::Sense(Sense::Darkvision, 60),
+                FeatureEffect::Spell(Spell::MistyStep),
+            ]),
+            ElfSubrace::ShadarKai => effects.extend([
+                FeatureEffect::Sense(Sense::Darkvision, 60),
+                FeatureEffect::DamageResistance(DamageType::Necrotic),
+            ]),
+            ElfSubrace::High(_) | ElfSubrace::Sea | ElfSubrace::Wood => {
+                effects.push(FeatureEffect::Sense(Sense::Darkvision, 60));
+            }
+        }
+        effects
+    }
 }
 
 impl Languages for Elf {
@@ -576,6 +629,37 @@ impl Race for Elf {
         }
     }
 
+    fn gen_subrace(rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        let subrace = match subrace {
+            Subrace::Dark => ElfSubrace::Dark(DrowHouseSpecialty::iter().choose(rng).unwrap()),
+            Subrace::Eladrin => ElfSubrace::Eladrin(EladrinVariant::iter().choose(rng).unwrap()),
+            Subrace::High => ElfSubrace::High(HighVariant::High),
+            Subrace::Moon => ElfSubrace::High(HighVariant::Moon),
+            Subrace::Sun => ElfSubrace::High(HighVariant::Sun),
+            Subrace::Sea => ElfSubrace::Sea,
+            Subrace::ShadarKai => ElfSubrace::ShadarKai,
+            Subrace::Wood => ElfSubrace::Wood,
+            _ => return None,
+        };
+        Some(Self {
+            story_hook: subrace.story_hook(rng),
+            subrace,
+        })
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        Some(match self.subrace {
+            ElfSubrace::Dark(_) => Subrace::Dark,
+            ElfSubrace::Eladrin(_) => Subrace::Eladrin,
+            ElfSubrace::High(HighVariant::High) => Subrace::High,
+            ElfSubrace::High(HighVariant::Moon) => Subrace::Moon,
+            ElfSubrace::High(HighVariant::Sun) => Subrace::Sun,
+            ElfSubrace::Sea => Subrace::Sea,
+            ElfSubrace::ShadarKai => Subrace::ShadarKai,
+            ElfSubrace::Wood => Subrace::Wood,
+        })
+    }
+
     fn abilities(&self) -> Vec<AbilityScore> {
         vec![
             AbilityScore(AbilityScoreType::Dexterity, 2),
@@ -593,19 +677,6 @@ impl Race for Elf {
     }
 }
 
-impl Resistances for Elf {
-    fn resistances(&self) -> Vec<DamageType> {
-        match self.subrace {
-            ElfSubrace::ShadarKai => vec![DamageType::Necrotic],
-            ElfSubrace::Dark(_)
-            | ElfSubrace::Eladrin(_)
-            | ElfSubrace::High(_)
-            | ElfSubrace::Sea
-            | ElfSubrace::Wood => vec![],
-        }
-    }
-}
-
 impl Trinkets for Elf {
     fn trinket_options(&self) -> Vec<TrinketOption> {
         vec![TrinketOption::Elven]
@@ -674,7 +745,7 @@ mod tests {
                 story_hook: String::new(),
                 subrace
             })
-            .features())
+            .features(1))
             .collect::<Vec<Vec<Feature>>>());
     }
 
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     in_inches, names::Name, AgeRange, Appearance, CharacteristicDetails, Characteristics,
@@ -10,11 +10,12 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::Proficiencies,
@@ -58,6 +59,8 @@ impl AlignmentInfluences for Firbolg {
 
 impl Appearance for Firbolg {}
 
+impl Attacks for Firbolg {}
+
 impl Backstory for Firbolg {
     fn backstory(&self) -> Vec<String> {
         vec![format!(
@@ -65,6 +68,10 @@ impl Backstory for Firbolg {
             self.reason_for_adventuring
         )]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.reason_for_adventuring = (*REASON_FOR_ADVENTURING.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Firbolg {
@@ -92,30 +99,42 @@ impl Citations for Firbolg {
 }
 
 impl Features for Firbolg {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can cast Detect Magic and Disguise Self with this trait, using Wisdom as your spellcasting ability for them. Once you cast either spell, you can't cast it again with this trait until you finish a short or long rest. When you use this version of disguise self, you can seem up to 3 feet shorter than normal, allowing you to more easily blend in with humans and elves.
             Feature {
                 title: "Firbolg Magic",
+                description: "You can cast Detect Magic and Disguise Self with this trait, using Wisdom as your spellcasting ability for them. Once you cast either spell, you can't cast it again with this trait until you finish a short or long rest. When you use this version of disguise self, you can seem up to 3 feet shorter than normal, allowing you to more easily blend in with humans and elves.",
                 citation: Citation(Book::Vgtm, 107),
+                scaling: None,
             },
-            //  As a bonus action, you can magically turn invisible until the start of your next turn or until you attack, make a damage roll, or force someone to make a saving throw. Once you use this trait, you can't use it again until you finish a short or long rest.
             Feature {
                 title: "Hidden Step",
+                description: "As a bonus action, you can magically turn invisible until the start of your next turn or until you attack, make a damage roll, or force someone to make a saving throw. Once you use this trait, you can't use it again until you finish a short or long rest.",
                 citation: Citation(Book::Vgtm, 107),
+                scaling: None,
             },
-            // You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.
             Feature {
                 title: "Powerful Build",
+                description: "You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.",
                 citation: Citation(Book::Vgtm, 107),
+                scaling: None,
             },
-            // You have the ability to communicate in a limited manner with beasts and plants. They can understand the meaning of your words, though you have no special ability to understand them in return. You have advantage on all Charisma checks you make to influence them.
             Feature {
                 title: "Speech of Beast and Leaf",
+                description: "You have the ability to communicate in a limited manner with beasts and plants. They can understand the meaning of your words, though you have no special ability to understand them in return. You have advantage on all Charisma checks you make to influence them.",
                 citation: Citation(Book::Vgtm, 107),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![
+            FeatureEffect::PowerfulBuild,
+            FeatureEffect::Spell(Spell::DetectMagic),
+            FeatureEffect::Spell(Spell::DisguiseSelf),
+        ]
+    }
 }
 
 impl Languages for Firbolg {
@@ -159,8 +178,6 @@ impl Race for Firbolg {
     }
 }
 
-impl Resistances for Firbolg {}
-
 impl Trinkets for Firbolg {}
 
 impl fmt::Display for Firbolg {
@@ -235,7 +252,7 @@ mod tests {
         let firbolg = Firbolg {
             reason_for_adventuring: String::new(),
         };
-        insta::assert_yaml_snapshot!(firbolg.features());
+        insta::assert_yaml_snapshot!(firbolg.features(1));
     }
 
     #[test]
@@ -2,7 +2,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     names::{
@@ -14,7 +14,7 @@ use characteristics::{
 };
 use citation::{Book, Citation, CitationList, Citations};
 use deities::Pantheons;
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, SaveAdvantage, Threat};
 use gear::{armor::ArmorType, weapons::Weapon};
 use languages::{Language, LanguageType, Languages};
 use personality::{Influence, PersonalityOptions};
@@ -23,6 +23,7 @@ use rand::{
     Rng,
 };
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::{Proficiencies, Proficiency, ProficiencyOption, WeaponProficiency},
@@ -30,7 +31,7 @@ use stats::{
 use strum::{Display, EnumIter, IntoEnumIterator};
 use trinkets::Trinkets;
 
-use super::Race;
+use super::{Race, Subrace};
 
 mod height_and_weight {
     use characteristics::{in_inches, HeightAndWeightTable, WeightMod};
@@ -165,6 +166,8 @@ impl AlignmentInfluences for Gith {
 
 impl Appearance for Gith {}
 
+impl Attacks for Gith {}
+
 impl Backstory for Gith {}
 
 impl Characteristics for Gith {
@@ -195,34 +198,60 @@ impl Citations for Gith {
 }
 
 impl Features for Gith {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         match self.subrace {
             GithSubrace::Githyanki => vec![
-                // You know the mage hand cantrip, and the hand is invisible when you cast the cantrip with this trait.
-                // When you reach 3rd level, you can cast the jump spell once with this trait, and you regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the misty step spell once with this trait, and you regain the ability to do so when you finish a long rest.
-                // Intelligence is your spellcasting ability for these spells. When you cast them with this trait, they don't require components.
                 Feature {
                     title: "Githyanki Psionics",
+                    description: "You know the mage hand cantrip, and the hand is invisible when you cast the cantrip with this trait. When you reach 3rd level, you can cast the jump spell once with this trait, and you regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the misty step spell once with this trait, and you regain the ability to do so when you finish a long rest. Intelligence is your spellcasting ability for these spells. When you cast them with this trait, they don't require components.",
                     citation: Citation(Book::Mtof, 96),
+                    scaling: None,
                 },
             ],
 
             GithSubrace::Githzerai => vec![
-                // You have advantage on saving throws against the charmed and frightened conditions. Under the tutelage of monastic masters, githzerai learn to govern their own minds.
                 Feature {
                     title: "Mental Discipline",
+                    description: "You have advantage on saving throws against the charmed and frightened conditions. Under the tutelage of monastic masters, githzerai learn to govern their own minds.",
                     citation: Citation(Book::Mtof, 96),
+                    scaling: None,
                 },
-                // You know the mage hand cantrip, and the hand is invisible when you cast the cantrip with this trait.
-                // When you reach 3rd level, you can cast the shield spell once with this trait, and you regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the detect thoughts spell once with this trait, and you regain the ability to do so when you finish a long rest.
-                // Wisdom is your spellcasting ability for these spells. When you cast them with this trait, they don't require components.
                 Feature {
                     title: "Githzerai Psionics",
+                    description: "You know the mage hand cantrip, and the hand is invisible when you cast the cantrip with this trait. When you reach 3rd level, you can cast the shield spell once with this trait, and you regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the detect thoughts spell once with this trait, and you regain the ability to do so when you finish a long rest. Wisdom is your spellcasting ability for these spells. When you cast them with this trait, they don't require components.",
                     citation: Citation(Book::Mtof, 96),
+                    scaling: None,
                 },
             ],
         }
     }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let (mut effects, spells) = match self.subrace {
+            GithSubrace::Githyanki => (
+                vec![],
+                vec![
+                    (1, Spell::MageHand),
+                    (3, Spell::Jump),
+                    (5, Spell::MistyStep),
+                ],
+            ),
+            GithSubrace::Githzerai => (
+                vec![
+                    FeatureEffect::SaveAdvantage(SaveAdvantage::against(Threat::Charmed)),
+                    FeatureEffect::SaveAdvantage(SaveAdvantage::against(Threat::Frightened)),
+                ],
+                vec![(1, Spell::MageHand), (3, Spell::Shield)],
+            ),
+        };
+        effects.extend(
+            spells
+                .into_iter()
+                .filter(|&(gained, _)| gained <= level)
+                .map(|(_, spell)| FeatureEffect::Spell(spell)),
+        );
+        effects
+    }
 }
 
 impl Languages for Gith {
@@ -312,6 +341,22 @@ impl Race for Gith {
         }
     }
 
+    fn gen_subrace(_rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        let subrace = match subrace {
+            Subrace::Githyanki => GithSubrace::Githyanki,
+            Subrace::Githzerai => GithSubrace::Githzerai,
+            _ => return None,
+        };
+        Some(Self { subrace })
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        Some(match self.subrace {
+            GithSubrace::Githyanki => Subrace::Githyanki,
+            GithSubrace::Githzerai => Subrace::Githzerai,
+        })
+    }
+
     fn abilities(&self) -> Vec<AbilityScore> {
         vec![
             AbilityScore(AbilityScoreType::Intelligence, 1),
@@ -323,8 +368,6 @@ impl Race for Gith {
     }
 }
 
-impl Resistances for Gith {}
-
 impl Trinkets for Gith {}
 
 impl fmt::Display for Gith {
@@ -371,7 +414,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         insta::assert_yaml_snapshot!(GithSubrace::iter()
-            .map(|subrace| (Gith { subrace }).features())
+            .map(|subrace| (Gith { subrace }).features(1))
             .collect::<Vec<Vec<Feature>>>());
     }
 }
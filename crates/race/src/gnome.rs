@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -15,7 +15,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, SaveAdvantage, Sense, Threat};
 use gear::tools::{ArtisansTools, Tool};
 use languages::{Language, Languages};
 use personality::{Influence, PersonalityOptions};
@@ -24,6 +24,7 @@ use rand::{
     Rng,
 };
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::{Proficiencies, Proficiency},
@@ -31,7 +32,7 @@ use stats::{
 use strum::{Display, EnumIter, IntoEnumIterator};
 use trinkets::Trinkets;
 
-use super::Race;
+use super::{Race, Subrace};
 
 const BONDS: &[&str] = &[
     "You pledge to bring something of immense value back to your burrow.",
@@ -114,6 +115,8 @@ impl AlignmentInfluences for Gnome {
 
 impl Appearance for Gnome {}
 
+impl Attacks for Gnome {}
+
 impl Backstory for Gnome {}
 
 impl Characteristics for Gnome {
@@ -152,64 +155,87 @@ impl Citations for Gnome {
 }
 
 impl Features for Gnome {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         let mut features = vec![
-            // Accustomed to life underground, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "Accustomed to life underground, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Phb, 37),
+                scaling: None,
             },
-            // You have advantage on all Intelligence, Wisdom, and Charisma saving throws against magic.
             Feature {
                 title: "Gnome Cunning",
+                description: "You have advantage on all Intelligence, Wisdom, and Charisma saving throws against magic.",
                 citation: Citation(Book::Phb, 37),
+                scaling: None,
             },
         ];
         features.extend(match self.subrace {
             GnomeSubrace::Forest => vec![
-                // You know the minor illusion cantrip. Intelligence is your spellcasting ability for it.
                 Feature {
                     title: "Natural Illusionist",
+                    description: "You know the minor illusion cantrip. Intelligence is your spellcasting ability for it.",
                     citation: Citation(Book::Phb, 37),
+                    scaling: None,
                 },
-                // Through sounds and gestures, you can communicate simple ideas with Small or smaller beasts. Forest gnomes love animals and often keep squirrels, badgers, rabbits, moles, woodpeckers, and other creatures as beloved pets.
                 Feature {
                     title: "Speak with Small Beasts",
+                    description: "Through sounds and gestures, you can communicate simple ideas with Small or smaller beasts. Forest gnomes love animals and often keep squirrels, badgers, rabbits, moles, woodpeckers, and other creatures as beloved pets.",
                     citation: Citation(Book::Phb, 37),
+                    scaling: None,
                 },
             ],
             GnomeSubrace::Rock => vec![
                 Feature {
-                    // Whenever you make an Intelligence (History) check related to magic items, alchemical objects, or technological devices, you can add twice your proficiency bonus, instead of any proficiency bonus you normally apply.
                     title: "Artificer's Lore",
+                    description: "Whenever you make an Intelligence (History) check related to magic items, alchemical objects, or technological devices, you can add twice your proficiency bonus, instead of any proficiency bonus you normally apply.",
                     citation: Citation(Book::Phb, 37),
+                    scaling: None,
                 },
-                // You have proficiency with artisan's tools (tinker's tools). Using those tools, you can spend 1 hour and 10 gp worth of materials to construct a Tiny clockwork device (AC 5, 1 hp). The device ceases to function after 24 hours (unless you spend 1 hour repairing it to keep the device functioning), or when you use your action to dismantle it; at that time, you can reclaim the materials used to create it. You can have up to three such devices active at a time.
-                // When you create a device, choose one of the following options:
-                // Clockwork Toy. This toy is a clockwork animal, monster, or person, such as a frog, mouse, bird, dragon, or soldier. When placed on the ground, the toy moves 5 feet across the ground on each of your turns in a random direction. It makes noises as appropriate to the creature it represents.
-                // Fire Starter. The device produces a miniature flame, which you can use to light a candle, torch, or campfire. Using the device requires your action.
-                // Music Box. When opened, this music box plays a single song at a moderate volume.
-                // The box stops playing when it reaches the song's end or when it is closed.
                 Feature {
                     title: "Tinker",
+                    description: "You have proficiency with artisan's tools (tinker's tools). Using those tools, you can spend 1 hour and 10 gp worth of materials to construct a Tiny clockwork device (AC 5, 1 hp). The device ceases to function after 24 hours (unless you spend 1 hour repairing it to keep the device functioning), or when you use your action to dismantle it; at that time, you can reclaim the materials used to create it. You can have up to three such devices active at a time. When you create a device, choose one of the following options: Clockwork Toy. This toy is a clockwork animal, monster, or person, such as a frog, mouse, bird, dragon, or soldier. When placed on the ground, the toy moves 5 feet across the ground on each of your turns in a random direction. It makes noises as appropriate to the creature it represents. Fire Starter. The device produces a miniature flame, which you can use to light a candle, torch, or campfire. Using the device requires your action. Music Box. When opened, this music box plays a single song at a moderate volume. The box stops playing when it reaches the song's end or when it is closed.",
                     citation: Citation(Book::Phb, 37),
+                    scaling: None,
                 },
             ],
             GnomeSubrace::Svirfneblin => vec![
-                // Your Darkvision has a radius of 120 ft
                 Feature {
                     title: "Superior Darkvision",
+                    description: "Your Darkvision has a radius of 120 ft",
                     citation: Citation(Book::Mtof, 114),
+                    scaling: None,
                 },
-                // You have advantage on Dexterity (Stealth) checks to hide in rocky terrain.
                 Feature {
                     title: "Stone Camouflage",
+                    description: "You have advantage on Dexterity (Stealth) checks to hide in rocky terrain.",
                     citation: Citation(Book::Mtof, 114),
+                    scaling: None,
                 },
             ],
         });
         features
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![FeatureEffect::SaveAdvantage(SaveAdvantage {
+            abilities: Some(vec![
+                AbilityScoreType::Intelligence,
+                AbilityScoreType::Wisdom,
+                AbilityScoreType::Charisma,
+            ]),
+            against: Threat::Magic,
+        })];
+        effects.extend(match self.subrace {
+            GnomeSubrace::Forest => vec![
+                FeatureEffect::Sense(Sense::Darkvision, 60),
+                FeatureEffect::Spell(Spell::MinorIllusion),
+            ],
+            GnomeSubrace::Rock => vec![FeatureEffect::Sense(Sense::Darkvision, 60)],
+            GnomeSubrace::Svirfneblin => vec![FeatureEffect::Sense(Sense::Darkvision, 120)],
+        });
+        effects
+    }
 }
 
 impl Languages for Gnome {
@@ -284,6 +310,24 @@ impl Race for Gnome {
         }
     }
 
+    fn gen_subrace(_rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        let subrace = match subrace {
+            Subrace::Forest => GnomeSubrace::Forest,
+            Subrace::Rock => GnomeSubrace::Rock,
+            Subrace::Svirfneblin => GnomeSubrace::Svirfneblin,
+            _ => return None,
+        };
+        Some(Self { subrace })
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        Some(match self.subrace {
+            GnomeSubrace::Forest => Subrace::Forest,
+            GnomeSubrace::Rock => Subrace::Rock,
+            GnomeSubrace::Svirfneblin => Subrace::Svirfneblin,
+        })
+    }
+
     fn abilities(&self) -> Vec<AbilityScore> {
         vec![
             AbilityScore(AbilityScoreType::Intelligence, 2),
@@ -297,8 +341,6 @@ impl Race for Gnome {
     }
 }
 
-impl Resistances for Gnome {}
-
 impl Trinkets for Gnome {}
 
 impl fmt::Display for Gnome {
@@ -345,7 +387,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         insta::assert_yaml_snapshot!(GnomeSubrace::iter()
-            .map(|subrace| (Gnome { subrace }).features())
+            .map(|subrace| (Gnome { subrace }).features(1))
             .collect::<Vec<Vec<Feature>>>());
     }
 
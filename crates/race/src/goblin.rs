@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::{Backstory, MONSTROUS_ORIGIN};
 use characteristics::{
     in_inches,
@@ -12,7 +12,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{prelude::SliceRandom, Rng};
@@ -49,10 +49,16 @@ impl AlignmentInfluences for Goblin {
 
 impl Appearance for Goblin {}
 
+impl Attacks for Goblin {}
+
 impl Backstory for Goblin {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Origin: {}", self.origin)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.origin = (*MONSTROUS_ORIGIN.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Goblin {
@@ -80,25 +86,32 @@ impl Citations for Goblin {
 }
 
 impl Features for Goblin {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, level: u8) -> Vec<Feature> {
         vec![
-            // You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // When you damage a creature with an attack or a spell and the creature’s size is larger than yours, you can cause the attack or spell to deal extra damage to the creature. The extra damage equals your level. Once you use this trait, you can’t use it again until you finish a short or long rest.
             Feature {
                 title: "Fury of the Small",
+                description: "When you damage a creature with an attack or a spell and the creature’s size is larger than yours, you can cause the attack or spell to deal extra damage to the creature. The extra damage equals your level. Once you use this trait, you can’t use it again until you finish a short or long rest.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: Some(format!("{level} extra damage")),
             },
-            // You can take the Disengage or Hide action as a bonus action on each of your turns.
             Feature {
                 title: "Nimble Escape",
+                description: "You can take the Disengage or Hide action as a bonus action on each of your turns.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![FeatureEffect::Sense(Sense::Darkvision, 60)]
+    }
 }
 
 impl Languages for Goblin {
@@ -138,8 +151,6 @@ impl Race for Goblin {
     }
 }
 
-impl Resistances for Goblin {}
-
 impl Trinkets for Goblin {
     fn trinket_options(&self) -> Vec<TrinketOption> {
         vec![TrinketOption::Goblin]
@@ -216,7 +227,7 @@ mod tests {
         let goblin = Goblin {
             origin: String::new(),
         };
-        insta::assert_yaml_snapshot!(goblin.features());
+        insta::assert_yaml_snapshot!(goblin.features(1));
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -15,7 +15,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{prelude::SliceRandom, Rng};
@@ -50,6 +50,8 @@ impl AlignmentInfluences for Goliath {
 
 impl Appearance for Goliath {}
 
+impl Attacks for Goliath {}
+
 impl Backstory for Goliath {}
 
 impl Characteristics for Goliath {
@@ -77,25 +79,32 @@ impl Citations for Goliath {
 }
 
 impl Features for Goliath {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can focus yourself to occasionally shrug off injury. When you take damage, you can use your reaction to roll a d12. Add your Constitution modifier to the number rolled, and reduce the damage by that total. After you use this trait, you can’t use it again until you finish a short or long rest.
             Feature {
                 title: "Stone's Endurance",
+                description: "You can focus yourself to occasionally shrug off injury. When you take damage, you can use your reaction to roll a d12. Add your Constitution modifier to the number rolled, and reduce the damage by that total. After you use this trait, you can’t use it again until you finish a short or long rest.",
                 citation: Citation(Book::Vgtm, 109),
+                scaling: None,
             },
-            // You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.
             Feature {
                 title: "Powerful Build",
+                description: "You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.",
                 citation: Citation(Book::Vgtm, 109),
+                scaling: None,
             },
-            // You are acclimated to high altitude, including elevations above 20,000 feet. You're also naturally adapted to cold climates, as described in chapter 5 of the Dungeon Master's Guide.
             Feature {
                 title: "Mountain Born",
+                description: "You are acclimated to high altitude, including elevations above 20,000 feet. You're also naturally adapted to cold climates, as described in chapter 5 of the Dungeon Master's Guide.",
                 citation: Citation(Book::Vgtm, 109),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![FeatureEffect::PowerfulBuild]
+    }
 }
 
 impl Languages for Goliath {
@@ -142,8 +151,6 @@ impl Race for Goliath {
     }
 }
 
-impl Resistances for Goliath {}
-
 impl Trinkets for Goliath {}
 
 impl fmt::Display for Goliath {
@@ -200,7 +207,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         let goliath = Goliath;
-        insta::assert_yaml_snapshot!(goliath.features());
+        insta::assert_yaml_snapshot!(goliath.features(1));
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     in_inches, names::Name, AgeRange, Appearance, CharacteristicDetails, Characteristics,
@@ -10,7 +10,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, SaveAdvantage, Sense, Threat};
 use languages::{Language, LanguageType, Languages};
 use personality::{Influence, PersonalityOptions};
 use rand::{
@@ -26,7 +26,7 @@ use strum::IntoEnumIterator;
 use trinkets::{TrinketOption, Trinkets};
 
 use super::{
-    elf::{Elf, ElfSubrace},
+    elf::{drow_magic, Elf, ElfSubrace},
     human::Human,
     Race,
 };
@@ -106,6 +106,8 @@ impl AlignmentInfluences for HalfElf {
 
 impl Appearance for HalfElf {}
 
+impl Attacks for HalfElf {}
+
 impl Backstory for HalfElf {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Elven ancestry: {} Elf", self.subrace)]
@@ -153,45 +155,61 @@ impl Citations for HalfElf {
 }
 
 impl Features for HalfElf {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         let mut features = vec![
-            // Thanks to your elf blood, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "Thanks to your elf blood, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Phb, 39),
+                scaling: None,
             },
-            // You have advantage on saving throws against being charmed, and magic can't put you to sleep.
             Feature {
                 title: "Fey Ancestry",
+                description: "You have advantage on saving throws against being charmed, and magic can't put you to sleep.",
                 citation: Citation(Book::Phb, 39),
+                scaling: None,
             },
         ];
         features.extend(match self.variant {
             Variant::DrowMagic => vec![
-                // You know the dancing lights cantrip. When you reach 3rd level, you can cast the faerie fire spell once with this trait and regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the darkness spell once with this trait and regain the ability to do so when you finish a long rest. Charisma is your spellcasting ability for these spells.
                 Feature {
                     title: "Drow Magic",
+                    description: "You know the dancing lights cantrip. When you reach 3rd level, you can cast the faerie fire spell once with this trait and regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the darkness spell once with this trait and regain the ability to do so when you finish a long rest. Charisma is your spellcasting ability for these spells.",
                     citation: Citation(Book::Phb, 24),
+                    scaling: None,
                 },
             ],
             Variant::MaskOfTheWild => vec![
-                // You can attempt to hide even when you are only lightly obscured by foliage, heavy rain, falling snow, mist, and other natural phenomena.
                 Feature {
                     title: "Mask of the Wild",
+                    description: "You can attempt to hide even when you are only lightly obscured by foliage, heavy rain, falling snow, mist, and other natural phenomena.",
                     citation: Citation(Book::Phb, 24),
+                    scaling: None,
                 },
             ],
             Variant::Cantrip => vec![
-                // You know one cantrip of your choice from the wizard spell list. Intelligence is your spellcasting ability for it.
                 Feature {
                     title: "Cantrip",
+                    description: "You know one cantrip of your choice from the wizard spell list. Intelligence is your spellcasting ability for it.",
                     citation: Citation(Book::Phb, 24),
+                    scaling: None,
                 },
             ],
             _ => vec![],
         });
         features
     }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![
+            FeatureEffect::Sense(Sense::Darkvision, 60),
+            FeatureEffect::SaveAdvantage(SaveAdvantage::against(Threat::Charmed)),
+        ];
+        if let Variant::DrowMagic = self.variant {
+            effects.extend(drow_magic(level));
+        }
+        effects
+    }
 }
 
 impl Languages for HalfElf {
@@ -282,8 +300,6 @@ impl Race for HalfElf {
     }
 }
 
-impl Resistances for HalfElf {}
-
 impl Trinkets for HalfElf {
     fn trinket_options(&self) -> Vec<trinkets::TrinketOption> {
         vec![TrinketOption::Elven]
@@ -334,7 +350,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let half_elf = HalfElf::gen(&mut rng);
-        insta::assert_yaml_snapshot!(half_elf.features());
+        insta::assert_yaml_snapshot!(half_elf.features(1));
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     in_inches, names::Name, AgeRange, Appearance, CharacteristicDetails, Characteristics,
@@ -10,7 +10,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, Languages};
 use personality::{Influence, PersonalityOptions};
 use rand::{prelude::SliceRandom, Rng};
@@ -49,6 +49,8 @@ impl AlignmentInfluences for HalfOrc {
 
 impl Appearance for HalfOrc {}
 
+impl Attacks for HalfOrc {}
+
 impl Backstory for HalfOrc {}
 
 impl Characteristics for HalfOrc {
@@ -80,25 +82,32 @@ impl Citations for HalfOrc {
 }
 
 impl Features for HalfOrc {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // Thanks to your orc blood, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "Thanks to your orc blood, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Phb, 41),
+                scaling: None,
             },
-            // When you are reduced to 0 hit points but not killed outright, you can drop to 1 hit point instead. You can't use this feature again until you finish a long rest.
             Feature {
                 title: "Relentless Endurance",
+                description: "When you are reduced to 0 hit points but not killed outright, you can drop to 1 hit point instead. You can't use this feature again until you finish a long rest.",
                 citation: Citation(Book::Phb, 41),
+                scaling: None,
             },
-            // When you score a critical hit with a melee weapon attack, you can roll one of the weapon's damage dice one additional time and add it to the extra damage of the critical hit.
             Feature {
                 title: "Savage Attacks",
+                description: "When you score a critical hit with a melee weapon attack, you can roll one of the weapon's damage dice one additional time and add it to the extra damage of the critical hit.",
                 citation: Citation(Book::Phb, 41),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![FeatureEffect::Sense(Sense::Darkvision, 60)]
+    }
 }
 
 impl Languages for HalfOrc {
@@ -169,8 +178,6 @@ impl Race for HalfOrc {
     }
 }
 
-impl Resistances for HalfOrc {}
-
 impl Trinkets for HalfOrc {}
 
 impl fmt::Display for HalfOrc {
@@ -214,7 +221,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         let half_orc = HalfOrc;
-        insta::assert_yaml_snapshot!(half_orc.features());
+        insta::assert_yaml_snapshot!(half_orc.features(1));
     }
 
     #[test]
@@ -2,7 +2,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attacks, DamageType};
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -16,7 +16,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, SaveAdvantage, Threat};
 use languages::{Language, Languages};
 use personality::{Influence, PersonalityOptions};
 use rand::{
@@ -31,7 +31,7 @@ use stats::{
 use strum::{Display, EnumIter, IntoEnumIterator};
 use trinkets::Trinkets;
 
-use super::Race;
+use super::{Race, Subrace};
 
 const BONDS: &[&str] = &[
     "The safety of your village is worth any sacrifice.",
@@ -145,6 +145,8 @@ impl AlignmentInfluences for Halfling {
 
 impl Appearance for Halfling {}
 
+impl Attacks for Halfling {}
+
 impl Backstory for Halfling {
     fn backstory(&self) -> Vec<String> {
         vec![format!(
@@ -152,6 +154,10 @@ impl Backstory for Halfling {
             self.reason_for_adventuring
         )]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.reason_for_adventuring = (*REASON_FOR_ADVENTURING.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Halfling {
@@ -187,38 +193,56 @@ impl Citations for Halfling {
 }
 
 impl Features for Halfling {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         let mut features = vec![
-            // You have advantage on saving throws against being frightened.
             Feature {
                 title: "Brave",
+                description: "You have advantage on saving throws against being frightened.",
                 citation: Citation(Book::Phb, 28),
+                scaling: None,
             },
-            // You can move through the space of any creature that is of a size larger than yours.
             Feature {
                 title: "Halfling Nimbleness",
+                description: "You can move through the space of any creature that is of a size larger than yours.",
                 citation: Citation(Book::Phb, 28),
+                scaling: None,
             },
         ];
         features.push(match self.subrace {
-            // You can speak telepathically to any creature within 30 feet of you. The creature understands you only if the two of you share a language. You can speak telepathically in this way to one creature at a time.
             HalflingSubrace::Ghostwise => Feature {
                 title: "Silent Speech",
+                description: "You can speak telepathically to any creature within 30 feet of you. The creature understands you only if the two of you share a language. You can speak telepathically in this way to one creature at a time.",
                 citation: Citation(Book::Scag, 110),
+                scaling: None,
             },
-            // You can attempt to hide even when you are obscured only by a creature that is at least one size larger than you.
             HalflingSubrace::Lightfoot => Feature {
                 title: "Naturally Stealthy",
+                description: "You can attempt to hide even when you are obscured only by a creature that is at least one size larger than you.",
                 citation: Citation(Book::Phb, 28),
+                scaling: None,
             },
-            // You have advantage on saving throws against poison, and you have resistance against poison damage.
             HalflingSubrace::Stout(_) => Feature {
                 title: "Stout Resilience",
+                description: "You have advantage on saving throws against poison, and you have resistance against poison damage.",
                 citation: Citation(Book::Phb, 28),
+                scaling: None,
             },
         });
         features
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![FeatureEffect::SaveAdvantage(SaveAdvantage::against(
+            Threat::Frightened,
+        ))];
+        if let HalflingSubrace::Stout(_) = self.subrace {
+            effects.extend([
+                FeatureEffect::DamageResistance(DamageType::Poison),
+                FeatureEffect::SaveAdvantage(SaveAdvantage::against(Threat::Poison)),
+            ]);
+        }
+        effects
+    }
 }
 
 impl Languages for Halfling {
@@ -279,6 +303,29 @@ impl Race for Halfling {
         }
     }
 
+    fn gen_subrace(rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        let subrace = match subrace {
+            Subrace::Ghostwise => HalflingSubrace::Ghostwise,
+            Subrace::Lightfoot => HalflingSubrace::Lightfoot,
+            Subrace::Stout => HalflingSubrace::Stout(StoutVariant::Stout),
+            Subrace::Strongheart => HalflingSubrace::Stout(StoutVariant::Strongheart),
+            _ => return None,
+        };
+        Some(Self {
+            reason_for_adventuring: (*REASON_FOR_ADVENTURING.choose(rng).unwrap()).to_string(),
+            subrace,
+        })
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        Some(match self.subrace {
+            HalflingSubrace::Ghostwise => Subrace::Ghostwise,
+            HalflingSubrace::Lightfoot => Subrace::Lightfoot,
+            HalflingSubrace::Stout(StoutVariant::Stout) => Subrace::Stout,
+            HalflingSubrace::Stout(StoutVariant::Strongheart) => Subrace::Strongheart,
+        })
+    }
+
     fn abilities(&self) -> Vec<AbilityScore> {
         vec![
             AbilityScore(AbilityScoreType::Dexterity, 2),
@@ -291,15 +338,6 @@ impl Race for Halfling {
     }
 }
 
-impl Resistances for Halfling {
-    fn resistances(&self) -> Vec<DamageType> {
-        match self.subrace {
-            HalflingSubrace::Ghostwise | HalflingSubrace::Lightfoot => vec![],
-            HalflingSubrace::Stout(_) => vec![DamageType::Poison],
-        }
-    }
-}
-
 impl Trinkets for Halfling {}
 
 impl fmt::Display for Halfling {
@@ -364,7 +402,7 @@ mod tests {
                 subrace,
                 reason_for_adventuring: String::new()
             })
-            .features())
+            .features(1))
             .collect::<Vec<Vec<Feature>>>());
     }
 
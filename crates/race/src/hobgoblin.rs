@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::{Backstory, MONSTROUS_ORIGIN};
 use characteristics::{
     in_inches,
@@ -12,7 +12,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use gear::{armor::ArmorType, weapons::WeaponCategory};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
@@ -50,10 +50,16 @@ impl AlignmentInfluences for Hobgoblin {
 
 impl Appearance for Hobgoblin {}
 
+impl Attacks for Hobgoblin {}
+
 impl Backstory for Hobgoblin {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Origin: {}", self.origin)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.origin = (*MONSTROUS_ORIGIN.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Hobgoblin {
@@ -81,20 +87,26 @@ impl Citations for Hobgoblin {
 }
 
 impl Features for Hobgoblin {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // Hobgoblins are careful not to show weakness in front of their allies, for fear of losing status. If you miss with an attack roll or fail an ability check or a saving throw, you can gain a bonus to the roll equal to the number of allies you can see within 30 feet of you (maximum bonus of +5). Once you use this trait, you can’t use it again until you finish a short or long rest.
             Feature {
                 title: "Saving Face",
+                description: "Hobgoblins are careful not to show weakness in front of their allies, for fear of losing status. If you miss with an attack roll or fail an ability check or a saving throw, you can gain a bonus to the roll equal to the number of allies you can see within 30 feet of you (maximum bonus of +5). Once you use this trait, you can’t use it again until you finish a short or long rest.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![FeatureEffect::Sense(Sense::Darkvision, 60)]
+    }
 }
 
 impl Languages for Hobgoblin {
@@ -146,8 +158,6 @@ impl Race for Hobgoblin {
     }
 }
 
-impl Resistances for Hobgoblin {}
-
 impl Trinkets for Hobgoblin {}
 
 impl fmt::Display for Hobgoblin {
@@ -220,7 +230,7 @@ mod tests {
         let hobgoblin = Hobgoblin {
             origin: String::new(),
         };
-        insta::assert_yaml_snapshot!(hobgoblin.features());
+        insta::assert_yaml_snapshot!(hobgoblin.features(1));
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::AlignmentInfluences;
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     in_inches, names::Name, AgeRange, Appearance, CharacteristicDetails, Characteristics, Gender,
@@ -13,16 +13,19 @@ use dice_roller::{Die, RollCmd};
 use features::Features;
 use languages::{Language, LanguageType, Languages};
 use personality::PersonalityOptions;
-use rand::{prelude::SliceRandom, Rng};
+use rand::{
+    prelude::{IteratorRandom, SliceRandom},
+    Rng,
+};
 use serde::{Deserialize, Serialize};
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
-    proficiencies::Proficiencies,
+    proficiencies::{Proficiencies, ProficiencyOption},
 };
 use strum::IntoEnumIterator;
 use trinkets::Trinkets;
 
-use super::Race;
+use super::{Race, Subrace};
 
 const HEIGHT_AND_WEIGHT: HeightAndWeightTable = HeightAndWeightTable {
     base_height: in_inches(4, 8),
@@ -32,7 +35,10 @@ const HEIGHT_AND_WEIGHT: HeightAndWeightTable = HeightAndWeightTable {
 };
 
 #[derive(Default, Deserialize, Serialize)]
-pub struct Human;
+pub struct Human {
+    /// Variant Human ability score increases, chosen in place of +1 to every score
+    variant: Option<Vec<AbilityScore>>,
+}
 
 impl Human {
     /// Separate function to make it easier to share with other races
@@ -60,12 +66,23 @@ impl Human {
         let names = ethnicity.unwrap().names();
         names.surname.choose(rng).unwrap_or(&"")
     }
+
+    /// Two different ability scores to increase by 1 for a Variant Human
+    fn gen_variant(rng: &mut impl Rng) -> Vec<AbilityScore> {
+        AbilityScoreType::iter()
+            .choose_multiple(rng, 2)
+            .into_iter()
+            .map(|t| AbilityScore(t, 1))
+            .collect()
+    }
 }
 
 impl AlignmentInfluences for Human {}
 
 impl Appearance for Human {}
 
+impl Attacks for Human {}
+
 impl Backstory for Human {}
 
 impl Characteristics for Human {
@@ -92,7 +109,11 @@ impl Characteristics for Human {
 
 impl Citations for Human {
     fn citations(&self) -> CitationList {
-        CitationList(vec![Citation(Book::Phb, 29)])
+        let mut citations = vec![Citation(Book::Phb, 29)];
+        if self.variant.is_some() {
+            citations.push(Citation(Book::Phb, 31));
+        }
+        CitationList(citations)
     }
 }
 
@@ -131,26 +152,53 @@ impl Pantheons for Human {
 
 impl PersonalityOptions for Human {}
 
-impl Proficiencies for Human {}
+impl Proficiencies for Human {
+    fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {
+        match self.variant {
+            Some(_) => vec![ProficiencyOption::Skill(None, 1)],
+            None => vec![],
+        }
+    }
+}
 
 impl Race for Human {
-    fn gen(_: &mut impl Rng) -> Self {
-        Self
+    fn gen(rng: &mut impl Rng) -> Self {
+        Self {
+            variant: rng.gen_bool(0.5).then(|| Self::gen_variant(rng)),
+        }
+    }
+
+    fn gen_subrace(rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        (subrace == Subrace::Variant).then(|| Self {
+            variant: Some(Self::gen_variant(rng)),
+        })
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        self.variant.is_some().then_some(Subrace::Variant)
     }
 
     fn abilities(&self) -> Vec<AbilityScore> {
-        AbilityScoreType::iter()
-            .map(|t| AbilityScore(t, 1))
-            .collect()
+        match &self.variant {
+            Some(abilities) => abilities.clone(),
+            None => AbilityScoreType::iter()
+                .map(|t| AbilityScore(t, 1))
+                .collect(),
+        }
     }
-}
 
-impl Resistances for Human {}
+    fn feats(&self) -> usize {
+        usize::from(self.variant.is_some())
+    }
+}
 
 impl Trinkets for Human {}
 
 impl fmt::Display for Human {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.variant.is_some() {
+            write!(f, "Variant ")?;
+        }
         write!(f, "Human")
     }
 }
@@ -177,25 +225,40 @@ mod tests {
 
     #[test]
     fn test_snapshot_abilities() {
-        let human = Human;
+        let human = Human::default();
         insta::assert_yaml_snapshot!(human.abilities());
     }
 
     #[test]
     fn test_snapshot_citations() {
-        let human = Human;
+        let human = Human::default();
         insta::assert_yaml_snapshot!(human.citations());
     }
 
     #[test]
     fn test_snapshot_features() {
-        let human = Human;
-        insta::assert_yaml_snapshot!(human.features());
+        let human = Human::default();
+        insta::assert_yaml_snapshot!(human.features(1));
     }
 
     #[test]
     fn test_snapshot_addl_pantheons() {
-        let human = Human;
+        let human = Human::default();
         insta::assert_yaml_snapshot!(human.addl_pantheons());
     }
+
+    #[test]
+    fn test_variant() {
+        let human = Human {
+            variant: Some(vec![
+                AbilityScore(AbilityScoreType::Strength, 1),
+                AbilityScore(AbilityScoreType::Constitution, 1),
+            ]),
+        };
+        assert_eq!(human.to_string(), "Variant Human");
+        assert_eq!(human.abilities().len(), 2);
+        assert_eq!(human.feats(), 1);
+        assert_eq!(human.addl_proficiencies().len(), 1);
+        insta::assert_yaml_snapshot!(human.citations());
+    }
 }
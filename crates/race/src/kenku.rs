@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -47,6 +47,8 @@ impl AlignmentInfluences for Kenku {
 
 impl Appearance for Kenku {}
 
+impl Attacks for Kenku {}
+
 impl Backstory for Kenku {}
 
 impl Characteristics for Kenku {
@@ -74,17 +76,19 @@ impl Citations for Kenku {
 }
 
 impl Features for Kenku {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can duplicate other creatures' handwriting and craftwork. You have advantage on all checks made to produce forgeries or duplicates of existing objects.
             Feature {
                 title: "Expert Forgery",
+                description: "You can duplicate other creatures' handwriting and craftwork. You have advantage on all checks made to produce forgeries or duplicates of existing objects.",
                 citation: Citation(Book::Vgtm, 111),
+                scaling: None,
             },
-            // You can mimic sounds you have heard, including voices. A creature that hears the sounds you make can tell they are imitations with a successful Wisdom (Insight) check opposed by your Charisma (Deception) check.
             Feature {
                 title: "Mimicry",
+                description: "You can mimic sounds you have heard, including voices. A creature that hears the sounds you make can tell they are imitations with a successful Wisdom (Insight) check opposed by your Charisma (Deception) check.",
                 citation: Citation(Book::Vgtm, 111),
+                scaling: None,
             },
         ]
     }
@@ -133,8 +137,6 @@ impl Race for Kenku {
     }
 }
 
-impl Resistances for Kenku {}
-
 impl Trinkets for Kenku {}
 
 impl fmt::Display for Kenku {
@@ -192,7 +194,7 @@ mod tests {
     #[test]
     fn test_snapshot_features() {
         let kenku = Kenku;
-        insta::assert_yaml_snapshot!(kenku.features());
+        insta::assert_yaml_snapshot!(kenku.features(1));
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::{Backstory, MONSTROUS_ORIGIN};
 use characteristics::{
     in_inches,
@@ -12,7 +12,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{
@@ -157,10 +157,16 @@ impl Appearance for Kobold {
     }
 }
 
+impl Attacks for Kobold {}
+
 impl Backstory for Kobold {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Origin: {}", self.origin)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.origin = (*MONSTROUS_ORIGIN.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Kobold {
@@ -188,30 +194,38 @@ impl Citations for Kobold {
 }
 
 impl Features for Kobold {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // As an action on your turn, you can cower pathetically to distract nearby foes. Until the end of your next turn, your allies gain advantage on attack rolls against enemies within 10 feet of you that can see you. Once you use this trait, you can’t use it again until you finish a short or long rest.
             Feature {
                 title: "Grovel, Cower, and Beg",
+                description: "As an action on your turn, you can cower pathetically to distract nearby foes. Until the end of your next turn, your allies gain advantage on attack rolls against enemies within 10 feet of you that can see you. Once you use this trait, you can’t use it again until you finish a short or long rest.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // You have advantage on an attack roll against a creature if at least one of your allies is within 5 feet of the creature and the ally isn’t incapacitated.
             Feature {
                 title: "Pack Tactics",
+                description: "You have advantage on an attack roll against a creature if at least one of your allies is within 5 feet of the creature and the ally isn’t incapacitated.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
-            // You have disadvantage on attack rolls and on Wisdom (Perception) checks that rely on sight when you, the target of your attack, or whatever you are trying to perceive is in direct sunlight.
             Feature {
                 title: "Sunlight Sensitivity",
+                description: "You have disadvantage on attack rolls and on Wisdom (Perception) checks that rely on sight when you, the target of your attack, or whatever you are trying to perceive is in direct sunlight.",
                 citation: Citation(Book::Vgtm, 119),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![FeatureEffect::Sense(Sense::Darkvision, 60)]
+    }
 }
 
 impl Languages for Kobold {
@@ -253,8 +267,6 @@ impl Race for Kobold {
     }
 }
 
-impl Resistances for Kobold {}
-
 impl Trinkets for Kobold {}
 
 impl fmt::Display for Kobold {
@@ -342,7 +354,7 @@ mod tests {
             scale_color: vec![ScaleColor::Black],
             scale_pattern: ScalePattern::Mottled,
         };
-        insta::assert_yaml_snapshot!(kobold.features());
+        insta::assert_yaml_snapshot!(kobold.features(1));
     }
 
     #[test]
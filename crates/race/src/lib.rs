@@ -25,21 +25,22 @@ mod yuan_ti;
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attack, Attacks};
 use backstory::Backstory;
 use characteristics::{names::Name, Appearance, CharacteristicDetails, Characteristics};
 use citation::{CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features};
 use languages::{Language, LanguageType, Languages};
 use personality::{Influence, PersonalityOptions};
 use rand::{prelude::IteratorRandom, Rng};
 use serde::{Deserialize, Serialize};
 use stats::{
-    ability::AbilityScore,
+    ability::{AbilityScore, AbilityScoreType, AbilityScores},
+    armor_class::UnarmoredDefense,
     proficiencies::{Proficiencies, Proficiency, ProficiencyOption},
 };
-use strum::{EnumIter, IntoEnumIterator};
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoEnumIterator};
 use trinkets::{TrinketOption, Trinkets};
 
 use self::{
@@ -50,10 +51,55 @@ use self::{
     tiefling::Tiefling, triton::Triton, yuan_ti::YuanTiPureblood,
 };
 
+/// Subraces and variants that can be chosen for a race. Each race only has some of these, and
+/// `Race::gen_subrace` will return `None` for the rest.
+#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, Eq, PartialEq)]
+#[strum(ascii_case_insensitive)]
+pub enum Subrace {
+    Asmodeus,
+    Baalzebul,
+    Dark,
+    Dispater,
+    Duergar,
+    Eladrin,
+    Fallen,
+    Feral,
+    Fierna,
+    Forest,
+    Ghostwise,
+    Githyanki,
+    Githzerai,
+    Glasya,
+    Gold,
+    High,
+    Hill,
+    Levistus,
+    Lightfoot,
+    Mammon,
+    Mephistopheles,
+    Moon,
+    Mountain,
+    Protector,
+    Rock,
+    Scourge,
+    Sea,
+    #[strum(serialize = "Shadar-kai")]
+    ShadarKai,
+    Shield,
+    Stout,
+    Strongheart,
+    Sun,
+    Svirfneblin,
+    Variant,
+    Wood,
+    Zariel,
+}
+
 /// Shared racial traits each race should provide.
 pub trait Race:
     AlignmentInfluences
     + Appearance
+    + Attacks
     + Backstory
     + Characteristics
     + Citations
@@ -63,42 +109,100 @@ pub trait Race:
     + Pantheons
     + PersonalityOptions
     + Proficiencies
-    + Resistances
     + Trinkets
     + fmt::Display
 {
     /// Method to generate a new instance of the struct
     fn gen(rng: &mut impl Rng) -> Self;
 
+    /// Method to generate a new instance of the struct with the given subrace, if the race has it
+    fn gen_subrace(_rng: &mut impl Rng, _subrace: Subrace) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// The race's subrace, for races that have them
+    fn subrace(&self) -> Option<Subrace> {
+        None
+    }
+
     /// Returns ability score increases for the race
     fn abilities(&self) -> Vec<AbilityScore>;
+
+    /// Abilities whose maximum score the race raises above 20
+    fn ability_maximums(&self) -> Vec<(AbilityScoreType, i16)> {
+        vec![]
+    }
+
+    /// Additional hit points gained at each level
+    fn hit_points_per_level(&self) -> i16 {
+        0
+    }
+
+    /// Number of feats the race grants at 1st level
+    fn feats(&self) -> usize {
+        0
+    }
+
+    /// Whether wearing heavy armor without meeting its Strength requirement reduces speed
+    fn heavy_armor_reduces_speed(&self) -> bool {
+        true
+    }
+
+    /// Alternative way to calculate Armor Class when not wearing armor
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        None
+    }
 }
 
 #[impl_enum::with_methods {
     pub fn abilities(&self) -> Vec<AbilityScore> {}
+    pub fn ability_maximums(&self) -> Vec<(AbilityScoreType, i16)> {}
     pub fn addl_languages(&self) -> (usize, Option<LanguageType>) {}
     pub fn addl_pantheons(&self) -> Vec<(Pantheon, PantheonWeight)> {}
     pub fn addl_proficiencies(&self) -> Vec<ProficiencyOption> {}
     pub fn appearance(&self) -> Vec<String> {}
+    pub fn attacks(&self, level: u8, ability_scores: &AbilityScores, proficiency_bonus: i16) -> Vec<Attack> {}
     pub fn attitude(&self) -> Vec<Attitude> {}
     pub fn backstory(&self) -> Vec<String> {}
     pub  fn citations(&self) -> CitationList {}
     pub fn bonds(&self) -> Vec<String> {}
     pub fn deity_required(&self) -> bool {}
-    pub fn features(&self) -> Vec<Feature> {}
+    pub fn feats(&self) -> usize {}
+    pub fn effects(&self, level: u8) -> Vec<FeatureEffect> {}
+    pub fn features(&self, level: u8) -> Vec<Feature> {}
     pub fn flaws(&self) -> Vec<String> {}
     pub fn gen_characteristics(&self, rng: &mut impl Rng) -> CharacteristicDetails {}
     pub fn gen_name(&self, rng: &mut impl Rng, characteristics: &CharacteristicDetails) -> String {}
+    pub fn heavy_armor_reduces_speed(&self) -> bool {}
+    pub fn hit_points_per_level(&self) -> i16 {}
     pub fn ideals(&self) -> Vec<(String, Influence)> {}
-    pub fn immunities(&self) -> Vec<DamageType> {}
     pub fn languages(&self) -> Vec<Language> {}
     pub fn morality(&self) -> Vec<Morality> {}
     pub fn proficiencies(&self) -> Vec<Proficiency> {}
-    pub fn resistances(&self) -> Vec<DamageType> {}
+    pub fn reroll_backstory(&mut self, rng: &mut impl Rng) {}
+    pub fn subrace(&self) -> Option<Subrace> {}
     pub fn traits(&self) -> Vec<String> {}
     pub fn trinket_options(&self) -> Vec<TrinketOption> {}
+    pub fn unarmored_defense(&self) -> Option<UnarmoredDefense> {}
 }]
-#[derive(Deserialize, EnumIter, Serialize)]
+#[derive(Deserialize, EnumDiscriminants, EnumIter, Serialize)]
+#[strum_discriminants(
+    name(RaceType),
+    derive(
+        Deserialize,
+        Display,
+        EnumIter,
+        EnumString,
+        Hash,
+        Ord,
+        PartialOrd,
+        Serialize
+    ),
+    strum(ascii_case_insensitive)
+)]
 pub enum RaceOption {
     Aasimar(Aasimar),
     Bugbear(Bugbear),
@@ -128,30 +232,68 @@ pub enum RaceOption {
 impl RaceOption {
     /// Randomly choose a race option and return the result of the corresponding racial struct's `gen` method
     pub fn gen(rng: &mut impl Rng) -> Self {
-        match Self::iter().choose(rng).unwrap() {
-            Self::Aasimar(_) => Self::Aasimar(Aasimar::gen(rng)),
-            Self::Bugbear(_) => Self::Bugbear(Bugbear::gen(rng)),
-            Self::Dragonborn(_) => Self::Dragonborn(Dragonborn::gen(rng)),
-            Self::Dwarf(_) => Self::Dwarf(Dwarf::gen(rng)),
-            Self::Elf(_) => Self::Elf(Elf::gen(rng)),
-            Self::Firbolg(_) => Self::Firbolg(Firbolg::gen(rng)),
-            Self::Gith(_) => Self::Gith(Gith::gen(rng)),
-            Self::Gnome(_) => Self::Gnome(Gnome::gen(rng)),
-            Self::Goblin(_) => Self::Goblin(Goblin::gen(rng)),
-            Self::Goliath(_) => Self::Goliath(Goliath::gen(rng)),
-            Self::HalfElf(_) => Self::HalfElf(HalfElf::gen(rng)),
-            Self::HalfOrc(_) => Self::HalfOrc(HalfOrc::gen(rng)),
-            Self::Halfling(_) => Self::Halfling(Halfling::gen(rng)),
-            Self::Hobgoblin(_) => Self::Hobgoblin(Hobgoblin::gen(rng)),
-            Self::Human(_) => Self::Human(Human::gen(rng)),
-            Self::Kenku(_) => Self::Kenku(Kenku::gen(rng)),
-            Self::Kobold(_) => Self::Kobold(Kobold::gen(rng)),
-            Self::Lizardfolk(_) => Self::Lizardfolk(Lizardfolk::gen(rng)),
-            Self::Orc(_) => Self::Orc(Orc::gen(rng)),
-            Self::Tabaxi(_) => Self::Tabaxi(Tabaxi::gen(rng)),
-            Self::Tiefling(_) => Self::Tiefling(Tiefling::gen(rng)),
-            Self::Triton(_) => Self::Triton(Triton::gen(rng)),
-            Self::YuanTiPureblood(_) => Self::YuanTiPureblood(YuanTiPureblood::gen(rng)),
+        let race_type = RaceType::iter().choose(rng).unwrap();
+        Self::gen_race(rng, race_type)
+    }
+
+    /// Generate a specific race, randomly choosing any subrace or other options within it
+    pub fn gen_race(rng: &mut impl Rng, race_type: RaceType) -> Self {
+        match race_type {
+            RaceType::Aasimar => Self::Aasimar(Aasimar::gen(rng)),
+            RaceType::Bugbear => Self::Bugbear(Bugbear::gen(rng)),
+            RaceType::Dragonborn => Self::Dragonborn(Dragonborn::gen(rng)),
+            RaceType::Dwarf => Self::Dwarf(Dwarf::gen(rng)),
+            RaceType::Elf => Self::Elf(Elf::gen(rng)),
+            RaceType::Firbolg => Self::Firbolg(Firbolg::gen(rng)),
+            RaceType::Gith => Self::Gith(Gith::gen(rng)),
+            RaceType::Gnome => Self::Gnome(Gnome::gen(rng)),
+            RaceType::Goblin => Self::Goblin(Goblin::gen(rng)),
+            RaceType::Goliath => Self::Goliath(Goliath::gen(rng)),
+            RaceType::HalfElf => Self::HalfElf(HalfElf::gen(rng)),
+            RaceType::HalfOrc => Self::HalfOrc(HalfOrc::gen(rng)),
+            RaceType::Halfling => Self::Halfling(Halfling::gen(rng)),
+            RaceType::Hobgoblin => Self::Hobgoblin(Hobgoblin::gen(rng)),
+            RaceType::Human => Self::Human(Human::gen(rng)),
+            RaceType::Kenku => Self::Kenku(Kenku::gen(rng)),
+            RaceType::Kobold => Self::Kobold(Kobold::gen(rng)),
+            RaceType::Lizardfolk => Self::Lizardfolk(Lizardfolk::gen(rng)),
+            RaceType::Orc => Self::Orc(Orc::gen(rng)),
+            RaceType::Tabaxi => Self::Tabaxi(Tabaxi::gen(rng)),
+            RaceType::Tiefling => Self::Tiefling(Tiefling::gen(rng)),
+            RaceType::Triton => Self::Triton(Triton::gen(rng)),
+            RaceType::YuanTiPureblood => Self::YuanTiPureblood(YuanTiPureblood::gen(rng)),
+        }
+    }
+
+    /// Generate a specific race with the given subrace, randomly choosing any other options within
+    /// it. Returns `None` if the race doesn't have that subrace.
+    pub fn gen_subrace(rng: &mut impl Rng, race_type: RaceType, subrace: Subrace) -> Option<Self> {
+        match race_type {
+            RaceType::Aasimar => Aasimar::gen_subrace(rng, subrace).map(Self::Aasimar),
+            RaceType::Bugbear => Bugbear::gen_subrace(rng, subrace).map(Self::Bugbear),
+            RaceType::Dragonborn => Dragonborn::gen_subrace(rng, subrace).map(Self::Dragonborn),
+            RaceType::Dwarf => Dwarf::gen_subrace(rng, subrace).map(Self::Dwarf),
+            RaceType::Elf => Elf::gen_subrace(rng, subrace).map(Self::Elf),
+            RaceType::Firbolg => Firbolg::gen_subrace(rng, subrace).map(Self::Firbolg),
+            RaceType::Gith => Gith::gen_subrace(rng, subrace).map(Self::Gith),
+            RaceType::Gnome => Gnome::gen_subrace(rng, subrace).map(Self::Gnome),
+            RaceType::Goblin => Goblin::gen_subrace(rng, subrace).map(Self::Goblin),
+            RaceType::Goliath => Goliath::gen_subrace(rng, subrace).map(Self::Goliath),
+            RaceType::HalfElf => HalfElf::gen_subrace(rng, subrace).map(Self::HalfElf),
+            RaceType::HalfOrc => HalfOrc::gen_subrace(rng, subrace).map(Self::HalfOrc),
+            RaceType::Halfling => Halfling::gen_subrace(rng, subrace).map(Self::Halfling),
+            RaceType::Hobgoblin => Hobgoblin::gen_subrace(rng, subrace).map(Self::Hobgoblin),
+            RaceType::Human => Human::gen_subrace(rng, subrace).map(Self::Human),
+            RaceType::Kenku => Kenku::gen_subrace(rng, subrace).map(Self::Kenku),
+            RaceType::Kobold => Kobold::gen_subrace(rng, subrace).map(Self::Kobold),
+            RaceType::Lizardfolk => Lizardfolk::gen_subrace(rng, subrace).map(Self::Lizardfolk),
+            RaceType::Orc => Orc::gen_subrace(rng, subrace).map(Self::Orc),
+            RaceType::Tabaxi => Tabaxi::gen_subrace(rng, subrace).map(Self::Tabaxi),
+            RaceType::Tiefling => Tiefling::gen_subrace(rng, subrace).map(Self::Tiefling),
+            RaceType::Triton => Triton::gen_subrace(rng, subrace).map(Self::Triton),
+            RaceType::YuanTiPureblood => {
+                YuanTiPureblood::gen_subrace(rng, subrace).map(Self::YuanTiPureblood)
+            }
         }
     }
 }
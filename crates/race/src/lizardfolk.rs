@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::{Attack, Attacks, Damage, DamageType, Hit};
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -18,7 +18,8 @@ use personality::PersonalityOptions;
 use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 use stats::{
-    ability::{AbilityScore, AbilityScoreType, Skill},
+    ability::{AbilityScore, AbilityScoreType, AbilityScores, Skill},
+    armor_class::UnarmoredDefense,
     proficiencies::{Proficiencies, ProficiencyOption},
 };
 use trinkets::Trinkets;
@@ -60,10 +61,38 @@ impl AlignmentInfluences for Lizardfolk {
 
 impl Appearance for Lizardfolk {}
 
+impl Attacks for Lizardfolk {
+    /// Bite are natural weapons, which you can use to make unarmed strikes.
+    fn attacks(
+        &self,
+        _: u8,
+        ability_scores: &AbilityScores,
+        proficiency_bonus: i16,
+    ) -> Vec<Attack> {
+        let strength = ability_scores.modifier(AbilityScoreType::Strength);
+        vec![Attack {
+            citation: Citation(Book::Vgtm, 113),
+            damage: Some(Damage {
+                damage_type: DamageType::Piercing,
+                modifier: strength,
+                roll: Some(RollCmd(1, Die::D6)),
+            }),
+            hit: Hit::Roll(strength + proficiency_bonus),
+            name: "Bite".to_string(),
+            properties: vec![],
+            range: "5 ft.".to_string(),
+        }]
+    }
+}
+
 impl Backstory for Lizardfolk {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Quirk: {}", self.quirk)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.quirk = (*QUIRKS.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Lizardfolk {
@@ -91,32 +120,37 @@ impl Citations for Lizardfolk {
 }
 
 impl Features for Lizardfolk {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // Your fanged maw is a natural weapon, which you can use to make unarmed strikes. If you hit with it, you deal piercing damage equal to 1d6 + your Strength modifier, instead of the bludgeoning damage normal for an unarmed strike.
             Feature {
                 title: "Bite",
+                description: "Your fanged maw is a natural weapon, which you can use to make unarmed strikes. If you hit with it, you deal piercing damage equal to 1d6 + your Strength modifier, instead of the bludgeoning damage normal for an unarmed strike.",
                 citation: Citation(Book::Vgtm, 113),
+                scaling: None,
             },
-            // As part of a short rest, you can harvest bone and hide from a slain beast, construct, dragon, monstrosity, or plant creature of size Small or larger to create one of the following items: a shield, a club, a javelin, or 1d4 darts or blowgun needles. To use this trait, you need a blade, such as a dagger, or appropriate artisan's tools, such as leatherworker's tools.
             Feature {
                 title: "Cunning Artisan",
+                description: "As part of a short rest, you can harvest bone and hide from a slain beast, construct, dragon, monstrosity, or plant creature of size Small or larger to create one of the following items: a shield, a club, a javelin, or 1d4 darts or blowgun needles. To use this trait, you need a blade, such as a dagger, or appropriate artisan's tools, such as leatherworker's tools.",
                 citation: Citation(Book::Vgtm, 113),
+                scaling: None,
             },
-            // You can hold your breath for up to 15 minutes at a time.
             Feature {
                 title: "Hold Breath",
+                description: "You can hold your breath for up to 15 minutes at a time.",
                 citation: Citation(Book::Vgtm, 113),
+                scaling: None,
             },
-            // You have tough, scaly skin. When you aren't wearing armor, your AC is 13 + your Dexterity modifier. You can use your natural armor to determine your AC if the armor you wear would leave you with a lower AC. A shield's benefits apply as normal while you use your natural armor.
             Feature {
                 title: "Natural Armor",
+                description: "You have tough, scaly skin. When you aren't wearing armor, your AC is 13 + your Dexterity modifier. You can use your natural armor to determine your AC if the armor you wear would leave you with a lower AC. A shield's benefits apply as normal while you use your natural armor.",
                 citation: Citation(Book::Vgtm, 113),
+                scaling: None,
             },
-            // In battle, you can throw yourself into a vicious feeding frenzy. As a bonus action, you can make a special attack with your bite. If the attack hits, it deals its normal damage, and you gain temporary hit points (minimum of 1) equal to your Constitution modifier, and you can't use this trait again until you finish a short or long rest.
             Feature {
                 title: "Hungry Jaws",
+                description: "In battle, you can throw yourself into a vicious feeding frenzy. As a bonus action, you can make a special attack with your bite. If the attack hits, it deals its normal damage, and you gain temporary hit points (minimum of 1) equal to your Constitution modifier, and you can't use this trait again until you finish a short or long rest.",
                 citation: Citation(Book::Vgtm, 113),
+                scaling: None,
             },
         ]
     }
@@ -169,9 +203,16 @@ impl Race for Lizardfolk {
             AbilityScore(AbilityScoreType::Wisdom, 1),
         ]
     }
-}
 
-impl Resistances for Lizardfolk {}
+    fn unarmored_defense(&self) -> Option<UnarmoredDefense> {
+        Some(UnarmoredDefense {
+            name: "Natural Armor",
+            base: 13,
+            abilities: vec![AbilityScoreType::Dexterity],
+            shield: true,
+        })
+    }
+}
 
 impl Trinkets for Lizardfolk {}
 
@@ -248,7 +289,17 @@ mod tests {
         let lizardfolk = Lizardfolk {
             quirk: String::new(),
         };
-        insta::assert_yaml_snapshot!(lizardfolk.features());
+        insta::assert_yaml_snapshot!(lizardfolk.features(1));
+    }
+
+    #[test]
+    fn test_snapshot_attacks() {
+        let lizardfolk = Lizardfolk {
+            quirk: String::new(),
+        };
+        let ability_scores =
+            AbilityScores([(AbilityScoreType::Strength, 14)].into_iter().collect());
+        insta::assert_yaml_snapshot!(lizardfolk.attacks(1, &ability_scores, 2));
     }
 
     #[test]
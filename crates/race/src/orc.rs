@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::Attacks;
 use backstory::{Backstory, MONSTROUS_ORIGIN};
 use characteristics::{
     in_inches,
@@ -15,7 +15,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::{Pantheon, PantheonWeight, Pantheons};
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, Languages};
 use personality::{Influence, PersonalityOptions};
 use rand::{prelude::SliceRandom, Rng};
@@ -117,10 +117,16 @@ impl AlignmentInfluences for Orc {
 
 impl Appearance for Orc {}
 
+impl Attacks for Orc {}
+
 impl Backstory for Orc {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Origin: {}", self.origin)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.origin = (*MONSTROUS_ORIGIN.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Orc {
@@ -148,25 +154,35 @@ impl Citations for Orc {
 }
 
 impl Features for Orc {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 120),
+                scaling: None,
             },
-            // As a bonus action, you can move up to your speed toward an enemy of your choice that you can see or hear. You must end this move closer to the enemy than you started.
             Feature {
                 title: "Aggresive",
+                description: "As a bonus action, you can move up to your speed toward an enemy of your choice that you can see or hear. You must end this move closer to the enemy than you started.",
                 citation: Citation(Book::Vgtm, 120),
+                scaling: None,
             },
-            // You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.
             Feature {
                 title: "Powerful Build",
+                description: "You count as one size larger when determining your carrying capacity and the weight you can push, drag, or lift.",
                 citation: Citation(Book::Vgtm, 120),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![
+            FeatureEffect::PowerfulBuild,
+            FeatureEffect::Sense(Sense::Darkvision, 60),
+        ]
+    }
 }
 
 impl Languages for Orc {
@@ -231,8 +247,6 @@ impl Race for Orc {
     }
 }
 
-impl Resistances for Orc {}
-
 impl Trinkets for Orc {}
 
 impl fmt::Display for Orc {
@@ -305,7 +319,7 @@ mod tests {
         let orc = Orc {
             origin: String::new(),
         };
-        insta::assert_yaml_snapshot!(orc.features());
+        insta::assert_yaml_snapshot!(orc.features(1));
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::Resistances;
+use attack::{Attack, Attacks, Damage, DamageType, Hit};
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -15,13 +15,13 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::Pantheons;
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, LanguageType, Languages};
 use personality::PersonalityOptions;
 use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 use stats::{
-    ability::{AbilityScore, AbilityScoreType, Skill},
+    ability::{AbilityScore, AbilityScoreType, AbilityScores, Skill},
     proficiencies::{Proficiencies, Proficiency},
 };
 use trinkets::Trinkets;
@@ -77,6 +77,30 @@ impl AlignmentInfluences for Tabaxi {
 
 impl Appearance for Tabaxi {}
 
+impl Attacks for Tabaxi {
+    /// Claws are natural weapons, which you can use to make unarmed strikes.
+    fn attacks(
+        &self,
+        _: u8,
+        ability_scores: &AbilityScores,
+        proficiency_bonus: i16,
+    ) -> Vec<Attack> {
+        let strength = ability_scores.modifier(AbilityScoreType::Strength);
+        vec![Attack {
+            citation: Citation(Book::Vgtm, 115),
+            damage: Some(Damage {
+                damage_type: DamageType::Slashing,
+                modifier: strength,
+                roll: Some(RollCmd(1, Die::D4)),
+            }),
+            hit: Hit::Roll(strength + proficiency_bonus),
+            name: "Claws".to_string(),
+            properties: vec![],
+            range: "5 ft.".to_string(),
+        }]
+    }
+}
+
 impl Backstory for Tabaxi {
     fn backstory(&self) -> Vec<String> {
         vec![
@@ -84,6 +108,11 @@ impl Backstory for Tabaxi {
             format!("Quirk: {}", self.quirk),
         ]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.obsession = (*OBSESSIONS.choose(rng).unwrap()).to_string();
+        self.quirk = (*QUIRKS.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Tabaxi {
@@ -111,25 +140,32 @@ impl Citations for Tabaxi {
 }
 
 impl Features for Tabaxi {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You have a cat's keen senses, especially in the dark. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "You have a cat's keen senses, especially in the dark. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 115),
+                scaling: None,
             },
-            // Your reflexes and agility allow you to move with a burst of speed. When you move on your tum in combat, you can double your speed until the end of the tum. Once you use this trait, you can't use it again until you move 0 feet on one of your turns.
             Feature {
                 title: "Feline Agility",
+                description: "Your reflexes and agility allow you to move with a burst of speed. When you move on your tum in combat, you can double your speed until the end of the tum. Once you use this trait, you can't use it again until you move 0 feet on one of your turns.",
                 citation: Citation(Book::Vgtm, 115),
+                scaling: None,
             },
-            // Because of your claws, you have a climbing speed of 20 feet. In addition, your claws are natural weapons, which you can use to make unarmed strikes. If you hit with them, you deal slashing damage equal to 1d4 + your Strength modifier, instead of the bludgeoning damage normal for an unarmed strike.
             Feature {
                 title: "Cat's Claws",
+                description: "Because of your claws, you have a climbing speed of 20 feet. In addition, your claws are natural weapons, which you can use to make unarmed strikes. If you hit with them, you deal slashing damage equal to 1d4 + your Strength modifier, instead of the bludgeoning damage normal for an unarmed strike.",
                 citation: Citation(Book::Vgtm, 115),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![FeatureEffect::Sense(Sense::Darkvision, 60)]
+    }
 }
 
 impl Languages for Tabaxi {
@@ -181,8 +217,6 @@ impl Race for Tabaxi {
     }
 }
 
-impl Resistances for Tabaxi {}
-
 impl Trinkets for Tabaxi {}
 
 impl fmt::Display for Tabaxi {
@@ -262,7 +296,18 @@ mod tests {
             obsession: String::new(),
             quirk: String::new(),
         };
-        insta::assert_yaml_snapshot!(tabaxi.features());
+        insta::assert_yaml_snapshot!(tabaxi.features(1));
+    }
+
+    #[test]
+    fn test_snapshot_attacks() {
+        let tabaxi = Tabaxi {
+            obsession: String::new(),
+            quirk: String::new(),
+        };
+        let ability_scores =
+            AbilityScores([(AbilityScoreType::Strength, 14)].into_iter().collect());
+        insta::assert_yaml_snapshot!(tabaxi.attacks(1, &ability_scores, 2));
     }
 
     #[test]
@@ -2,7 +2,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attacks, DamageType};
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -16,7 +16,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::Pantheons;
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features, Sense};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{
@@ -24,6 +24,7 @@ use rand::{
     Rng,
 };
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::Proficiencies,
@@ -31,7 +32,7 @@ use stats::{
 use strum::{Display, EnumIter, IntoEnumIterator};
 use trinkets::Trinkets;
 
-use super::{human::Human, Race};
+use super::{human::Human, Race, Subrace};
 
 /// Tiefling height and weight table
 const HEIGHT_AND_WEIGHT: HeightAndWeightTable = HeightAndWeightTable {
@@ -181,15 +182,17 @@ enum FeralVariant {
 impl FeralVariant {
     fn features(&self) -> Vec<Feature> {
         match self {
-            // You know the Vicious Mockery cantrip. Once you reach 3rd level, you can cast the Charm Person spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Enthrall spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells. This trait replaces the Infernal Legacy trait
             Self::DevilsTongue => vec![Feature {
                 title: "Devil's Tongue",
+                description: "You know the Vicious Mockery cantrip. Once you reach 3rd level, you can cast the Charm Person spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Enthrall spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells. This trait replaces the Infernal Legacy trait",
                 citation: Citation(Book::Scag, 118),
+                scaling: None,
             }],
-            // Once you reach 3rd level, you can cast the Burning Hands spell once as a 2nd-level spell. This trait replaces the Hellish Rebuke spell of the Infernal Legacy trait.
             Self::Hellfire => vec![Feature {
                 title: "Hellfire",
+                description: "Once you reach 3rd level, you can cast the Burning Hands spell once as a 2nd-level spell. This trait replaces the Hellish Rebuke spell of the Infernal Legacy trait.",
                 citation: Citation(Book::Scag, 118),
+                scaling: None,
             }],
             Self::Winged => vec![],
         }
@@ -285,6 +288,8 @@ impl Appearance for Tiefling {
     }
 }
 
+impl Attacks for Tiefling {}
+
 impl Backstory for Tiefling {}
 
 impl Characteristics for Tiefling {
@@ -332,67 +337,98 @@ impl Citations for Tiefling {
 }
 
 impl Features for Tiefling {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         let mut features = vec![
-            // Thanks to your infernal heritage, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "Thanks to your infernal heritage, you have superior vision in dark and dim conditions. You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can't discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Phb, 43),
+                scaling: None,
             },
         ];
         features.extend(match &self.subrace {
-            // You know the thaumaturgy cantrip. When you reach 3rd level, you can cast the hellish rebuke spell as a 2nd-level spell once with this trait and regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the darkness spell once with this trait and regain the ability to do so when you finish a long rest. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Asmodeus => {
                 vec![Feature {
                     title: "Infernal Legacy",
+                    description: "You know the thaumaturgy cantrip. When you reach 3rd level, you can cast the hellish rebuke spell as a 2nd-level spell once with this trait and regain the ability to do so when you finish a long rest. When you reach 5th level, you can cast the darkness spell once with this trait and regain the ability to do so when you finish a long rest. Charisma is your spellcasting ability for these spells.",
                     citation: Citation(Book::Phb, 43),
+                    scaling: None,
                 }]
             }
-            // You know the Thaumaturgy cantrip. Once you reach 3rd level, you can cast the Ray of Sickness spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Crown of Madness spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Baalzebul => vec![Feature {
                 title: "Legacy of Maladomini",
+                description: "You know the Thaumaturgy cantrip. Once you reach 3rd level, you can cast the Ray of Sickness spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Crown of Madness spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 21),
+                scaling: None,
             }],
-            // You know the Thaumaturgy cantrip. Once you reach 3rd level, you can cast the Disguise Self spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Detect Thoughts spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Dispater => vec![Feature {
                 title: "Legacy of Dis",
+                description: "You know the Thaumaturgy cantrip. Once you reach 3rd level, you can cast the Disguise Self spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Detect Thoughts spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 21),
+                scaling: None,
             }],
             TieflingSubrace::Feral(v) => v.features(),
-            // You know the Friends cantrip. Once you reach 3rd level, you can cast the Charm Person spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Suggestion spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Fierna => vec![Feature {
                 title: "Legacy of Phlegethos",
+                description: "You know the Friends cantrip. Once you reach 3rd level, you can cast the Charm Person spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Suggestion spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 21),
+                scaling: None,
             }],
-            // You know the Minor Illusion cantrip. Once you reach 3rd level, you can cast the Disguise Self spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Invisibility spell once as a 2nd-level spell. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Glasya => vec![Feature {
                 title: "Legacy of Malbolge",
+                description: "You know the Minor Illusion cantrip. Once you reach 3rd level, you can cast the Disguise Self spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Invisibility spell once as a 2nd-level spell. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 22),
+                scaling: None,
             }],
-            // You know the Ray of Frost cantrip. Once you reach 3rd level, you can cast the Armor of Agathys spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Darkness spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Levistus => vec![Feature {
                 title: "Legacy of Stygia",
+                description: "You know the Ray of Frost cantrip. Once you reach 3rd level, you can cast the Armor of Agathys spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Darkness spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 22),
+                scaling: None,
             }],
-            // You know the Mage Hand cantrip. Once you reach 3rd level, you can cast the Tenser's Floating Disk spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Arcane Lock spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Mammon => vec![Feature {
                 title: "Legacy of Minauros",
+                description: "You know the Mage Hand cantrip. Once you reach 3rd level, you can cast the Tenser's Floating Disk spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Arcane Lock spell once. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 22),
+                scaling: None,
             }],
-            // You know the Mage Hand cantrip. Once you reach 3rd level, you can cast the Burning Hands spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Flame Blade spell once as a 3rd-level spell. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Mephistopheles => vec![Feature {
                 title: "Legacy of Cania",
+                description: "You know the Mage Hand cantrip. Once you reach 3rd level, you can cast the Burning Hands spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Flame Blade spell once as a 3rd-level spell. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 23),
+                scaling: None,
             }],
-            // You know the Thaumaturgy cantrip. Once you reach 3rd level, you can cast the Searing Smite spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Branding Smite spell once as a 3rd-level spell. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.
             TieflingSubrace::Zariel => vec![Feature {
                 title: "Legacy of Avernus",
+                description: "You know the Thaumaturgy cantrip. Once you reach 3rd level, you can cast the Searing Smite spell once as a 2nd-level spell. Once you reach 5th level, you can also cast the Branding Smite spell once as a 3rd-level spell. You must finish a long rest to cast these spells again with this trait. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Mtof, 23),
+                scaling: None,
             }],
         });
 
         features
     }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![
+            FeatureEffect::Sense(Sense::Darkvision, 60),
+            FeatureEffect::DamageResistance(DamageType::Fire),
+        ];
+        // Infernal Legacy
+        if let TieflingSubrace::Asmodeus = self.subrace {
+            effects.extend(
+                [
+                    (1, Spell::Thaumaturgy),
+                    (3, Spell::HellishRebuke),
+                    (5, Spell::Darkness),
+                ]
+                .into_iter()
+                .filter(|&(gained, _)| gained <= level)
+                .map(|(_, spell)| FeatureEffect::Spell(spell)),
+            );
+        }
+        effects
+    }
 }
 
 impl Languages for Tiefling {
@@ -427,6 +463,41 @@ impl Race for Tiefling {
         }
     }
 
+    fn gen_subrace(rng: &mut impl Rng, subrace: Subrace) -> Option<Self> {
+        let subrace = match subrace {
+            Subrace::Asmodeus => TieflingSubrace::Asmodeus,
+            Subrace::Baalzebul => TieflingSubrace::Baalzebul,
+            Subrace::Dispater => TieflingSubrace::Dispater,
+            Subrace::Feral => TieflingSubrace::Feral(FeralVariant::iter().choose(rng).unwrap()),
+            Subrace::Fierna => TieflingSubrace::Fierna,
+            Subrace::Glasya => TieflingSubrace::Glasya,
+            Subrace::Levistus => TieflingSubrace::Levistus,
+            Subrace::Mammon => TieflingSubrace::Mammon,
+            Subrace::Mephistopheles => TieflingSubrace::Mephistopheles,
+            Subrace::Zariel => TieflingSubrace::Zariel,
+            _ => return None,
+        };
+        Some(Self {
+            appearance: PhysicalAppearance::gen(rng),
+            subrace,
+        })
+    }
+
+    fn subrace(&self) -> Option<Subrace> {
+        Some(match self.subrace {
+            TieflingSubrace::Asmodeus => Subrace::Asmodeus,
+            TieflingSubrace::Baalzebul => Subrace::Baalzebul,
+            TieflingSubrace::Dispater => Subrace::Dispater,
+            TieflingSubrace::Feral(_) => Subrace::Feral,
+            TieflingSubrace::Fierna => Subrace::Fierna,
+            TieflingSubrace::Glasya => Subrace::Glasya,
+            TieflingSubrace::Levistus => Subrace::Levistus,
+            TieflingSubrace::Mammon => Subrace::Mammon,
+            TieflingSubrace::Mephistopheles => Subrace::Mephistopheles,
+            TieflingSubrace::Zariel => Subrace::Zariel,
+        })
+    }
+
     fn abilities(&self) -> Vec<AbilityScore> {
         match self.subrace {
             TieflingSubrace::Asmodeus
@@ -462,12 +533,6 @@ impl Race for Tiefling {
     }
 }
 
-impl Resistances for Tiefling {
-    fn resistances(&self) -> Vec<DamageType> {
-        vec![DamageType::Fire]
-    }
-}
-
 impl Trinkets for Tiefling {}
 
 impl fmt::Display for Tiefling {
@@ -536,7 +601,7 @@ mod tests {
                 appearance: vec![],
                 subrace
             }
-            .features())
+            .features(1))
             .collect::<Vec<Vec<Feature>>>())
     }
 
@@ -587,11 +652,11 @@ mod tests {
     }
 
     #[test]
-    fn test_resistances() {
+    fn test_effects() {
         let tiefling = Tiefling {
             appearance: vec![],
             subrace: TieflingSubrace::Asmodeus,
         };
-        insta::assert_yaml_snapshot!(tiefling.resistances());
+        insta::assert_yaml_snapshot!(tiefling.effects(5));
     }
 }
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attacks, DamageType};
 use backstory::Backstory;
 use characteristics::{
     in_inches,
@@ -15,11 +15,12 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::Pantheons;
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Feature, FeatureEffect, Features};
 use languages::{Language, Languages};
 use personality::PersonalityOptions;
 use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::Proficiencies,
@@ -61,10 +62,16 @@ impl AlignmentInfluences for Triton {
 
 impl Appearance for Triton {}
 
+impl Attacks for Triton {}
+
 impl Backstory for Triton {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Quirk: {}", self.quirk)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.quirk = (*QUIRKS.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for Triton {
@@ -92,30 +99,41 @@ impl Citations for Triton {
 }
 
 impl Features for Triton {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can breathe air and water.
             Feature {
                 title: "Amphibious",
+                description: "You can breathe air and water.",
                 citation: Citation(Book::Vgtm, 118),
+                scaling: None,
             },
-            // A child of the sea, you can call on the magic of elemental air and water. You can cast fog cloud with this trait. Starting at 3rd level, you can cast gust of wind with it, and starting at 5th level, you can also cast wall of water with it. Once you cast a spell with this trait, you can’t cast that spell with it again until you finish a long rest. Charisma is your spellcasting ability for these spells.
             Feature {
                 title: "Control Air and Water",
+                description: "A child of the sea, you can call on the magic of elemental air and water. You can cast fog cloud with this trait. Starting at 3rd level, you can cast gust of wind with it, and starting at 5th level, you can also cast wall of water with it. Once you cast a spell with this trait, you can’t cast that spell with it again until you finish a long rest. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Vgtm, 118),
+                scaling: None,
             },
-            // Aquatic beasts have an extraordinary affinity with your people. You can communicate simple ideas with beasts that can breathe water. They can understand the meaning of your words, though you have no special ability to understand them in return.
             Feature {
                 title: "Emissary of the Sea",
+                description: "Aquatic beasts have an extraordinary affinity with your people. You can communicate simple ideas with beasts that can breathe water. They can understand the meaning of your words, though you have no special ability to understand them in return.",
                 citation: Citation(Book::Vgtm, 118),
+                scaling: None,
             },
-            // Adapted to even the most extreme ocean depths, you have resistance to cold damage, and you ignore any of the drawbacks caused by a deep, underwater environment.
             Feature {
                 title: "Guardians of the Depths",
+                description: "Adapted to even the most extreme ocean depths, you have resistance to cold damage, and you ignore any of the drawbacks caused by a deep, underwater environment.",
                 citation: Citation(Book::Vgtm, 118),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, _: u8) -> Vec<FeatureEffect> {
+        vec![
+            FeatureEffect::DamageResistance(DamageType::Cold),
+            FeatureEffect::Spell(Spell::FogCloud),
+        ]
+    }
 }
 
 impl Languages for Triton {
@@ -164,12 +182,6 @@ impl Race for Triton {
     }
 }
 
-impl Resistances for Triton {
-    fn resistances(&self) -> Vec<DamageType> {
-        vec![DamageType::Cold]
-    }
-}
-
 impl Trinkets for Triton {}
 
 impl fmt::Display for Triton {
@@ -245,7 +257,7 @@ mod tests {
         let triton = Triton {
             quirk: String::new(),
         };
-        insta::assert_yaml_snapshot!(triton.features());
+        insta::assert_yaml_snapshot!(triton.features(1));
     }
 
     #[test]
@@ -290,10 +302,10 @@ mod tests {
     }
 
     #[test]
-    fn test_snapshot_resistances() {
+    fn test_snapshot_effects() {
         let triton = Triton {
             quirk: String::new(),
         };
-        insta::assert_yaml_snapshot!(triton.resistances());
+        insta::assert_yaml_snapshot!(triton.effects(1));
     }
 }
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use alignment::{AlignmentInfluences, Attitude, Morality};
-use attack::{DamageType, Resistances};
+use attack::{Attacks, DamageType};
 use backstory::{Backstory, MONSTROUS_ORIGIN};
 use characteristics::{
     in_inches,
@@ -12,7 +12,7 @@ use characteristics::{
 use citation::{Book, Citation, CitationList, Citations};
 use deities::Pantheons;
 use dice_roller::{Die, RollCmd};
-use features::{Feature, Features};
+use features::{Condition, Feature, FeatureEffect, Features, SaveAdvantage, Sense, Threat};
 use languages::{Language, Languages};
 use personality::{Influence, PersonalityOptions};
 use rand::{
@@ -20,6 +20,7 @@ use rand::{
     Rng,
 };
 use serde::{Deserialize, Serialize};
+use spells::Spell;
 use stats::{
     ability::{AbilityScore, AbilityScoreType},
     proficiencies::Proficiencies,
@@ -367,10 +368,16 @@ impl Appearance for YuanTiPureblood {
     }
 }
 
+impl Attacks for YuanTiPureblood {}
+
 impl Backstory for YuanTiPureblood {
     fn backstory(&self) -> Vec<String> {
         vec![format!("Origin: {}", self.origin)]
     }
+
+    fn reroll_backstory(&mut self, rng: &mut impl Rng) {
+        self.origin = (*MONSTROUS_ORIGIN.choose(rng).unwrap()).to_string();
+    }
 }
 
 impl Characteristics for YuanTiPureblood {
@@ -402,30 +409,49 @@ impl Citations for YuanTiPureblood {
 }
 
 impl Features for YuanTiPureblood {
-    fn features(&self) -> Vec<Feature> {
+    fn features(&self, _: u8) -> Vec<Feature> {
         vec![
-            // You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.
             Feature {
                 title: "Darkvision",
+                description: "You can see in dim light within 60 feet of you as if it were bright light, and in darkness as if it were dim light. You can’t discern color in darkness, only shades of gray.",
                 citation: Citation(Book::Vgtm, 120),
+                scaling: None,
             },
-            // You know the poison spray cantrip. You can cast animal friendship an unlimited number of times with this trait, but you can target only snakes with it. Starting at 3rd level, you can also cast suggestion with this trait. Once you cast it, you can’t do so again until you finish a long rest. Charisma is your spellcasting ability for these spells.
             Feature {
                 title: "Innate Spellcasting",
+                description: "You know the poison spray cantrip. You can cast animal friendship an unlimited number of times with this trait, but you can target only snakes with it. Starting at 3rd level, you can also cast suggestion with this trait. Once you cast it, you can’t do so again until you finish a long rest. Charisma is your spellcasting ability for these spells.",
                 citation: Citation(Book::Vgtm, 120),
+                scaling: None,
             },
-            // You have advantage on saving throws against spells and other magical effects.
             Feature {
                 title: "Magic Resistance",
+                description: "You have advantage on saving throws against spells and other magical effects.",
                 citation: Citation(Book::Vgtm, 120),
+                scaling: None,
             },
-            // You are immune to poison damage and the poisoned condition.
             Feature {
                 title: "Poison Immunity",
+                description: "You are immune to poison damage and the poisoned condition.",
                 citation: Citation(Book::Vgtm, 120),
+                scaling: None,
             },
         ]
     }
+
+    fn effects(&self, level: u8) -> Vec<FeatureEffect> {
+        let mut effects = vec![
+            FeatureEffect::Sense(Sense::Darkvision, 60),
+            FeatureEffect::SaveAdvantage(SaveAdvantage::against(Threat::Magic)),
+            FeatureEffect::DamageImmunity(DamageType::Poison),
+            FeatureEffect::ConditionImmunity(Condition::Poisoned),
+            FeatureEffect::Spell(Spell::PoisonSpray),
+            FeatureEffect::Spell(Spell::AnimalFriendship),
+        ];
+        if level >= 3 {
+            effects.push(FeatureEffect::Spell(Spell::Suggestion));
+        }
+        effects
+    }
 }
 
 impl Languages for YuanTiPureblood {
@@ -494,12 +520,6 @@ impl Race for YuanTiPureblood {
     }
 }
 
-impl Resistances for YuanTiPureblood {
-    fn immunities(&self) -> Vec<DamageType> {
-        vec![DamageType::Poison]
-    }
-}
-
 impl Trinkets for YuanTiPureblood {}
 
 impl fmt::Display for YuanTiPureblood {
@@ -575,7 +595,7 @@ mod tests {
     fn test_snapshot_features() {
         let mut rng = Pcg64::seed_from_u64(1);
         let yuan_ti = YuanTiPureblood::gen(&mut rng);
-        insta::assert_yaml_snapshot!(yuan_ti.features());
+        insta::assert_yaml_snapshot!(yuan_ti.features(1));
     }
 
     #[test]
@@ -602,10 +622,10 @@ mod tests {
     }
 
     #[test]
-    fn test_snapshot_immunities() {
+    fn test_snapshot_effects() {
         let mut rng = Pcg64::seed_from_u64(1);
         let yuan_ti = YuanTiPureblood::gen(&mut rng);
-        insta::assert_yaml_snapshot!(yuan_ti.immunities());
+        insta::assert_yaml_snapshot!(yuan_ti.effects(3));
     }
 
     #[test]
@@ -0,0 +1,390 @@
+#![deny(clippy::all)]
+#![warn(clippy::pedantic)]
+
+mod spell;
+
+use std::ops::RangeInclusive;
+
+use rand::{prelude::IteratorRandom, Rng};
+use serde::{Deserialize, Serialize};
+use stats::ability::{AbilityScoreType, AbilityScores};
+use strum::IntoEnumIterator;
+
+pub use spell::{CastingTime, Component, School, Spell, SpellList};
+
+/// Spell slots per spell level for a full caster at each character level
+const FULL_CASTER_SLOTS: [[usize; 9]; 20] = [
+    [2, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 0, 0, 0, 0, 0, 0, 0, 0],
+    [4, 2, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 2, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 1, 0, 0, 0, 0, 0],
+    [4, 3, 3, 2, 0, 0, 0, 0, 0],
+    [4, 3, 3, 3, 1, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 2, 1, 1],
+];
+
+/// How quickly a class gains spell slots
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CasterType {
+    /// Bards, Clerics, Druids, Sorcerers and Wizards
+    Full,
+    /// Paladins and Rangers, who start casting at 2nd level
+    Half,
+    /// Warlock Pact Magic, where all slots are the same level
+    Pact,
+    /// Eldritch Knights and Arcane Tricksters, who start casting at 3rd level
+    Third,
+}
+
+/// How a class decides which spells it can cast
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Preparation {
+    /// A fixed number of spells known at each level
+    Known([usize; 20]),
+    /// Spellcasting modifier + level spells prepared from the entire class list
+    Prepared,
+    /// Spellcasting modifier + half level spells prepared from the entire class list
+    PreparedHalfLevel,
+    /// Spellcasting modifier + level spells prepared from those written in a spellbook
+    Spellbook,
+}
+
+/// Details of how a class casts spells
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Spellcaster {
+    /// Ability used for spell attacks and saving throw DCs
+    pub ability: AbilityScoreType,
+    /// How quickly the class gains spell slots
+    pub caster_type: CasterType,
+    /// Cantrips known when the class starts casting, which increases by one at 4th and 10th level
+    /// (only at 10th level for third casters)
+    pub cantrips: usize,
+    /// Spell list the class chooses spells from
+    pub list: SpellList,
+    /// How the class decides which spells it can cast
+    pub preparation: Preparation,
+}
+
+impl Spellcaster {
+    /// Number of cantrips known at a given level
+    #[must_use]
+    pub fn cantrips_known(&self, level: u8) -> usize {
+        match self.caster_type {
+            _ if self.cantrips == 0 => 0,
+            CasterType::Third if level < 3 => 0,
+            CasterType::Third => self.cantrips + usize::from(level >= 10),
+            _ => self.cantrips + usize::from(level >= 4) + usize::from(level >= 10),
+        }
+    }
+
+    /// Number of spell slots of each spell level, starting at 1st, available at a given level
+    #[must_use]
+    pub fn spell_slots(&self, level: u8) -> Vec<usize> {
+        let level = usize::from(level.clamp(1, 20));
+        match self.caster_type {
+            CasterType::Full => trim_slots(FULL_CASTER_SLOTS[level - 1]),
+            CasterType::Half if level < 2 => vec![],
+            CasterType::Half => trim_slots(FULL_CASTER_SLOTS[level.div_ceil(2) - 1]),
+            CasterType::Third if level < 3 => vec![],
+            CasterType::Third => trim_slots(FULL_CASTER_SLOTS[level.div_ceil(3) - 1]),
+            CasterType::Pact => {
+                let slots = match level {
+                    1 => 1,
+                    2..=10 => 2,
+                    11..=16 => 3,
+                    _ => 4,
+                };
+                let slot_level = level.div_ceil(2).min(5);
+                (1..=slot_level)
+                    .map(|l| if l == slot_level { slots } else { 0 })
+                    .collect()
+            }
+        }
+    }
+
+    /// Highest level of spell that can be cast at a given level
+    #[must_use]
+    pub fn max_spell_level(&self, level: u8) -> usize {
+        self.spell_slots(level).len()
+    }
+
+    /// Difficulty class of saving throws against the character's spells
+    #[must_use]
+    pub fn save_dc(&self, ability_scores: &AbilityScores, proficiency_bonus: i16) -> i16 {
+        8 + self.attack_bonus(ability_scores, proficiency_bonus)
+    }
+
+    /// Bonus added to spell attack rolls
+    #[must_use]
+    pub fn attack_bonus(&self, ability_scores: &AbilityScores, proficiency_bonus: i16) -> i16 {
+        ability_scores.modifier(self.ability) + proficiency_bonus
+    }
+
+    /// Number of spells known or prepared at a given level
+    #[must_use]
+    pub fn spells_known(&self, level: u8, ability_scores: &AbilityScores) -> usize {
+        if self.max_spell_level(level) == 0 {
+            return 0;
+        }
+        let modifier = ability_scores.modifier(self.ability);
+        let prepared = |levels: i16| {
+            usize::try_from(modifier + levels)
+                .unwrap_or_default()
+                .max(1)
+        };
+        match self.preparation {
+            Preparation::Known(known) => known[usize::from(level.clamp(1, 20)) - 1],
+            Preparation::Prepared | Preparation::Spellbook => prepared(level.into()),
+            Preparation::PreparedHalfLevel => prepared((level / 2).into()),
+        }
+    }
+
+    /// Spells on the class list within a range of spell levels
+    fn available(&self, levels: RangeInclusive<usize>) -> impl Iterator<Item = Spell> {
+        let list = self.list;
+        Spell::iter()
+            .filter(move |s| levels.contains(&s.level().into()) && s.spell_lists().contains(&list))
+    }
+
+    /// Choose cantrips and spells for a character of the given level
+    pub fn gen(&self, rng: &mut impl Rng, level: u8, ability_scores: &AbilityScores) -> Spells {
+        let max_level = self.max_spell_level(level);
+        let cantrips = self
+            .available(0..=0)
+            .choose_multiple(rng, self.cantrips_known(level));
+        let known = self.spells_known(level, ability_scores);
+        let (spellbook, spells) = if self.preparation == Preparation::Spellbook {
+            // Six spells at 1st level, and two more each level after
+            let spellbook = self
+                .available(1..=max_level)
+                .choose_multiple(rng, 6 + 2 * usize::from(level.saturating_sub(1)));
+            let spells = spellbook.iter().copied().choose_multiple(rng, known);
+            (spellbook, spells)
+        } else {
+            let spells = self.available(1..=max_level).choose_multiple(rng, known);
+            (vec![], spells)
+        };
+        Spells {
+            cantrips: sorted(cantrips),
+            spellbook: sorted(spellbook),
+            spells: sorted(spells),
+        }
+    }
+}
+
+/// Spell slots for a character with levels in several classes, given each class's spellcasting and
+/// level in that class.
+///
+/// With Spellcasting from only one class, that class's own table is used. Otherwise levels are
+/// combined into a single multiclass spellcaster level (PHB p164). Pact Magic slots are kept
+/// separate and aren't included in the combined total.
+#[must_use]
+pub fn multiclass_spell_slots(casters: &[(Spellcaster, u8)]) -> Vec<usize> {
+    match casters {
+        [] => vec![],
+        [(caster, level)] => caster.spell_slots(*level),
+        _ => {
+            let level: usize = casters
+                .iter()
+                .map(|(caster, level)| {
+                    let level = usize::from(*level);
+                    match caster.caster_type {
+                        CasterType::Full => level,
+                        CasterType::Half => level / 2,
+                        CasterType::Pact => 0,
+                        CasterType::Third => level / 3,
+                    }
+                })
+                .sum();
+            match level {
+                0 => vec![],
+                _ => trim_slots(FULL_CASTER_SLOTS[level.min(20) - 1]),
+            }
+        }
+    }
+}
+
+/// Remove any spell levels without slots
+fn trim_slots(slots: [usize; 9]) -> Vec<usize> {
+    slots.into_iter().take_while(|&s| s > 0).collect()
+}
+
+/// Sort spells by level, then name
+fn sorted(mut spells: Vec<Spell>) -> Vec<Spell> {
+    spells.sort_by_key(|s| (s.level(), s.to_string()));
+    spells
+}
+
+/// Spells chosen for a character
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Spells {
+    /// Cantrips known
+    pub cantrips: Vec<Spell>,
+    /// Spells written in a spellbook, which prepared spells are chosen from
+    pub spellbook: Vec<Spell>,
+    /// Spells known or prepared, which can be cast using spell slots
+    pub spells: Vec<Spell>,
+}
+
+/// Trait for anything that grants the ability to cast spells
+pub trait Spellcasting {
+    /// Details of how spells are cast, if they can be
+    fn spellcasting(&self) -> Option<Spellcaster> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+
+    const WIZARD: Spellcaster = Spellcaster {
+        ability: AbilityScoreType::Intelligence,
+        caster_type: CasterType::Full,
+        cantrips: 3,
+        list: SpellList::Wizard,
+        preparation: Preparation::Spellbook,
+    };
+
+    fn ability_scores() -> AbilityScores {
+        AbilityScores(
+            AbilityScoreType::iter()
+                .map(|a| {
+                    (
+                        a,
+                        if a == AbilityScoreType::Intelligence {
+                            16
+                        } else {
+                            10
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_full_caster_slots() {
+        assert_eq!(WIZARD.spell_slots(1), vec![2]);
+        assert_eq!(WIZARD.spell_slots(5), vec![4, 3, 2]);
+        assert_eq!(WIZARD.spell_slots(20), vec![4, 3, 3, 3, 3, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_half_caster_slots() {
+        let paladin = Spellcaster {
+            caster_type: CasterType::Half,
+            cantrips: 0,
+            ..WIZARD
+        };
+        assert!(paladin.spell_slots(1).is_empty());
+        assert_eq!(paladin.spell_slots(2), vec![2]);
+        assert_eq!(paladin.spell_slots(5), vec![4, 2]);
+        assert_eq!(paladin.spell_slots(17), vec![4, 3, 3, 3, 1]);
+        assert_eq!(paladin.cantrips_known(20), 0);
+    }
+
+    #[test]
+    fn test_pact_slots() {
+        let warlock = Spellcaster {
+            caster_type: CasterType::Pact,
+            ..WIZARD
+        };
+        assert_eq!(warlock.spell_slots(1), vec![1]);
+        assert_eq!(warlock.spell_slots(5), vec![0, 0, 2]);
+        assert_eq!(warlock.spell_slots(20), vec![0, 0, 0, 0, 4]);
+    }
+
+    #[test]
+    fn test_third_caster_slots() {
+        let eldritch_knight = Spellcaster {
+            caster_type: CasterType::Third,
+            cantrips: 2,
+            ..WIZARD
+        };
+        assert!(eldritch_knight.spell_slots(2).is_empty());
+        assert_eq!(eldritch_knight.spell_slots(3), vec![2]);
+        assert_eq!(eldritch_knight.spell_slots(7), vec![4, 2]);
+        assert_eq!(eldritch_knight.spell_slots(20), vec![4, 3, 3, 1]);
+        assert_eq!(eldritch_knight.cantrips_known(2), 0);
+        assert_eq!(eldritch_knight.cantrips_known(4), 2);
+        assert_eq!(eldritch_knight.cantrips_known(10), 3);
+    }
+
+    #[test]
+    fn test_multiclass_spell_slots() {
+        let paladin = Spellcaster {
+            caster_type: CasterType::Half,
+            ..WIZARD
+        };
+        let warlock = Spellcaster {
+            caster_type: CasterType::Pact,
+            ..WIZARD
+        };
+        assert!(multiclass_spell_slots(&[]).is_empty());
+        assert_eq!(multiclass_spell_slots(&[(paladin, 3)]), vec![3]);
+        // Wizard 3 + Paladin 3 is a 4th level multiclass spellcaster
+        assert_eq!(
+            multiclass_spell_slots(&[(WIZARD, 3), (paladin, 3)]),
+            vec![4, 3]
+        );
+        // Paladin 1 adds nothing
+        assert_eq!(
+            multiclass_spell_slots(&[(WIZARD, 3), (paladin, 1)]),
+            vec![4, 2]
+        );
+        assert_eq!(
+            multiclass_spell_slots(&[(WIZARD, 3), (warlock, 2)]),
+            vec![4, 2]
+        );
+    }
+
+    #[test]
+    fn test_cantrips_known() {
+        assert_eq!(WIZARD.cantrips_known(1), 3);
+        assert_eq!(WIZARD.cantrips_known(4), 4);
+        assert_eq!(WIZARD.cantrips_known(10), 5);
+    }
+
+    #[test]
+    fn test_save_dc_and_attack_bonus() {
+        assert_eq!(WIZARD.save_dc(&ability_scores(), 2), 13);
+        assert_eq!(WIZARD.attack_bonus(&ability_scores(), 2), 5);
+    }
+
+    #[test]
+    fn test_gen_spellbook() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let spells = WIZARD.gen(&mut rng, 3, &ability_scores());
+        assert_eq!(spells.cantrips.len(), 3);
+        assert_eq!(spells.spellbook.len(), 10);
+        assert_eq!(spells.spells.len(), 6);
+        assert!(spells
+            .spells
+            .iter()
+            .all(|s| spells.spellbook.contains(s) && s.level() <= 2));
+        insta::assert_yaml_snapshot!(spells);
+    }
+
+    #[test]
+    fn test_spell_lists() {
+        for spell in Spell::iter() {
+            assert!(!spell.spell_lists().is_empty(), "{spell} has no spell list");
+        }
+    }
+}
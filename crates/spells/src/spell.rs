@@ -0,0 +1,2690 @@
+use std::fmt;
+
+use citation::{Book, Citation};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+/// Schools of magic, which help describe spells
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, PartialEq, Serialize)]
+pub enum School {
+    Abjuration,
+    Conjuration,
+    Divination,
+    Enchantment,
+    Evocation,
+    Illusion,
+    Necromancy,
+    Transmutation,
+}
+
+/// How long it takes to cast a spell
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CastingTime {
+    Action,
+    BonusAction,
+    Reaction,
+    Minutes(u16),
+    Hours(u16),
+}
+
+impl fmt::Display for CastingTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Action => write!(f, "1 action"),
+            Self::BonusAction => write!(f, "1 bonus action"),
+            Self::Reaction => write!(f, "1 reaction"),
+            Self::Minutes(1) => write!(f, "1 minute"),
+            Self::Minutes(m) => write!(f, "{m} minutes"),
+            Self::Hours(1) => write!(f, "1 hour"),
+            Self::Hours(h) => write!(f, "{h} hours"),
+        }
+    }
+}
+
+/// Physical requirements for casting a spell
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, PartialEq, Serialize)]
+pub enum Component {
+    #[strum(serialize = "V")]
+    Verbal,
+    #[strum(serialize = "S")]
+    Somatic,
+    #[strum(serialize = "M")]
+    Material,
+}
+
+/// Class spell lists a spell can appear on
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
+pub enum SpellList {
+    Bard,
+    Cleric,
+    Druid,
+    Paladin,
+    Ranger,
+    Sorcerer,
+    Warlock,
+    Wizard,
+}
+
+/// Spells available to characters
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub enum Spell {
+    #[strum(serialize = "Acid Splash")]
+    AcidSplash,
+    Aid,
+    Alarm,
+    #[strum(serialize = "Animal Friendship")]
+    AnimalFriendship,
+    #[strum(serialize = "Animal Shapes")]
+    AnimalShapes,
+    #[strum(serialize = "Animate Dead")]
+    AnimateDead,
+    #[strum(serialize = "Animate Objects")]
+    AnimateObjects,
+    #[strum(serialize = "Antipathy/Sympathy")]
+    AntipathySympathy,
+    #[strum(serialize = "Arcane Eye")]
+    ArcaneEye,
+    #[strum(serialize = "Armor of Agathys")]
+    ArmorOfAgathys,
+    #[strum(serialize = "Astral Projection")]
+    AstralProjection,
+    #[strum(serialize = "Aura of Life")]
+    AuraOfLife,
+    #[strum(serialize = "Aura of Purity")]
+    AuraOfPurity,
+    #[strum(serialize = "Aura of Vitality")]
+    AuraOfVitality,
+    Bane,
+    #[strum(serialize = "Banishing Smite")]
+    BanishingSmite,
+    Banishment,
+    Barkskin,
+    #[strum(serialize = "Beacon of Hope")]
+    BeaconOfHope,
+    #[strum(serialize = "Bestow Curse")]
+    BestowCurse,
+    #[strum(serialize = "Blade Barrier")]
+    BladeBarrier,
+    #[strum(serialize = "Blade Ward")]
+    BladeWard,
+    Bless,
+    Blight,
+    #[strum(serialize = "Blindness/Deafness")]
+    BlindnessDeafness,
+    Blink,
+    Blur,
+    #[strum(serialize = "Branding Smite")]
+    BrandingSmite,
+    #[strum(serialize = "Burning Hands")]
+    BurningHands,
+    #[strum(serialize = "Call Lightning")]
+    CallLightning,
+    #[strum(serialize = "Calm Emotions")]
+    CalmEmotions,
+    #[strum(serialize = "Chain Lightning")]
+    ChainLightning,
+    #[strum(serialize = "Charm Person")]
+    CharmPerson,
+    #[strum(serialize = "Chill Touch")]
+    ChillTouch,
+    #[strum(serialize = "Chromatic Orb")]
+    ChromaticOrb,
+    #[strum(serialize = "Circle of Death")]
+    CircleOfDeath,
+    #[strum(serialize = "Circle of Power")]
+    CircleOfPower,
+    Clairvoyance,
+    Cloudkill,
+    #[strum(serialize = "Color Spray")]
+    ColorSpray,
+    Command,
+    Commune,
+    #[strum(serialize = "Commune with Nature")]
+    CommuneWithNature,
+    #[strum(serialize = "Compelled Duel")]
+    CompelledDuel,
+    #[strum(serialize = "Comprehend Languages")]
+    ComprehendLanguages,
+    Compulsion,
+    #[strum(serialize = "Cone of Cold")]
+    ConeOfCold,
+    Confusion,
+    #[strum(serialize = "Conjure Animals")]
+    ConjureAnimals,
+    #[strum(serialize = "Conjure Barrage")]
+    ConjureBarrage,
+    #[strum(serialize = "Conjure Elemental")]
+    ConjureElemental,
+    #[strum(serialize = "Conjure Fey")]
+    ConjureFey,
+    #[strum(serialize = "Conjure Woodland Beings")]
+    ConjureWoodlandBeings,
+    Counterspell,
+    #[strum(serialize = "Create or Destroy Water")]
+    CreateOrDestroyWater,
+    #[strum(serialize = "Crusader's Mantle")]
+    CrusadersMantle,
+    #[strum(serialize = "Cure Wounds")]
+    CureWounds,
+    #[strum(serialize = "Dancing Lights")]
+    DancingLights,
+    Darkness,
+    Darkvision,
+    Daylight,
+    #[strum(serialize = "Death Ward")]
+    DeathWard,
+    #[strum(serialize = "Destructive Wave")]
+    DestructiveWave,
+    #[strum(serialize = "Detect Magic")]
+    DetectMagic,
+    #[strum(serialize = "Dimension Door")]
+    DimensionDoor,
+    #[strum(serialize = "Disguise Self")]
+    DisguiseSelf,
+    Disintegrate,
+    #[strum(serialize = "Dispel Evil and Good")]
+    DispelEvilAndGood,
+    #[strum(serialize = "Dispel Magic")]
+    DispelMagic,
+    #[strum(serialize = "Dissonant Whispers")]
+    DissonantWhispers,
+    Divination,
+    #[strum(serialize = "Divine Favor")]
+    DivineFavor,
+    #[strum(serialize = "Divine Word")]
+    DivineWord,
+    #[strum(serialize = "Dominate Monster")]
+    DominateMonster,
+    #[strum(serialize = "Dominate Person")]
+    DominatePerson,
+    Dream,
+    Druidcraft,
+    Earthquake,
+    #[strum(serialize = "Eldritch Blast")]
+    EldritchBlast,
+    #[strum(serialize = "Elemental Weapon")]
+    ElementalWeapon,
+    #[strum(serialize = "Enhance Ability")]
+    EnhanceAbility,
+    #[strum(serialize = "Ensnaring Strike")]
+    EnsnaringStrike,
+    Entangle,
+    Etherealness,
+    #[strum(serialize = "Evard's Black Tentacles")]
+    EvardsBlackTentacles,
+    #[strum(serialize = "Expeditious Retreat")]
+    ExpeditiousRetreat,
+    Eyebite,
+    #[strum(serialize = "Faerie Fire")]
+    FaerieFire,
+    #[strum(serialize = "False Life")]
+    FalseLife,
+    Fear,
+    #[strum(serialize = "Feather Fall")]
+    FeatherFall,
+    Feeblemind,
+    #[strum(serialize = "Find Familiar")]
+    FindFamiliar,
+    #[strum(serialize = "Find Steed")]
+    FindSteed,
+    #[strum(serialize = "Find the Path")]
+    FindThePath,
+    #[strum(serialize = "Finger of Death")]
+    FingerOfDeath,
+    #[strum(serialize = "Fire Bolt")]
+    FireBolt,
+    #[strum(serialize = "Fire Shield")]
+    FireShield,
+    #[strum(serialize = "Fire Storm")]
+    FireStorm,
+    Fireball,
+    #[strum(serialize = "Flame Strike")]
+    FlameStrike,
+    #[strum(serialize = "Flaming Sphere")]
+    FlamingSphere,
+    Fly,
+    #[strum(serialize = "Fog Cloud")]
+    FogCloud,
+    Forcecage,
+    Foresight,
+    #[strum(serialize = "Freedom of Movement")]
+    FreedomOfMovement,
+    Friends,
+    #[strum(serialize = "Gaseous Form")]
+    GaseousForm,
+    Gate,
+    Glibness,
+    #[strum(serialize = "Globe of Invulnerability")]
+    GlobeOfInvulnerability,
+    #[strum(serialize = "Glyph of Warding")]
+    GlyphOfWarding,
+    Goodberry,
+    #[strum(serialize = "Grasping Vine")]
+    GraspingVine,
+    #[strum(serialize = "Greater Invisibility")]
+    GreaterInvisibility,
+    #[strum(serialize = "Greater Restoration")]
+    GreaterRestoration,
+    #[strum(serialize = "Guardian of Faith")]
+    GuardianOfFaith,
+    Guidance,
+    #[strum(serialize = "Guiding Bolt")]
+    GuidingBolt,
+    #[strum(serialize = "Hail of Thorns")]
+    HailOfThorns,
+    Hallow,
+    Harm,
+    Haste,
+    Heal,
+    #[strum(serialize = "Healing Word")]
+    HealingWord,
+    #[strum(serialize = "Heat Metal")]
+    HeatMetal,
+    #[strum(serialize = "Hellish Rebuke")]
+    HellishRebuke,
+    #[strum(serialize = "Heroes' Feast")]
+    HeroesFeast,
+    Heroism,
+    Hex,
+    #[strum(serialize = "Hold Monster")]
+    HoldMonster,
+    #[strum(serialize = "Hold Person")]
+    HoldPerson,
+    #[strum(serialize = "Holy Aura")]
+    HolyAura,
+    #[strum(serialize = "Hunger of Hadar")]
+    HungerOfHadar,
+    #[strum(serialize = "Hunter's Mark")]
+    HuntersMark,
+    #[strum(serialize = "Hypnotic Pattern")]
+    HypnoticPattern,
+    #[strum(serialize = "Ice Storm")]
+    IceStorm,
+    Identify,
+    #[strum(serialize = "Inflict Wounds")]
+    InflictWounds,
+    #[strum(serialize = "Insect Plague")]
+    InsectPlague,
+    Invisibility,
+    Jump,
+    Knock,
+    #[strum(serialize = "Legend Lore")]
+    LegendLore,
+    #[strum(serialize = "Lesser Restoration")]
+    LesserRestoration,
+    Levitate,
+    Light,
+    #[strum(serialize = "Lightning Arrow")]
+    LightningArrow,
+    #[strum(serialize = "Lightning Bolt")]
+    LightningBolt,
+    #[strum(serialize = "Locate Creature")]
+    LocateCreature,
+    #[strum(serialize = "Locate Object")]
+    LocateObject,
+    Longstrider,
+    #[strum(serialize = "Mage Armor")]
+    MageArmor,
+    #[strum(serialize = "Mage Hand")]
+    MageHand,
+    #[strum(serialize = "Magic Circle")]
+    MagicCircle,
+    #[strum(serialize = "Magic Missile")]
+    MagicMissile,
+    #[strum(serialize = "Magic Weapon")]
+    MagicWeapon,
+    #[strum(serialize = "Mass Cure Wounds")]
+    MassCureWounds,
+    #[strum(serialize = "Mass Heal")]
+    MassHeal,
+    #[strum(serialize = "Mass Healing Word")]
+    MassHealingWord,
+    #[strum(serialize = "Mass Suggestion")]
+    MassSuggestion,
+    Mending,
+    Message,
+    #[strum(serialize = "Meteor Swarm")]
+    MeteorSwarm,
+    #[strum(serialize = "Mind Blank")]
+    MindBlank,
+    #[strum(serialize = "Minor Illusion")]
+    MinorIllusion,
+    #[strum(serialize = "Mirage Arcane")]
+    MirageArcane,
+    #[strum(serialize = "Mirror Image")]
+    MirrorImage,
+    #[strum(serialize = "Misty Step")]
+    MistyStep,
+    Moonbeam,
+    Nondetection,
+    #[strum(serialize = "Otto's Irresistible Dance")]
+    OttosIrresistibleDance,
+    #[strum(serialize = "Pass without Trace")]
+    PassWithoutTrace,
+    #[strum(serialize = "Phantasmal Killer")]
+    PhantasmalKiller,
+    #[strum(serialize = "Plane Shift")]
+    PlaneShift,
+    #[strum(serialize = "Plant Growth")]
+    PlantGrowth,
+    #[strum(serialize = "Poison Spray")]
+    PoisonSpray,
+    Polymorph,
+    #[strum(serialize = "Power Word Kill")]
+    PowerWordKill,
+    #[strum(serialize = "Power Word Stun")]
+    PowerWordStun,
+    #[strum(serialize = "Prayer of Healing")]
+    PrayerOfHealing,
+    Prestidigitation,
+    #[strum(serialize = "Prismatic Spray")]
+    PrismaticSpray,
+    #[strum(serialize = "Produce Flame")]
+    ProduceFlame,
+    #[strum(serialize = "Protection from Energy")]
+    ProtectionFromEnergy,
+    #[strum(serialize = "Protection from Evil and Good")]
+    ProtectionFromEvilAndGood,
+    #[strum(serialize = "Protection from Poison")]
+    ProtectionFromPoison,
+    #[strum(serialize = "Raise Dead")]
+    RaiseDead,
+    #[strum(serialize = "Ray of Frost")]
+    RayOfFrost,
+    Regenerate,
+    #[strum(serialize = "Remove Curse")]
+    RemoveCurse,
+    Resistance,
+    Resurrection,
+    #[strum(serialize = "Reverse Gravity")]
+    ReverseGravity,
+    Revivify,
+    #[strum(serialize = "Sacred Flame")]
+    SacredFlame,
+    Sanctuary,
+    #[strum(serialize = "Scorching Ray")]
+    ScorchingRay,
+    Scrying,
+    #[strum(serialize = "Searing Smite")]
+    SearingSmite,
+    Seeming,
+    Sending,
+    Shapechange,
+    Shatter,
+    Shield,
+    #[strum(serialize = "Shield of Faith")]
+    ShieldOfFaith,
+    Shillelagh,
+    #[strum(serialize = "Shocking Grasp")]
+    ShockingGrasp,
+    Silence,
+    Sleep,
+    #[strum(serialize = "Sleet Storm")]
+    SleetStorm,
+    Slow,
+    #[strum(serialize = "Spare the Dying")]
+    SpareTheDying,
+    #[strum(serialize = "Speak with Animals")]
+    SpeakWithAnimals,
+    #[strum(serialize = "Speak with Dead")]
+    SpeakWithDead,
+    #[strum(serialize = "Spike Growth")]
+    SpikeGrowth,
+    #[strum(serialize = "Spirit Guardians")]
+    SpiritGuardians,
+    #[strum(serialize = "Spiritual Weapon")]
+    SpiritualWeapon,
+    #[strum(serialize = "Staggering Smite")]
+    StaggeringSmite,
+    #[strum(serialize = "Stinking Cloud")]
+    StinkingCloud,
+    Stoneskin,
+    #[strum(serialize = "Storm of Vengeance")]
+    StormOfVengeance,
+    Suggestion,
+    Sunbeam,
+    Sunburst,
+    #[strum(serialize = "Swift Quiver")]
+    SwiftQuiver,
+    #[strum(serialize = "Tasha's Hideous Laughter")]
+    TashasHideousLaughter,
+    Telekinesis,
+    Teleport,
+    Thaumaturgy,
+    #[strum(serialize = "Thorn Whip")]
+    ThornWhip,
+    #[strum(serialize = "Thunderous Smite")]
+    ThunderousSmite,
+    Thunderwave,
+    #[strum(serialize = "Time Stop")]
+    TimeStop,
+    Tongues,
+    #[strum(serialize = "Transport via Plants")]
+    TransportViaPlants,
+    #[strum(serialize = "Tree Stride")]
+    TreeStride,
+    #[strum(serialize = "True Polymorph")]
+    TruePolymorph,
+    #[strum(serialize = "True Resurrection")]
+    TrueResurrection,
+    #[strum(serialize = "True Seeing")]
+    TrueSeeing,
+    #[strum(serialize = "True Strike")]
+    TrueStrike,
+    #[strum(serialize = "Vampiric Touch")]
+    VampiricTouch,
+    #[strum(serialize = "Vicious Mockery")]
+    ViciousMockery,
+    #[strum(serialize = "Wall of Fire")]
+    WallOfFire,
+    #[strum(serialize = "Wall of Stone")]
+    WallOfStone,
+    #[strum(serialize = "Water Breathing")]
+    WaterBreathing,
+    #[strum(serialize = "Wind Walk")]
+    WindWalk,
+    #[strum(serialize = "Wind Wall")]
+    WindWall,
+    Wish,
+    #[strum(serialize = "Witch Bolt")]
+    WitchBolt,
+    #[strum(serialize = "Wrathful Smite")]
+    WrathfulSmite,
+    #[strum(serialize = "Zone of Truth")]
+    ZoneOfTruth,
+}
+
+impl Spell {
+    /// Level of the spell, where 0 is a cantrip
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn level(self) -> u8 {
+        match self {
+            Self::AcidSplash
+            | Self::BladeWard
+            | Self::ChillTouch
+            | Self::DancingLights
+            | Self::Druidcraft
+            | Self::EldritchBlast
+            | Self::FireBolt
+            | Self::Friends
+            | Self::Guidance
+            | Self::Light
+            | Self::MageHand
+            | Self::Mending
+            | Self::Message
+            | Self::MinorIllusion
+            | Self::PoisonSpray
+            | Self::Prestidigitation
+            | Self::ProduceFlame
+            | Self::RayOfFrost
+            | Self::Resistance
+            | Self::SacredFlame
+            | Self::Shillelagh
+            | Self::ShockingGrasp
+            | Self::SpareTheDying
+            | Self::Thaumaturgy
+            | Self::ThornWhip
+            | Self::TrueStrike
+            | Self::ViciousMockery => 0,
+            Self::Alarm
+            | Self::AnimalFriendship
+            | Self::ArmorOfAgathys
+            | Self::Bane
+            | Self::Bless
+            | Self::BurningHands
+            | Self::CharmPerson
+            | Self::ChromaticOrb
+            | Self::ColorSpray
+            | Self::Command
+            | Self::CompelledDuel
+            | Self::ComprehendLanguages
+            | Self::CreateOrDestroyWater
+            | Self::CureWounds
+            | Self::DetectMagic
+            | Self::DisguiseSelf
+            | Self::DissonantWhispers
+            | Self::DivineFavor
+            | Self::EnsnaringStrike
+            | Self::Entangle
+            | Self::ExpeditiousRetreat
+            | Self::FaerieFire
+            | Self::FalseLife
+            | Self::FeatherFall
+            | Self::FindFamiliar
+            | Self::FogCloud
+            | Self::Goodberry
+            | Self::GuidingBolt
+            | Self::HailOfThorns
+            | Self::HealingWord
+            | Self::HellishRebuke
+            | Self::Heroism
+            | Self::Hex
+            | Self::HuntersMark
+            | Self::Identify
+            | Self::InflictWounds
+            | Self::Jump
+            | Self::Longstrider
+            | Self::MageArmor
+            | Self::MagicMissile
+            | Self::ProtectionFromEvilAndGood
+            | Self::Sanctuary
+            | Self::SearingSmite
+            | Self::Shield
+            | Self::ShieldOfFaith
+            | Self::Sleep
+            | Self::SpeakWithAnimals
+            | Self::TashasHideousLaughter
+            | Self::ThunderousSmite
+            | Self::Thunderwave
+            | Self::WitchBolt
+            | Self::WrathfulSmite => 1,
+            Self::Aid
+            | Self::Barkskin
+            | Self::BlindnessDeafness
+            | Self::Blur
+            | Self::BrandingSmite
+            | Self::CalmEmotions
+            | Self::Darkness
+            | Self::Darkvision
+            | Self::EnhanceAbility
+            | Self::FindSteed
+            | Self::FlamingSphere
+            | Self::HeatMetal
+            | Self::HoldPerson
+            | Self::Invisibility
+            | Self::Knock
+            | Self::LesserRestoration
+            | Self::Levitate
+            | Self::LocateObject
+            | Self::MagicWeapon
+            | Self::MirrorImage
+            | Self::MistyStep
+            | Self::Moonbeam
+            | Self::PassWithoutTrace
+            | Self::PrayerOfHealing
+            | Self::ProtectionFromPoison
+            | Self::ScorchingRay
+            | Self::Shatter
+            | Self::Silence
+            | Self::SpikeGrowth
+            | Self::SpiritualWeapon
+            | Self::Suggestion
+            | Self::ZoneOfTruth => 2,
+            Self::AnimateDead
+            | Self::AuraOfVitality
+            | Self::BeaconOfHope
+            | Self::BestowCurse
+            | Self::Blink
+            | Self::CallLightning
+            | Self::Clairvoyance
+            | Self::ConjureAnimals
+            | Self::ConjureBarrage
+            | Self::Counterspell
+            | Self::CrusadersMantle
+            | Self::Daylight
+            | Self::DispelMagic
+            | Self::ElementalWeapon
+            | Self::Fear
+            | Self::Fireball
+            | Self::Fly
+            | Self::GaseousForm
+            | Self::GlyphOfWarding
+            | Self::Haste
+            | Self::HungerOfHadar
+            | Self::HypnoticPattern
+            | Self::LightningArrow
+            | Self::LightningBolt
+            | Self::MagicCircle
+            | Self::MassHealingWord
+            | Self::Nondetection
+            | Self::PlantGrowth
+            | Self::ProtectionFromEnergy
+            | Self::RemoveCurse
+            | Self::Revivify
+            | Self::Sending
+            | Self::SleetStorm
+            | Self::Slow
+            | Self::SpeakWithDead
+            | Self::SpiritGuardians
+            | Self::StinkingCloud
+            | Self::Tongues
+            | Self::VampiricTouch
+            | Self::WaterBreathing
+            | Self::WindWall => 3,
+            Self::ArcaneEye
+            | Self::AuraOfLife
+            | Self::AuraOfPurity
+            | Self::Banishment
+            | Self::Blight
+            | Self::Compulsion
+            | Self::Confusion
+            | Self::ConjureWoodlandBeings
+            | Self::DeathWard
+            | Self::DimensionDoor
+            | Self::Divination
+            | Self::EvardsBlackTentacles
+            | Self::FireShield
+            | Self::FreedomOfMovement
+            | Self::GraspingVine
+            | Self::GreaterInvisibility
+            | Self::GuardianOfFaith
+            | Self::IceStorm
+            | Self::LocateCreature
+            | Self::PhantasmalKiller
+            | Self::Polymorph
+            | Self::StaggeringSmite
+            | Self::Stoneskin
+            | Self::WallOfFire => 4,
+            Self::AnimateObjects
+            | Self::BanishingSmite
+            | Self::CircleOfPower
+            | Self::Cloudkill
+            | Self::Commune
+            | Self::CommuneWithNature
+            | Self::ConeOfCold
+            | Self::ConjureElemental
+            | Self::DestructiveWave
+            | Self::DispelEvilAndGood
+            | Self::DominatePerson
+            | Self::Dream
+            | Self::FlameStrike
+            | Self::GreaterRestoration
+            | Self::Hallow
+            | Self::HoldMonster
+            | Self::InsectPlague
+            | Self::LegendLore
+            | Self::MassCureWounds
+            | Self::RaiseDead
+            | Self::Scrying
+            | Self::Seeming
+            | Self::SwiftQuiver
+            | Self::Telekinesis
+            | Self::TreeStride
+            | Self::WallOfStone => 5,
+            Self::BladeBarrier
+            | Self::ChainLightning
+            | Self::CircleOfDeath
+            | Self::ConjureFey
+            | Self::Disintegrate
+            | Self::Eyebite
+            | Self::FindThePath
+            | Self::GlobeOfInvulnerability
+            | Self::Harm
+            | Self::Heal
+            | Self::HeroesFeast
+            | Self::MassSuggestion
+            | Self::OttosIrresistibleDance
+            | Self::Sunbeam
+            | Self::TransportViaPlants
+            | Self::TrueSeeing
+            | Self::WindWalk => 6,
+            Self::DivineWord
+            | Self::Etherealness
+            | Self::FingerOfDeath
+            | Self::FireStorm
+            | Self::Forcecage
+            | Self::MirageArcane
+            | Self::PlaneShift
+            | Self::PrismaticSpray
+            | Self::Regenerate
+            | Self::Resurrection
+            | Self::ReverseGravity
+            | Self::Teleport => 7,
+            Self::AnimalShapes
+            | Self::AntipathySympathy
+            | Self::DominateMonster
+            | Self::Earthquake
+            | Self::Feeblemind
+            | Self::Glibness
+            | Self::HolyAura
+            | Self::MindBlank
+            | Self::PowerWordStun
+            | Self::Sunburst => 8,
+            Self::AstralProjection
+            | Self::Foresight
+            | Self::Gate
+            | Self::MassHeal
+            | Self::MeteorSwarm
+            | Self::PowerWordKill
+            | Self::Shapechange
+            | Self::StormOfVengeance
+            | Self::TimeStop
+            | Self::TruePolymorph
+            | Self::TrueResurrection
+            | Self::Wish => 9,
+        }
+    }
+
+    /// School of magic the spell belongs to
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn school(self) -> School {
+        match self {
+            Self::Aid
+            | Self::Alarm
+            | Self::ArmorOfAgathys
+            | Self::AuraOfLife
+            | Self::AuraOfPurity
+            | Self::BanishingSmite
+            | Self::Banishment
+            | Self::BeaconOfHope
+            | Self::BladeWard
+            | Self::CircleOfPower
+            | Self::Counterspell
+            | Self::DeathWard
+            | Self::DispelEvilAndGood
+            | Self::DispelMagic
+            | Self::FreedomOfMovement
+            | Self::GlobeOfInvulnerability
+            | Self::GlyphOfWarding
+            | Self::GreaterRestoration
+            | Self::HolyAura
+            | Self::LesserRestoration
+            | Self::MageArmor
+            | Self::MagicCircle
+            | Self::MindBlank
+            | Self::Nondetection
+            | Self::PassWithoutTrace
+            | Self::ProtectionFromEnergy
+            | Self::ProtectionFromEvilAndGood
+            | Self::ProtectionFromPoison
+            | Self::RemoveCurse
+            | Self::Resistance
+            | Self::Sanctuary
+            | Self::Shield
+            | Self::ShieldOfFaith
+            | Self::Stoneskin => School::Abjuration,
+            Self::AcidSplash
+            | Self::CallLightning
+            | Self::Cloudkill
+            | Self::ConjureAnimals
+            | Self::ConjureBarrage
+            | Self::ConjureElemental
+            | Self::ConjureFey
+            | Self::ConjureWoodlandBeings
+            | Self::DimensionDoor
+            | Self::EnsnaringStrike
+            | Self::Entangle
+            | Self::EvardsBlackTentacles
+            | Self::FindFamiliar
+            | Self::FindSteed
+            | Self::FlamingSphere
+            | Self::FogCloud
+            | Self::Gate
+            | Self::GraspingVine
+            | Self::GuardianOfFaith
+            | Self::HailOfThorns
+            | Self::HeroesFeast
+            | Self::HungerOfHadar
+            | Self::InsectPlague
+            | Self::MageHand
+            | Self::MistyStep
+            | Self::PlaneShift
+            | Self::PoisonSpray
+            | Self::ProduceFlame
+            | Self::SleetStorm
+            | Self::SpiritGuardians
+            | Self::StinkingCloud
+            | Self::StormOfVengeance
+            | Self::Teleport
+            | Self::TransportViaPlants
+            | Self::TreeStride
+            | Self::Wish => School::Conjuration,
+            Self::ArcaneEye
+            | Self::Clairvoyance
+            | Self::Commune
+            | Self::CommuneWithNature
+            | Self::ComprehendLanguages
+            | Self::DetectMagic
+            | Self::Divination
+            | Self::FindThePath
+            | Self::Foresight
+            | Self::Guidance
+            | Self::HuntersMark
+            | Self::Identify
+            | Self::LegendLore
+            | Self::LocateCreature
+            | Self::LocateObject
+            | Self::Scrying
+            | Self::SpeakWithAnimals
+            | Self::Tongues
+            | Self::TrueSeeing
+            | Self::TrueStrike => School::Divination,
+            Self::AnimalFriendship
+            | Self::AntipathySympathy
+            | Self::Bane
+            | Self::Bless
+            | Self::CalmEmotions
+            | Self::CharmPerson
+            | Self::Command
+            | Self::CompelledDuel
+            | Self::Compulsion
+            | Self::Confusion
+            | Self::DissonantWhispers
+            | Self::DominateMonster
+            | Self::DominatePerson
+            | Self::Feeblemind
+            | Self::Friends
+            | Self::Heroism
+            | Self::Hex
+            | Self::HoldMonster
+            | Self::HoldPerson
+            | Self::MassSuggestion
+            | Self::OttosIrresistibleDance
+            | Self::PowerWordKill
+            | Self::PowerWordStun
+            | Self::Sleep
+            | Self::StaggeringSmite
+            | Self::Suggestion
+            | Self::TashasHideousLaughter
+            | Self::ViciousMockery
+            | Self::ZoneOfTruth => School::Enchantment,
+            Self::AuraOfVitality
+            | Self::BladeBarrier
+            | Self::BrandingSmite
+            | Self::BurningHands
+            | Self::ChainLightning
+            | Self::ChromaticOrb
+            | Self::ConeOfCold
+            | Self::CrusadersMantle
+            | Self::CureWounds
+            | Self::DancingLights
+            | Self::Darkness
+            | Self::Daylight
+            | Self::DestructiveWave
+            | Self::DivineFavor
+            | Self::DivineWord
+            | Self::Earthquake
+            | Self::EldritchBlast
+            | Self::FaerieFire
+            | Self::FireBolt
+            | Self::FireShield
+            | Self::FireStorm
+            | Self::Fireball
+            | Self::FlameStrike
+            | Self::Forcecage
+            | Self::GuidingBolt
+            | Self::Hallow
+            | Self::Heal
+            | Self::HealingWord
+            | Self::HellishRebuke
+            | Self::IceStorm
+            | Self::Light
+            | Self::LightningBolt
+            | Self::MagicMissile
+            | Self::MassCureWounds
+            | Self::MassHeal
+            | Self::MassHealingWord
+            | Self::MeteorSwarm
+            | Self::Moonbeam
+            | Self::PrayerOfHealing
+            | Self::PrismaticSpray
+            | Self::RayOfFrost
+            | Self::SacredFlame
+            | Self::ScorchingRay
+            | Self::SearingSmite
+            | Self::Sending
+            | Self::Shatter
+            | Self::ShockingGrasp
+            | Self::SpiritualWeapon
+            | Self::Sunbeam
+            | Self::Sunburst
+            | Self::ThunderousSmite
+            | Self::Thunderwave
+            | Self::WallOfFire
+            | Self::WallOfStone
+            | Self::WindWall
+            | Self::WitchBolt
+            | Self::WrathfulSmite => School::Evocation,
+            Self::Blur
+            | Self::ColorSpray
+            | Self::DisguiseSelf
+            | Self::Dream
+            | Self::Fear
+            | Self::GreaterInvisibility
+            | Self::HypnoticPattern
+            | Self::Invisibility
+            | Self::MinorIllusion
+            | Self::MirageArcane
+            | Self::MirrorImage
+            | Self::PhantasmalKiller
+            | Self::Seeming
+            | Self::Silence => School::Illusion,
+            Self::AnimateDead
+            | Self::AstralProjection
+            | Self::BestowCurse
+            | Self::Blight
+            | Self::BlindnessDeafness
+            | Self::ChillTouch
+            | Self::CircleOfDeath
+            | Self::Eyebite
+            | Self::FalseLife
+            | Self::FingerOfDeath
+            | Self::Harm
+            | Self::InflictWounds
+            | Self::RaiseDead
+            | Self::Resurrection
+            | Self::Revivify
+            | Self::SpareTheDying
+            | Self::SpeakWithDead
+            | Self::TrueResurrection
+            | Self::VampiricTouch => School::Necromancy,
+            Self::AnimalShapes
+            | Self::AnimateObjects
+            | Self::Barkskin
+            | Self::Blink
+            | Self::CreateOrDestroyWater
+            | Self::Darkvision
+            | Self::Disintegrate
+            | Self::Druidcraft
+            | Self::ElementalWeapon
+            | Self::EnhanceAbility
+            | Self::Etherealness
+            | Self::ExpeditiousRetreat
+            | Self::FeatherFall
+            | Self::Fly
+            | Self::GaseousForm
+            | Self::Glibness
+            | Self::Goodberry
+            | Self::Haste
+            | Self::HeatMetal
+            | Self::Jump
+            | Self::Knock
+            | Self::Levitate
+            | Self::LightningArrow
+            | Self::Longstrider
+            | Self::MagicWeapon
+            | Self::Mending
+            | Self::Message
+            | Self::PlantGrowth
+            | Self::Polymorph
+            | Self::Prestidigitation
+            | Self::Regenerate
+            | Self::ReverseGravity
+            | Self::Shapechange
+            | Self::Shillelagh
+            | Self::Slow
+            | Self::SpikeGrowth
+            | Self::SwiftQuiver
+            | Self::Telekinesis
+            | Self::Thaumaturgy
+            | Self::ThornWhip
+            | Self::TimeStop
+            | Self::TruePolymorph
+            | Self::WaterBreathing
+            | Self::WindWalk => School::Transmutation,
+        }
+    }
+
+    /// How long the spell takes to cast
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn casting_time(self) -> CastingTime {
+        match self {
+            Self::AcidSplash
+            | Self::Aid
+            | Self::AnimalFriendship
+            | Self::AnimalShapes
+            | Self::AnimateObjects
+            | Self::ArcaneEye
+            | Self::ArmorOfAgathys
+            | Self::AuraOfLife
+            | Self::AuraOfPurity
+            | Self::AuraOfVitality
+            | Self::Bane
+            | Self::Banishment
+            | Self::Barkskin
+            | Self::BeaconOfHope
+            | Self::BestowCurse
+            | Self::BladeBarrier
+            | Self::BladeWard
+            | Self::Bless
+            | Self::Blight
+            | Self::BlindnessDeafness
+            | Self::Blink
+            | Self::Blur
+            | Self::BurningHands
+            | Self::CallLightning
+            | Self::CalmEmotions
+            | Self::ChainLightning
+            | Self::CharmPerson
+            | Self::ChillTouch
+            | Self::ChromaticOrb
+            | Self::CircleOfDeath
+            | Self::CircleOfPower
+            | Self::Cloudkill
+            | Self::ColorSpray
+            | Self::Command
+            | Self::ComprehendLanguages
+            | Self::Compulsion
+            | Self::ConeOfCold
+            | Self::Confusion
+            | Self::ConjureAnimals
+            | Self::ConjureBarrage
+            | Self::ConjureWoodlandBeings
+            | Self::CreateOrDestroyWater
+            | Self::CrusadersMantle
+            | Self::CureWounds
+            | Self::DancingLights
+            | Self::Darkness
+            | Self::Darkvision
+            | Self::Daylight
+            | Self::DeathWard
+            | Self::DestructiveWave
+            | Self::DetectMagic
+            | Self::DimensionDoor
+            | Self::DisguiseSelf
+            | Self::Disintegrate
+            | Self::DispelEvilAndGood
+            | Self::DispelMagic
+            | Self::DissonantWhispers
+            | Self::Divination
+            | Self::DominateMonster
+            | Self::DominatePerson
+            | Self::Druidcraft
+            | Self::Earthquake
+            | Self::EldritchBlast
+            | Self::ElementalWeapon
+            | Self::EnhanceAbility
+            | Self::Entangle
+            | Self::Etherealness
+            | Self::EvardsBlackTentacles
+            | Self::Eyebite
+            | Self::FaerieFire
+            | Self::FalseLife
+            | Self::Fear
+            | Self::Feeblemind
+            | Self::FingerOfDeath
+            | Self::FireBolt
+            | Self::FireShield
+            | Self::FireStorm
+            | Self::Fireball
+            | Self::FlameStrike
+            | Self::FlamingSphere
+            | Self::Fly
+            | Self::FogCloud
+            | Self::Forcecage
+            | Self::FreedomOfMovement
+            | Self::Friends
+            | Self::GaseousForm
+            | Self::Gate
+            | Self::Glibness
+            | Self::GlobeOfInvulnerability
+            | Self::Goodberry
+            | Self::GreaterInvisibility
+            | Self::GreaterRestoration
+            | Self::GuardianOfFaith
+            | Self::Guidance
+            | Self::GuidingBolt
+            | Self::Harm
+            | Self::Haste
+            | Self::Heal
+            | Self::HeatMetal
+            | Self::Heroism
+            | Self::HoldMonster
+            | Self::HoldPerson
+            | Self::HolyAura
+            | Self::HungerOfHadar
+            | Self::HypnoticPattern
+            | Self::IceStorm
+            | Self::InflictWounds
+            | Self::InsectPlague
+            | Self::Invisibility
+            | Self::Jump
+            | Self::Knock
+            | Self::LesserRestoration
+            | Self::Levitate
+            | Self::Light
+            | Self::LightningBolt
+            | Self::LocateCreature
+            | Self::LocateObject
+            | Self::Longstrider
+            | Self::MageArmor
+            | Self::MageHand
+            | Self::MagicMissile
+            | Self::MassCureWounds
+            | Self::MassHeal
+            | Self::MassSuggestion
+            | Self::Message
+            | Self::MeteorSwarm
+            | Self::MindBlank
+            | Self::MinorIllusion
+            | Self::MirrorImage
+            | Self::Moonbeam
+            | Self::Nondetection
+            | Self::OttosIrresistibleDance
+            | Self::PassWithoutTrace
+            | Self::PhantasmalKiller
+            | Self::PlaneShift
+            | Self::PlantGrowth
+            | Self::PoisonSpray
+            | Self::Polymorph
+            | Self::PowerWordKill
+            | Self::PowerWordStun
+            | Self::Prestidigitation
+            | Self::PrismaticSpray
+            | Self::ProduceFlame
+            | Self::ProtectionFromEnergy
+            | Self::ProtectionFromEvilAndGood
+            | Self::ProtectionFromPoison
+            | Self::RayOfFrost
+            | Self::RemoveCurse
+            | Self::Resistance
+            | Self::ReverseGravity
+            | Self::Revivify
+            | Self::SacredFlame
+            | Self::ScorchingRay
+            | Self::Seeming
+            | Self::Sending
+            | Self::Shapechange
+            | Self::Shatter
+            | Self::ShockingGrasp
+            | Self::Silence
+            | Self::Sleep
+            | Self::SleetStorm
+            | Self::Slow
+            | Self::SpareTheDying
+            | Self::SpeakWithAnimals
+            | Self::SpeakWithDead
+            | Self::SpikeGrowth
+            | Self::SpiritGuardians
+            | Self::StinkingCloud
+            | Self::Stoneskin
+            | Self::StormOfVengeance
+            | Self::Suggestion
+            | Self::Sunbeam
+            | Self::Sunburst
+            | Self::TashasHideousLaughter
+            | Self::Telekinesis
+            | Self::Teleport
+            | Self::Thaumaturgy
+            | Self::ThornWhip
+            | Self::Thunderwave
+            | Self::TimeStop
+            | Self::Tongues
+            | Self::TransportViaPlants
+            | Self::TreeStride
+            | Self::TruePolymorph
+            | Self::TrueSeeing
+            | Self::TrueStrike
+            | Self::VampiricTouch
+            | Self::ViciousMockery
+            | Self::WallOfFire
+            | Self::WallOfStone
+            | Self::WaterBreathing
+            | Self::WindWall
+            | Self::Wish
+            | Self::WitchBolt
+            | Self::ZoneOfTruth => CastingTime::Action,
+            Self::Alarm
+            | Self::AnimateDead
+            | Self::Commune
+            | Self::CommuneWithNature
+            | Self::ConjureElemental
+            | Self::ConjureFey
+            | Self::Dream
+            | Self::FindThePath
+            | Self::Foresight
+            | Self::Identify
+            | Self::MagicCircle
+            | Self::Mending
+            | Self::Regenerate
+            | Self::WindWalk => CastingTime::Minutes(1),
+            Self::AntipathySympathy
+            | Self::AstralProjection
+            | Self::FindFamiliar
+            | Self::GlyphOfWarding
+            | Self::RaiseDead
+            | Self::Resurrection
+            | Self::TrueResurrection => CastingTime::Hours(1),
+            Self::BanishingSmite
+            | Self::BrandingSmite
+            | Self::CompelledDuel
+            | Self::DivineFavor
+            | Self::DivineWord
+            | Self::EnsnaringStrike
+            | Self::ExpeditiousRetreat
+            | Self::GraspingVine
+            | Self::HailOfThorns
+            | Self::HealingWord
+            | Self::Hex
+            | Self::HuntersMark
+            | Self::LightningArrow
+            | Self::MagicWeapon
+            | Self::MassHealingWord
+            | Self::MistyStep
+            | Self::Sanctuary
+            | Self::SearingSmite
+            | Self::ShieldOfFaith
+            | Self::Shillelagh
+            | Self::SpiritualWeapon
+            | Self::StaggeringSmite
+            | Self::SwiftQuiver
+            | Self::ThunderousSmite
+            | Self::WrathfulSmite => CastingTime::BonusAction,
+            Self::Clairvoyance
+            | Self::FindSteed
+            | Self::HeroesFeast
+            | Self::LegendLore
+            | Self::MirageArcane
+            | Self::PrayerOfHealing
+            | Self::Scrying => CastingTime::Minutes(10),
+            Self::Counterspell | Self::FeatherFall | Self::HellishRebuke | Self::Shield => {
+                CastingTime::Reaction
+            }
+            Self::Hallow => CastingTime::Hours(24),
+        }
+    }
+
+    /// Range of the spell, or the area it affects when centered on the caster
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn range(self) -> &'static str {
+        match self {
+            Self::AcidSplash
+            | Self::AntipathySympathy
+            | Self::Banishment
+            | Self::CalmEmotions
+            | Self::Command
+            | Self::ConjureAnimals
+            | Self::ConjureWoodlandBeings
+            | Self::Counterspell
+            | Self::Darkness
+            | Self::Daylight
+            | Self::Disintegrate
+            | Self::DissonantWhispers
+            | Self::DominateMonster
+            | Self::DominatePerson
+            | Self::FaerieFire
+            | Self::FeatherFall
+            | Self::FingerOfDeath
+            | Self::FlameStrike
+            | Self::FlamingSphere
+            | Self::Gate
+            | Self::Harm
+            | Self::Heal
+            | Self::HealingWord
+            | Self::HeatMetal
+            | Self::HellishRebuke
+            | Self::HoldPerson
+            | Self::Knock
+            | Self::Levitate
+            | Self::MassCureWounds
+            | Self::MassHeal
+            | Self::MassHealingWord
+            | Self::MassSuggestion
+            | Self::Polymorph
+            | Self::PowerWordKill
+            | Self::PowerWordStun
+            | Self::RayOfFrost
+            | Self::SacredFlame
+            | Self::Shatter
+            | Self::ShieldOfFaith
+            | Self::SpiritualWeapon
+            | Self::Telekinesis
+            | Self::ViciousMockery
+            | Self::ZoneOfTruth => "60 feet",
+            Self::Aid
+            | Self::Alarm
+            | Self::AnimalFriendship
+            | Self::AnimalShapes
+            | Self::ArcaneEye
+            | Self::Bane
+            | Self::BeaconOfHope
+            | Self::Bless
+            | Self::Blight
+            | Self::BlindnessDeafness
+            | Self::CharmPerson
+            | Self::CompelledDuel
+            | Self::Compulsion
+            | Self::CreateOrDestroyWater
+            | Self::DivineWord
+            | Self::Druidcraft
+            | Self::FindSteed
+            | Self::GraspingVine
+            | Self::GuardianOfFaith
+            | Self::Haste
+            | Self::HeroesFeast
+            | Self::MageHand
+            | Self::MinorIllusion
+            | Self::OttosIrresistibleDance
+            | Self::PrayerOfHealing
+            | Self::Sanctuary
+            | Self::Seeming
+            | Self::Suggestion
+            | Self::TashasHideousLaughter
+            | Self::Thaumaturgy
+            | Self::ThornWhip
+            | Self::TruePolymorph
+            | Self::TrueStrike
+            | Self::WaterBreathing
+            | Self::WindWalk
+            | Self::WitchBolt => "30 feet",
+            Self::AnimateDead
+            | Self::AstralProjection
+            | Self::FindFamiliar
+            | Self::MagicCircle
+            | Self::PoisonSpray
+            | Self::Prestidigitation
+            | Self::SpeakWithDead
+            | Self::Teleport
+            | Self::TransportViaPlants => "10 feet",
+            Self::AnimateObjects
+            | Self::CallLightning
+            | Self::ChillTouch
+            | Self::Cloudkill
+            | Self::DancingLights
+            | Self::DispelMagic
+            | Self::EldritchBlast
+            | Self::FireBolt
+            | Self::FogCloud
+            | Self::GuidingBolt
+            | Self::HypnoticPattern
+            | Self::MagicMissile
+            | Self::Message
+            | Self::Moonbeam
+            | Self::PhantasmalKiller
+            | Self::ScorchingRay
+            | Self::Silence
+            | Self::Slow
+            | Self::WallOfFire
+            | Self::WallOfStone
+            | Self::WindWall => "120 feet",
+            Self::ArmorOfAgathys
+            | Self::BanishingSmite
+            | Self::BladeWard
+            | Self::Blink
+            | Self::Blur
+            | Self::BrandingSmite
+            | Self::Commune
+            | Self::CommuneWithNature
+            | Self::ComprehendLanguages
+            | Self::CrusadersMantle
+            | Self::DetectMagic
+            | Self::DisguiseSelf
+            | Self::DispelEvilAndGood
+            | Self::Divination
+            | Self::DivineFavor
+            | Self::EnsnaringStrike
+            | Self::Etherealness
+            | Self::ExpeditiousRetreat
+            | Self::Eyebite
+            | Self::FalseLife
+            | Self::FindThePath
+            | Self::FireShield
+            | Self::Friends
+            | Self::Glibness
+            | Self::HailOfThorns
+            | Self::HolyAura
+            | Self::LegendLore
+            | Self::LightningArrow
+            | Self::LocateCreature
+            | Self::LocateObject
+            | Self::MirrorImage
+            | Self::MistyStep
+            | Self::PassWithoutTrace
+            | Self::ProduceFlame
+            | Self::Scrying
+            | Self::SearingSmite
+            | Self::Shapechange
+            | Self::Shield
+            | Self::SpeakWithAnimals
+            | Self::StaggeringSmite
+            | Self::ThunderousSmite
+            | Self::TimeStop
+            | Self::TreeStride
+            | Self::VampiricTouch
+            | Self::Wish
+            | Self::WrathfulSmite => "Self",
+            Self::AuraOfLife
+            | Self::AuraOfPurity
+            | Self::AuraOfVitality
+            | Self::CircleOfPower
+            | Self::DestructiveWave => "Self (30-foot radius)",
+            Self::Barkskin
+            | Self::BestowCurse
+            | Self::CureWounds
+            | Self::Darkvision
+            | Self::DeathWard
+            | Self::ElementalWeapon
+            | Self::EnhanceAbility
+            | Self::Fly
+            | Self::Foresight
+            | Self::FreedomOfMovement
+            | Self::GaseousForm
+            | Self::GlyphOfWarding
+            | Self::Goodberry
+            | Self::GreaterInvisibility
+            | Self::GreaterRestoration
+            | Self::Guidance
+            | Self::Hallow
+            | Self::Heroism
+            | Self::Identify
+            | Self::InflictWounds
+            | Self::Invisibility
+            | Self::Jump
+            | Self::LesserRestoration
+            | Self::Light
+            | Self::Longstrider
+            | Self::MageArmor
+            | Self::MagicWeapon
+            | Self::Mending
+            | Self::MindBlank
+            | Self::Nondetection
+            | Self::PlaneShift
+            | Self::ProtectionFromEnergy
+            | Self::ProtectionFromEvilAndGood
+            | Self::ProtectionFromPoison
+            | Self::RaiseDead
+            | Self::Regenerate
+            | Self::RemoveCurse
+            | Self::Resistance
+            | Self::Resurrection
+            | Self::Revivify
+            | Self::Shillelagh
+            | Self::ShockingGrasp
+            | Self::SpareTheDying
+            | Self::Stoneskin
+            | Self::SwiftQuiver
+            | Self::Tongues
+            | Self::TrueResurrection
+            | Self::TrueSeeing => "Touch",
+            Self::BladeBarrier
+            | Self::ChromaticOrb
+            | Self::Confusion
+            | Self::ConjureElemental
+            | Self::ConjureFey
+            | Self::Entangle
+            | Self::EvardsBlackTentacles
+            | Self::Hex
+            | Self::HoldMonster
+            | Self::HuntersMark
+            | Self::Sleep
+            | Self::StinkingCloud => "90 feet",
+            Self::BurningHands | Self::ColorSpray => "Self (15-foot cone)",
+            Self::ChainLightning
+            | Self::CircleOfDeath
+            | Self::Feeblemind
+            | Self::FireStorm
+            | Self::Fireball
+            | Self::HungerOfHadar
+            | Self::PlantGrowth
+            | Self::SleetStorm
+            | Self::SpikeGrowth
+            | Self::Sunburst => "150 feet",
+            Self::Clairvoyance | Self::MeteorSwarm => "1 mile",
+            Self::ConeOfCold | Self::ConjureBarrage | Self::PrismaticSpray => "Self (60-foot cone)",
+            Self::DimensionDoor | Self::Earthquake => "500 feet",
+            Self::Dream => "Special",
+            Self::Fear => "Self (30-foot cone)",
+            Self::Forcecage | Self::ReverseGravity => "100 feet",
+            Self::GlobeOfInvulnerability => "Self (10-foot radius)",
+            Self::IceStorm | Self::InsectPlague => "300 feet",
+            Self::LightningBolt => "Self (100-foot line)",
+            Self::MirageArcane | Self::StormOfVengeance => "Sight",
+            Self::Sending => "Unlimited",
+            Self::SpiritGuardians => "Self (15-foot radius)",
+            Self::Sunbeam => "Self (60-foot line)",
+            Self::Thunderwave => "Self (15-foot cube)",
+        }
+    }
+
+    /// Components needed to cast the spell
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn components(self) -> Vec<Component> {
+        match self {
+            Self::Aid
+            | Self::Alarm
+            | Self::AnimalFriendship
+            | Self::AnimateDead
+            | Self::AntipathySympathy
+            | Self::ArcaneEye
+            | Self::ArmorOfAgathys
+            | Self::AstralProjection
+            | Self::Bane
+            | Self::Banishment
+            | Self::Barkskin
+            | Self::Bless
+            | Self::ChainLightning
+            | Self::ChromaticOrb
+            | Self::CircleOfDeath
+            | Self::Clairvoyance
+            | Self::ColorSpray
+            | Self::Commune
+            | Self::ComprehendLanguages
+            | Self::ConeOfCold
+            | Self::Confusion
+            | Self::ConjureBarrage
+            | Self::ConjureElemental
+            | Self::ConjureWoodlandBeings
+            | Self::CreateOrDestroyWater
+            | Self::DancingLights
+            | Self::Darkvision
+            | Self::Disintegrate
+            | Self::DispelEvilAndGood
+            | Self::Divination
+            | Self::Dream
+            | Self::Earthquake
+            | Self::EnhanceAbility
+            | Self::EvardsBlackTentacles
+            | Self::FalseLife
+            | Self::Fear
+            | Self::Feeblemind
+            | Self::FindFamiliar
+            | Self::FindThePath
+            | Self::FireShield
+            | Self::Fireball
+            | Self::FlameStrike
+            | Self::FlamingSphere
+            | Self::Fly
+            | Self::Forcecage
+            | Self::Foresight
+            | Self::FreedomOfMovement
+            | Self::GaseousForm
+            | Self::Gate
+            | Self::GlobeOfInvulnerability
+            | Self::GlyphOfWarding
+            | Self::Goodberry
+            | Self::GreaterRestoration
+            | Self::Hallow
+            | Self::Haste
+            | Self::HeatMetal
+            | Self::HeroesFeast
+            | Self::Hex
+            | Self::HoldMonster
+            | Self::HoldPerson
+            | Self::HolyAura
+            | Self::HungerOfHadar
+            | Self::IceStorm
+            | Self::Identify
+            | Self::InsectPlague
+            | Self::Invisibility
+            | Self::Jump
+            | Self::LegendLore
+            | Self::Levitate
+            | Self::LightningBolt
+            | Self::LocateCreature
+            | Self::LocateObject
+            | Self::Longstrider
+            | Self::MageArmor
+            | Self::MagicCircle
+            | Self::Mending
+            | Self::Message
+            | Self::Moonbeam
+            | Self::Nondetection
+            | Self::PassWithoutTrace
+            | Self::PlaneShift
+            | Self::Polymorph
+            | Self::ProtectionFromEvilAndGood
+            | Self::RaiseDead
+            | Self::Regenerate
+            | Self::Resistance
+            | Self::Resurrection
+            | Self::ReverseGravity
+            | Self::Revivify
+            | Self::Sanctuary
+            | Self::Scrying
+            | Self::Sending
+            | Self::Shapechange
+            | Self::Shatter
+            | Self::ShieldOfFaith
+            | Self::Shillelagh
+            | Self::Sleep
+            | Self::SleetStorm
+            | Self::Slow
+            | Self::SpeakWithDead
+            | Self::SpikeGrowth
+            | Self::SpiritGuardians
+            | Self::StinkingCloud
+            | Self::Stoneskin
+            | Self::Sunbeam
+            | Self::Sunburst
+            | Self::SwiftQuiver
+            | Self::TashasHideousLaughter
+            | Self::ThornWhip
+            | Self::TruePolymorph
+            | Self::TrueResurrection
+            | Self::TrueSeeing
+            | Self::WallOfFire
+            | Self::WallOfStone
+            | Self::WaterBreathing
+            | Self::WindWalk
+            | Self::WindWall
+            | Self::WitchBolt => vec![Component::Verbal, Component::Somatic, Component::Material],
+            Self::Friends | Self::HypnoticPattern | Self::MinorIllusion => {
+                vec![Component::Somatic, Component::Material]
+            }
+            Self::Darkness
+            | Self::FeatherFall
+            | Self::Light
+            | Self::MassSuggestion
+            | Self::Suggestion
+            | Self::Tongues => vec![Component::Verbal, Component::Material],
+            Self::AcidSplash
+            | Self::AnimalShapes
+            | Self::AnimateObjects
+            | Self::BeaconOfHope
+            | Self::BestowCurse
+            | Self::BladeBarrier
+            | Self::BladeWard
+            | Self::Blight
+            | Self::Blink
+            | Self::BurningHands
+            | Self::CallLightning
+            | Self::CalmEmotions
+            | Self::CharmPerson
+            | Self::ChillTouch
+            | Self::Cloudkill
+            | Self::CommuneWithNature
+            | Self::Compulsion
+            | Self::ConjureAnimals
+            | Self::ConjureFey
+            | Self::CureWounds
+            | Self::Daylight
+            | Self::DeathWard
+            | Self::DetectMagic
+            | Self::DisguiseSelf
+            | Self::DispelMagic
+            | Self::DivineFavor
+            | Self::DominateMonster
+            | Self::DominatePerson
+            | Self::Druidcraft
+            | Self::EldritchBlast
+            | Self::ElementalWeapon
+            | Self::Entangle
+            | Self::Etherealness
+            | Self::ExpeditiousRetreat
+            | Self::Eyebite
+            | Self::FindSteed
+            | Self::FingerOfDeath
+            | Self::FireBolt
+            | Self::FireStorm
+            | Self::FogCloud
+            | Self::GraspingVine
+            | Self::GreaterInvisibility
+            | Self::Guidance
+            | Self::GuidingBolt
+            | Self::Harm
+            | Self::Heal
+            | Self::HellishRebuke
+            | Self::Heroism
+            | Self::InflictWounds
+            | Self::LesserRestoration
+            | Self::LightningArrow
+            | Self::MageHand
+            | Self::MagicMissile
+            | Self::MagicWeapon
+            | Self::MassCureWounds
+            | Self::MassHeal
+            | Self::MeteorSwarm
+            | Self::MindBlank
+            | Self::MirageArcane
+            | Self::MirrorImage
+            | Self::PhantasmalKiller
+            | Self::PlantGrowth
+            | Self::PoisonSpray
+            | Self::Prestidigitation
+            | Self::PrismaticSpray
+            | Self::ProduceFlame
+            | Self::ProtectionFromEnergy
+            | Self::ProtectionFromPoison
+            | Self::RayOfFrost
+            | Self::RemoveCurse
+            | Self::SacredFlame
+            | Self::ScorchingRay
+            | Self::Seeming
+            | Self::Shield
+            | Self::ShockingGrasp
+            | Self::Silence
+            | Self::SpareTheDying
+            | Self::SpeakWithAnimals
+            | Self::SpiritualWeapon
+            | Self::StormOfVengeance
+            | Self::Telekinesis
+            | Self::Thunderwave
+            | Self::TransportViaPlants
+            | Self::TreeStride
+            | Self::VampiricTouch
+            | Self::ZoneOfTruth => vec![Component::Verbal, Component::Somatic],
+            Self::Counterspell | Self::TrueStrike => vec![Component::Somatic],
+            Self::AuraOfLife
+            | Self::AuraOfPurity
+            | Self::AuraOfVitality
+            | Self::BanishingSmite
+            | Self::BlindnessDeafness
+            | Self::Blur
+            | Self::BrandingSmite
+            | Self::CircleOfPower
+            | Self::Command
+            | Self::CompelledDuel
+            | Self::CrusadersMantle
+            | Self::DestructiveWave
+            | Self::DimensionDoor
+            | Self::DissonantWhispers
+            | Self::DivineWord
+            | Self::EnsnaringStrike
+            | Self::FaerieFire
+            | Self::Glibness
+            | Self::GuardianOfFaith
+            | Self::HailOfThorns
+            | Self::HealingWord
+            | Self::HuntersMark
+            | Self::Knock
+            | Self::MassHealingWord
+            | Self::MistyStep
+            | Self::OttosIrresistibleDance
+            | Self::PowerWordKill
+            | Self::PowerWordStun
+            | Self::PrayerOfHealing
+            | Self::SearingSmite
+            | Self::StaggeringSmite
+            | Self::Teleport
+            | Self::Thaumaturgy
+            | Self::ThunderousSmite
+            | Self::TimeStop
+            | Self::ViciousMockery
+            | Self::Wish
+            | Self::WrathfulSmite => vec![Component::Verbal],
+        }
+    }
+
+    /// How long the spell lasts
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn duration(self) -> &'static str {
+        match self {
+            Self::AcidSplash
+            | Self::AnimateDead
+            | Self::Blight
+            | Self::BurningHands
+            | Self::ChainLightning
+            | Self::ChromaticOrb
+            | Self::CircleOfDeath
+            | Self::CommuneWithNature
+            | Self::ConeOfCold
+            | Self::ConjureBarrage
+            | Self::Counterspell
+            | Self::CreateOrDestroyWater
+            | Self::CureWounds
+            | Self::DestructiveWave
+            | Self::DimensionDoor
+            | Self::Disintegrate
+            | Self::DispelMagic
+            | Self::DissonantWhispers
+            | Self::Divination
+            | Self::DivineWord
+            | Self::Druidcraft
+            | Self::EldritchBlast
+            | Self::Feeblemind
+            | Self::FindFamiliar
+            | Self::FindSteed
+            | Self::FingerOfDeath
+            | Self::FireBolt
+            | Self::FireStorm
+            | Self::Fireball
+            | Self::FlameStrike
+            | Self::Goodberry
+            | Self::GreaterRestoration
+            | Self::Harm
+            | Self::Heal
+            | Self::HealingWord
+            | Self::HellishRebuke
+            | Self::HeroesFeast
+            | Self::IceStorm
+            | Self::Identify
+            | Self::InflictWounds
+            | Self::Knock
+            | Self::LegendLore
+            | Self::LesserRestoration
+            | Self::LightningBolt
+            | Self::MagicMissile
+            | Self::MassCureWounds
+            | Self::MassHeal
+            | Self::MassHealingWord
+            | Self::Mending
+            | Self::MeteorSwarm
+            | Self::MistyStep
+            | Self::PlaneShift
+            | Self::PlantGrowth
+            | Self::PoisonSpray
+            | Self::PowerWordKill
+            | Self::PowerWordStun
+            | Self::PrayerOfHealing
+            | Self::PrismaticSpray
+            | Self::RaiseDead
+            | Self::RayOfFrost
+            | Self::RemoveCurse
+            | Self::Resurrection
+            | Self::Revivify
+            | Self::SacredFlame
+            | Self::ScorchingRay
+            | Self::Shatter
+            | Self::ShockingGrasp
+            | Self::SpareTheDying
+            | Self::Sunburst
+            | Self::Teleport
+            | Self::ThornWhip
+            | Self::Thunderwave
+            | Self::TimeStop
+            | Self::TrueResurrection
+            | Self::ViciousMockery
+            | Self::Wish => "Instantaneous",
+            Self::Aid
+            | Self::Alarm
+            | Self::Darkvision
+            | Self::DeathWard
+            | Self::Dream
+            | Self::Foresight
+            | Self::GuardianOfFaith
+            | Self::MageArmor
+            | Self::Nondetection
+            | Self::Seeming
+            | Self::WindWalk => "8 hours",
+            Self::AnimalFriendship
+            | Self::MassSuggestion
+            | Self::MindBlank
+            | Self::WaterBreathing => "24 hours",
+            Self::AnimalShapes => "Concentration, up to 24 hours",
+            Self::AnimateObjects
+            | Self::AuraOfVitality
+            | Self::Bane
+            | Self::BanishingSmite
+            | Self::Banishment
+            | Self::BeaconOfHope
+            | Self::BestowCurse
+            | Self::Bless
+            | Self::Blur
+            | Self::BrandingSmite
+            | Self::CalmEmotions
+            | Self::CompelledDuel
+            | Self::Compulsion
+            | Self::Confusion
+            | Self::CrusadersMantle
+            | Self::DancingLights
+            | Self::DispelEvilAndGood
+            | Self::DivineFavor
+            | Self::DominatePerson
+            | Self::Earthquake
+            | Self::EnsnaringStrike
+            | Self::Entangle
+            | Self::EvardsBlackTentacles
+            | Self::Eyebite
+            | Self::FaerieFire
+            | Self::Fear
+            | Self::FlamingSphere
+            | Self::Friends
+            | Self::Gate
+            | Self::GlobeOfInvulnerability
+            | Self::GraspingVine
+            | Self::GreaterInvisibility
+            | Self::Guidance
+            | Self::HailOfThorns
+            | Self::Haste
+            | Self::HeatMetal
+            | Self::Heroism
+            | Self::HoldMonster
+            | Self::HoldPerson
+            | Self::HolyAura
+            | Self::HungerOfHadar
+            | Self::HypnoticPattern
+            | Self::LightningArrow
+            | Self::Moonbeam
+            | Self::OttosIrresistibleDance
+            | Self::PhantasmalKiller
+            | Self::Resistance
+            | Self::ReverseGravity
+            | Self::SearingSmite
+            | Self::SleetStorm
+            | Self::Slow
+            | Self::StaggeringSmite
+            | Self::StinkingCloud
+            | Self::StormOfVengeance
+            | Self::Sunbeam
+            | Self::SwiftQuiver
+            | Self::TashasHideousLaughter
+            | Self::ThunderousSmite
+            | Self::TreeStride
+            | Self::VampiricTouch
+            | Self::WallOfFire
+            | Self::WindWall
+            | Self::WitchBolt
+            | Self::WrathfulSmite => "Concentration, up to 1 minute",
+            Self::AntipathySympathy | Self::MirageArcane => "10 days",
+            Self::ArcaneEye
+            | Self::Barkskin
+            | Self::ConjureAnimals
+            | Self::ConjureElemental
+            | Self::ConjureFey
+            | Self::ConjureWoodlandBeings
+            | Self::DominateMonster
+            | Self::ElementalWeapon
+            | Self::EnhanceAbility
+            | Self::FogCloud
+            | Self::GaseousForm
+            | Self::Hex
+            | Self::HuntersMark
+            | Self::Invisibility
+            | Self::LocateCreature
+            | Self::MagicWeapon
+            | Self::PassWithoutTrace
+            | Self::Polymorph
+            | Self::ProtectionFromEnergy
+            | Self::Shapechange
+            | Self::Stoneskin
+            | Self::TruePolymorph => "Concentration, up to 1 hour",
+            Self::ArmorOfAgathys
+            | Self::CharmPerson
+            | Self::ComprehendLanguages
+            | Self::Daylight
+            | Self::DisguiseSelf
+            | Self::FalseLife
+            | Self::Forcecage
+            | Self::FreedomOfMovement
+            | Self::Glibness
+            | Self::Light
+            | Self::Longstrider
+            | Self::MagicCircle
+            | Self::ProtectionFromPoison
+            | Self::Regenerate
+            | Self::Tongues
+            | Self::TrueSeeing => "1 hour",
+            Self::AstralProjection => "Special",
+            Self::AuraOfLife
+            | Self::AuraOfPurity
+            | Self::BladeBarrier
+            | Self::CallLightning
+            | Self::CircleOfPower
+            | Self::Clairvoyance
+            | Self::Cloudkill
+            | Self::Darkness
+            | Self::DetectMagic
+            | Self::ExpeditiousRetreat
+            | Self::Fly
+            | Self::InsectPlague
+            | Self::Levitate
+            | Self::LocateObject
+            | Self::ProtectionFromEvilAndGood
+            | Self::Scrying
+            | Self::ShieldOfFaith
+            | Self::Silence
+            | Self::SpikeGrowth
+            | Self::SpiritGuardians
+            | Self::Telekinesis
+            | Self::WallOfStone => "Concentration, up to 10 minutes",
+            Self::BladeWard
+            | Self::ChillTouch
+            | Self::ColorSpray
+            | Self::Command
+            | Self::GuidingBolt
+            | Self::Message
+            | Self::Sending
+            | Self::Shield
+            | Self::TransportViaPlants => "1 round",
+            Self::BlindnessDeafness
+            | Self::Blink
+            | Self::Commune
+            | Self::FeatherFall
+            | Self::Jump
+            | Self::MageHand
+            | Self::MinorIllusion
+            | Self::MirrorImage
+            | Self::Sanctuary
+            | Self::Shillelagh
+            | Self::Sleep
+            | Self::SpiritualWeapon => "1 minute",
+            Self::Etherealness => "Up to 8 hours",
+            Self::FindThePath => "Concentration, up to 1 day",
+            Self::FireShield
+            | Self::ProduceFlame
+            | Self::SpeakWithAnimals
+            | Self::SpeakWithDead
+            | Self::ZoneOfTruth => "10 minutes",
+            Self::GlyphOfWarding => "Until dispelled or triggered",
+            Self::Hallow => "Until dispelled",
+            Self::Prestidigitation => "Up to 1 hour",
+            Self::Suggestion => "Concentration, up to 8 hours",
+            Self::Thaumaturgy => "Up to 1 minute",
+            Self::TrueStrike => "Concentration, up to 1 round",
+        }
+    }
+
+    /// Whether the spell can be cast as a ritual
+    #[must_use]
+    pub fn ritual(self) -> bool {
+        matches!(
+            self,
+            Self::Alarm
+                | Self::Commune
+                | Self::CommuneWithNature
+                | Self::ComprehendLanguages
+                | Self::DetectMagic
+                | Self::Divination
+                | Self::FindFamiliar
+                | Self::Identify
+                | Self::Silence
+                | Self::SpeakWithAnimals
+                | Self::WaterBreathing
+        )
+    }
+
+    /// Whether the caster has to maintain concentration on the spell
+    #[must_use]
+    pub fn concentration(self) -> bool {
+        self.duration().starts_with("Concentration")
+    }
+
+    /// Class spell lists the spell appears on
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn spell_lists(self) -> Vec<SpellList> {
+        match self {
+            Self::AcidSplash
+            | Self::Blink
+            | Self::Blur
+            | Self::BurningHands
+            | Self::ChainLightning
+            | Self::ChromaticOrb
+            | Self::Cloudkill
+            | Self::ColorSpray
+            | Self::ConeOfCold
+            | Self::Disintegrate
+            | Self::FalseLife
+            | Self::FireBolt
+            | Self::Fireball
+            | Self::GlobeOfInvulnerability
+            | Self::Haste
+            | Self::Levitate
+            | Self::LightningBolt
+            | Self::MageArmor
+            | Self::MagicMissile
+            | Self::MeteorSwarm
+            | Self::PrismaticSpray
+            | Self::RayOfFrost
+            | Self::ScorchingRay
+            | Self::Shield
+            | Self::ShockingGrasp
+            | Self::Slow
+            | Self::Telekinesis
+            | Self::TimeStop
+            | Self::Wish => vec![SpellList::Sorcerer, SpellList::Wizard],
+            Self::Aid
+            | Self::Bless
+            | Self::Command
+            | Self::DeathWard
+            | Self::DispelEvilAndGood
+            | Self::Revivify
+            | Self::ShieldOfFaith => vec![SpellList::Cleric, SpellList::Paladin],
+            Self::Alarm => vec![SpellList::Ranger, SpellList::Wizard],
+            Self::AnimalFriendship | Self::PlantGrowth | Self::SpeakWithAnimals => {
+                vec![SpellList::Bard, SpellList::Druid, SpellList::Ranger]
+            }
+            Self::AnimalShapes
+            | Self::CallLightning
+            | Self::Druidcraft
+            | Self::Entangle
+            | Self::Moonbeam
+            | Self::ProduceFlame
+            | Self::Shillelagh
+            | Self::StormOfVengeance
+            | Self::ThornWhip
+            | Self::TransportViaPlants
+            | Self::WindWalk => vec![SpellList::Druid],
+            Self::AnimateDead => vec![SpellList::Cleric, SpellList::Wizard],
+            Self::AnimateObjects
+            | Self::DancingLights
+            | Self::DisguiseSelf
+            | Self::DominatePerson
+            | Self::FeatherFall
+            | Self::GreaterInvisibility
+            | Self::Knock
+            | Self::Message
+            | Self::Seeming
+            | Self::Sleep
+            | Self::StinkingCloud
+            | Self::Teleport => vec![SpellList::Bard, SpellList::Sorcerer, SpellList::Wizard],
+            Self::AntipathySympathy
+            | Self::ConjureElemental
+            | Self::FlamingSphere
+            | Self::Shapechange => vec![SpellList::Druid, SpellList::Wizard],
+            Self::ArcaneEye
+            | Self::EvardsBlackTentacles
+            | Self::FindFamiliar
+            | Self::FireShield
+            | Self::PhantasmalKiller => vec![SpellList::Wizard],
+            Self::ArmorOfAgathys
+            | Self::EldritchBlast
+            | Self::HellishRebuke
+            | Self::Hex
+            | Self::HungerOfHadar => vec![SpellList::Warlock],
+            Self::AstralProjection => {
+                vec![SpellList::Cleric, SpellList::Warlock, SpellList::Wizard]
+            }
+            Self::AuraOfLife
+            | Self::AuraOfPurity
+            | Self::AuraOfVitality
+            | Self::BanishingSmite
+            | Self::BrandingSmite
+            | Self::CircleOfPower
+            | Self::CompelledDuel
+            | Self::CrusadersMantle
+            | Self::DestructiveWave
+            | Self::DivineFavor
+            | Self::ElementalWeapon
+            | Self::FindSteed
+            | Self::SearingSmite
+            | Self::StaggeringSmite
+            | Self::ThunderousSmite
+            | Self::WrathfulSmite => vec![SpellList::Paladin],
+            Self::Bane | Self::CalmEmotions | Self::Resurrection | Self::SpeakWithDead => {
+                vec![SpellList::Bard, SpellList::Cleric]
+            }
+            Self::Banishment => vec![
+                SpellList::Cleric,
+                SpellList::Paladin,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::Barkskin
+            | Self::CommuneWithNature
+            | Self::ConjureAnimals
+            | Self::ConjureWoodlandBeings
+            | Self::Goodberry
+            | Self::GraspingVine
+            | Self::PassWithoutTrace
+            | Self::SpikeGrowth
+            | Self::TreeStride
+            | Self::WindWall => vec![SpellList::Druid, SpellList::Ranger],
+            Self::BeaconOfHope
+            | Self::BladeBarrier
+            | Self::Commune
+            | Self::Divination
+            | Self::DivineWord
+            | Self::FlameStrike
+            | Self::GuardianOfFaith
+            | Self::GuidingBolt
+            | Self::Hallow
+            | Self::Harm
+            | Self::HolyAura
+            | Self::InflictWounds
+            | Self::MassHeal
+            | Self::MassHealingWord
+            | Self::PrayerOfHealing
+            | Self::SacredFlame
+            | Self::Sanctuary
+            | Self::SpareTheDying
+            | Self::SpiritGuardians
+            | Self::SpiritualWeapon
+            | Self::Thaumaturgy => vec![SpellList::Cleric],
+            Self::BestowCurse | Self::GlyphOfWarding | Self::LegendLore | Self::Sending => {
+                vec![SpellList::Bard, SpellList::Cleric, SpellList::Wizard]
+            }
+            Self::BladeWard
+            | Self::ComprehendLanguages
+            | Self::DimensionDoor
+            | Self::DominateMonster
+            | Self::Eyebite
+            | Self::Fear
+            | Self::Friends
+            | Self::HoldMonster
+            | Self::HypnoticPattern
+            | Self::Invisibility
+            | Self::MageHand
+            | Self::MassSuggestion
+            | Self::MinorIllusion
+            | Self::PowerWordKill
+            | Self::PowerWordStun
+            | Self::Prestidigitation
+            | Self::Shatter
+            | Self::Suggestion
+            | Self::TrueStrike => vec![
+                SpellList::Bard,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::Blight | Self::PoisonSpray => vec![
+                SpellList::Druid,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::BlindnessDeafness | Self::Clairvoyance | Self::Light => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Sorcerer,
+                SpellList::Wizard,
+            ],
+            Self::CharmPerson => vec![
+                SpellList::Bard,
+                SpellList::Druid,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::ChillTouch
+            | Self::CircleOfDeath
+            | Self::Counterspell
+            | Self::Darkness
+            | Self::ExpeditiousRetreat
+            | Self::FingerOfDeath
+            | Self::Fly
+            | Self::GaseousForm
+            | Self::MirrorImage
+            | Self::MistyStep
+            | Self::WitchBolt => vec![SpellList::Sorcerer, SpellList::Warlock, SpellList::Wizard],
+            Self::Compulsion | Self::DissonantWhispers | Self::ViciousMockery => {
+                vec![SpellList::Bard]
+            }
+            Self::Confusion | Self::Polymorph | Self::Thunderwave => vec![
+                SpellList::Bard,
+                SpellList::Druid,
+                SpellList::Sorcerer,
+                SpellList::Wizard,
+            ],
+            Self::ConjureBarrage
+            | Self::EnsnaringStrike
+            | Self::HailOfThorns
+            | Self::HuntersMark
+            | Self::LightningArrow
+            | Self::SwiftQuiver => vec![SpellList::Ranger],
+            Self::ConjureFey => vec![SpellList::Druid, SpellList::Warlock],
+            Self::CreateOrDestroyWater
+            | Self::Guidance
+            | Self::Heal
+            | Self::HeroesFeast
+            | Self::Resistance
+            | Self::TrueResurrection => vec![SpellList::Cleric, SpellList::Druid],
+            Self::CureWounds | Self::LesserRestoration => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Paladin,
+                SpellList::Ranger,
+            ],
+            Self::Darkvision
+            | Self::FogCloud
+            | Self::Jump
+            | Self::Stoneskin
+            | Self::WaterBreathing => vec![
+                SpellList::Druid,
+                SpellList::Ranger,
+                SpellList::Sorcerer,
+                SpellList::Wizard,
+            ],
+            Self::Daylight => vec![
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Paladin,
+                SpellList::Ranger,
+                SpellList::Sorcerer,
+            ],
+            Self::DetectMagic => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Paladin,
+                SpellList::Ranger,
+                SpellList::Sorcerer,
+                SpellList::Wizard,
+            ],
+            Self::DispelMagic => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Paladin,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::Dream | Self::Forcecage | Self::TruePolymorph => {
+                vec![SpellList::Bard, SpellList::Warlock, SpellList::Wizard]
+            }
+            Self::Earthquake | Self::FireStorm | Self::InsectPlague => {
+                vec![SpellList::Cleric, SpellList::Druid, SpellList::Sorcerer]
+            }
+            Self::EnhanceAbility => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Sorcerer,
+            ],
+            Self::Etherealness | Self::Tongues | Self::TrueSeeing => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::FaerieFire | Self::HeatMetal => vec![SpellList::Bard, SpellList::Druid],
+            Self::Feeblemind | Self::Foresight => vec![
+                SpellList::Bard,
+                SpellList::Druid,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::FindThePath
+            | Self::GreaterRestoration
+            | Self::HealingWord
+            | Self::MassCureWounds
+            | Self::Regenerate => vec![SpellList::Bard, SpellList::Cleric, SpellList::Druid],
+            Self::FreedomOfMovement => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Ranger,
+            ],
+            Self::Gate => vec![SpellList::Cleric, SpellList::Sorcerer, SpellList::Wizard],
+            Self::Glibness => vec![SpellList::Bard, SpellList::Warlock],
+            Self::Heroism => vec![SpellList::Bard, SpellList::Paladin],
+            Self::HoldPerson => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::IceStorm
+            | Self::ReverseGravity
+            | Self::SleetStorm
+            | Self::Sunbeam
+            | Self::Sunburst
+            | Self::WallOfFire
+            | Self::WallOfStone => vec![SpellList::Druid, SpellList::Sorcerer, SpellList::Wizard],
+            Self::Identify
+            | Self::MindBlank
+            | Self::OttosIrresistibleDance
+            | Self::TashasHideousLaughter => vec![SpellList::Bard, SpellList::Wizard],
+            Self::LocateCreature | Self::LocateObject => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Paladin,
+                SpellList::Ranger,
+                SpellList::Wizard,
+            ],
+            Self::Longstrider => vec![
+                SpellList::Bard,
+                SpellList::Druid,
+                SpellList::Ranger,
+                SpellList::Wizard,
+            ],
+            Self::MagicCircle | Self::ProtectionFromEvilAndGood | Self::RemoveCurse => vec![
+                SpellList::Cleric,
+                SpellList::Paladin,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::MagicWeapon => vec![SpellList::Paladin, SpellList::Wizard],
+            Self::Mending => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Sorcerer,
+                SpellList::Wizard,
+            ],
+            Self::MirageArcane => vec![SpellList::Bard, SpellList::Druid, SpellList::Wizard],
+            Self::Nondetection => vec![SpellList::Bard, SpellList::Ranger, SpellList::Wizard],
+            Self::PlaneShift => vec![
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Sorcerer,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::ProtectionFromEnergy => vec![
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Paladin,
+                SpellList::Ranger,
+                SpellList::Sorcerer,
+                SpellList::Wizard,
+            ],
+            Self::ProtectionFromPoison => vec![
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Paladin,
+                SpellList::Ranger,
+            ],
+            Self::RaiseDead | Self::ZoneOfTruth => {
+                vec![SpellList::Bard, SpellList::Cleric, SpellList::Paladin]
+            }
+            Self::Scrying => vec![
+                SpellList::Bard,
+                SpellList::Cleric,
+                SpellList::Druid,
+                SpellList::Warlock,
+                SpellList::Wizard,
+            ],
+            Self::Silence => vec![SpellList::Bard, SpellList::Cleric, SpellList::Ranger],
+            Self::VampiricTouch => vec![SpellList::Warlock, SpellList::Wizard],
+        }
+    }
+
+    /// Where the spell can be found
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub fn citation(self) -> Citation {
+        match self {
+            Self::AcidSplash | Self::Aid | Self::Alarm => Citation(Book::Phb, 211),
+            Self::AnimalFriendship | Self::AnimalShapes | Self::AnimateDead => {
+                Citation(Book::Phb, 212)
+            }
+            Self::AnimateObjects => Citation(Book::Phb, 213),
+            Self::AntipathySympathy | Self::ArcaneEye => Citation(Book::Phb, 214),
+            Self::ArmorOfAgathys | Self::AstralProjection => Citation(Book::Phb, 215),
+            Self::AuraOfLife | Self::AuraOfPurity | Self::AuraOfVitality | Self::Bane => {
+                Citation(Book::Phb, 216)
+            }
+            Self::BanishingSmite
+            | Self::Banishment
+            | Self::Barkskin
+            | Self::BeaconOfHope
+            | Self::BladeBarrier => Citation(Book::Phb, 217),
+            Self::BestowCurse | Self::BladeWard => Citation(Book::Phb, 218),
+            Self::Bless
+            | Self::Blight
+            | Self::BlindnessDeafness
+            | Self::Blink
+            | Self::Blur
+            | Self::BrandingSmite => Citation(Book::Phb, 219),
+            Self::BurningHands | Self::CallLightning => Citation(Book::Phb, 220),
+            Self::CalmEmotions
+            | Self::ChainLightning
+            | Self::CharmPerson
+            | Self::ChillTouch
+            | Self::ChromaticOrb
+            | Self::CircleOfDeath
+            | Self::CircleOfPower => Citation(Book::Phb, 221),
+            Self::Clairvoyance | Self::Cloudkill | Self::ColorSpray => Citation(Book::Phb, 222),
+            Self::Command | Self::Commune => Citation(Book::Phb, 223),
+            Self::CommuneWithNature
+            | Self::CompelledDuel
+            | Self::ComprehendLanguages
+            | Self::Compulsion
+            | Self::ConeOfCold
+            | Self::Confusion => Citation(Book::Phb, 224),
+            Self::ConjureAnimals | Self::ConjureElemental => Citation(Book::Phb, 225),
+            Self::ConjureBarrage | Self::ConjureFey | Self::ConjureWoodlandBeings => {
+                Citation(Book::Phb, 226)
+            }
+            Self::Counterspell => Citation(Book::Phb, 228),
+            Self::CreateOrDestroyWater => Citation(Book::Phb, 229),
+            Self::CrusadersMantle
+            | Self::CureWounds
+            | Self::DancingLights
+            | Self::Darkness
+            | Self::Darkvision
+            | Self::Daylight => Citation(Book::Phb, 230),
+            Self::DestructiveWave | Self::DetectMagic => Citation(Book::Phb, 231),
+            Self::DimensionDoor
+            | Self::DisguiseSelf
+            | Self::Disintegrate
+            | Self::DispelEvilAndGood => Citation(Book::Phb, 233),
+            Self::DispelMagic
+            | Self::DissonantWhispers
+            | Self::Divination
+            | Self::DivineFavor
+            | Self::DivineWord
+            | Self::Earthquake => Citation(Book::Phb, 234),
+            Self::DeathWard | Self::DominateMonster | Self::DominatePerson => {
+                Citation(Book::Phb, 235)
+            }
+            Self::Dream | Self::Druidcraft => Citation(Book::Phb, 236),
+            Self::EldritchBlast
+            | Self::ElementalWeapon
+            | Self::EnhanceAbility
+            | Self::EnsnaringStrike => Citation(Book::Phb, 237),
+            Self::Entangle
+            | Self::Etherealness
+            | Self::EvardsBlackTentacles
+            | Self::ExpeditiousRetreat
+            | Self::Eyebite => Citation(Book::Phb, 238),
+            Self::FaerieFire
+            | Self::FalseLife
+            | Self::Fear
+            | Self::FeatherFall
+            | Self::Feeblemind => Citation(Book::Phb, 239),
+            Self::FindFamiliar | Self::FindSteed | Self::FindThePath => Citation(Book::Phb, 240),
+            Self::FingerOfDeath | Self::Fireball => Citation(Book::Phb, 241),
+            Self::FireBolt
+            | Self::FireShield
+            | Self::FireStorm
+            | Self::FlameStrike
+            | Self::FlamingSphere => Citation(Book::Phb, 242),
+            Self::Fly | Self::FogCloud | Self::Forcecage => Citation(Book::Phb, 243),
+            Self::Foresight
+            | Self::FreedomOfMovement
+            | Self::Friends
+            | Self::GaseousForm
+            | Self::Gate => Citation(Book::Phb, 244),
+            Self::Glibness | Self::GlobeOfInvulnerability | Self::GlyphOfWarding => {
+                Citation(Book::Phb, 245)
+            }
+            Self::Goodberry
+            | Self::GraspingVine
+            | Self::GreaterInvisibility
+            | Self::GreaterRestoration
+            | Self::GuardianOfFaith => Citation(Book::Phb, 246),
+            Self::Guidance | Self::GuidingBolt => Citation(Book::Phb, 248),
+            Self::HailOfThorns | Self::Hallow | Self::Harm => Citation(Book::Phb, 249),
+            Self::Haste
+            | Self::Heal
+            | Self::HealingWord
+            | Self::HeatMetal
+            | Self::HellishRebuke
+            | Self::HeroesFeast
+            | Self::Heroism => Citation(Book::Phb, 250),
+            Self::Hex
+            | Self::HoldMonster
+            | Self::HoldPerson
+            | Self::HolyAura
+            | Self::HungerOfHadar
+            | Self::HuntersMark => Citation(Book::Phb, 251),
+            Self::HypnoticPattern | Self::IceStorm | Self::Identify => Citation(Book::Phb, 252),
+            Self::InflictWounds => Citation(Book::Phb, 253),
+            Self::InsectPlague
+            | Self::Invisibility
+            | Self::Jump
+            | Self::Knock
+            | Self::LegendLore => Citation(Book::Phb, 254),
+            Self::LesserRestoration
+            | Self::Levitate
+            | Self::Light
+            | Self::LightningArrow
+            | Self::LightningBolt => Citation(Book::Phb, 255),
+            Self::LocateCreature
+            | Self::LocateObject
+            | Self::Longstrider
+            | Self::MageArmor
+            | Self::MageHand
+            | Self::MagicCircle => Citation(Book::Phb, 256),
+            Self::MagicMissile | Self::MagicWeapon => Citation(Book::Phb, 257),
+            Self::MassCureWounds
+            | Self::MassHeal
+            | Self::MassHealingWord
+            | Self::MassSuggestion => Citation(Book::Phb, 258),
+            Self::Mending | Self::Message | Self::MeteorSwarm | Self::MindBlank => {
+                Citation(Book::Phb, 259)
+            }
+            Self::MinorIllusion | Self::MirageArcane | Self::MirrorImage | Self::MistyStep => {
+                Citation(Book::Phb, 260)
+            }
+            Self::Moonbeam => Citation(Book::Phb, 261),
+            Self::Nondetection => Citation(Book::Phb, 263),
+            Self::OttosIrresistibleDance | Self::PassWithoutTrace => Citation(Book::Phb, 264),
+            Self::PhantasmalKiller => Citation(Book::Phb, 265),
+            Self::PlaneShift
+            | Self::PlantGrowth
+            | Self::PoisonSpray
+            | Self::Polymorph
+            | Self::PowerWordKill => Citation(Book::Phb, 266),
+            Self::PowerWordStun
+            | Self::PrayerOfHealing
+            | Self::Prestidigitation
+            | Self::PrismaticSpray => Citation(Book::Phb, 267),
+            Self::ProduceFlame => Citation(Book::Phb, 269),
+            Self::ProtectionFromEnergy
+            | Self::ProtectionFromEvilAndGood
+            | Self::ProtectionFromPoison
+            | Self::RaiseDead => Citation(Book::Phb, 270),
+            Self::RayOfFrost | Self::Regenerate | Self::RemoveCurse => Citation(Book::Phb, 271),
+            Self::Resistance
+            | Self::Resurrection
+            | Self::ReverseGravity
+            | Self::Revivify
+            | Self::SacredFlame
+            | Self::Sanctuary => Citation(Book::Phb, 272),
+            Self::ScorchingRay | Self::Scrying => Citation(Book::Phb, 273),
+            Self::SearingSmite | Self::Seeming | Self::Sending | Self::Shapechange => {
+                Citation(Book::Phb, 274)
+            }
+            Self::Shatter
+            | Self::Shield
+            | Self::ShieldOfFaith
+            | Self::Shillelagh
+            | Self::ShockingGrasp
+            | Self::Silence => Citation(Book::Phb, 275),
+            Self::Sleep | Self::SleetStorm => Citation(Book::Phb, 276),
+            Self::Slow
+            | Self::SpareTheDying
+            | Self::SpeakWithAnimals
+            | Self::SpeakWithDead
+            | Self::SpikeGrowth => Citation(Book::Phb, 277),
+            Self::SpiritGuardians
+            | Self::SpiritualWeapon
+            | Self::StaggeringSmite
+            | Self::StinkingCloud
+            | Self::Stoneskin => Citation(Book::Phb, 278),
+            Self::StormOfVengeance
+            | Self::Suggestion
+            | Self::Sunbeam
+            | Self::Sunburst
+            | Self::SwiftQuiver => Citation(Book::Phb, 279),
+            Self::TashasHideousLaughter | Self::Telekinesis => Citation(Book::Phb, 280),
+            Self::Teleport => Citation(Book::Phb, 281),
+            Self::Thaumaturgy | Self::ThornWhip | Self::ThunderousSmite | Self::Thunderwave => {
+                Citation(Book::Phb, 282)
+            }
+            Self::TimeStop
+            | Self::Tongues
+            | Self::TransportViaPlants
+            | Self::TreeStride
+            | Self::TruePolymorph => Citation(Book::Phb, 283),
+            Self::TrueResurrection | Self::TrueSeeing | Self::TrueStrike => {
+                Citation(Book::Phb, 284)
+            }
+            Self::VampiricTouch | Self::ViciousMockery | Self::WallOfFire => {
+                Citation(Book::Phb, 285)
+            }
+            Self::WallOfStone | Self::WaterBreathing => Citation(Book::Phb, 287),
+            Self::WindWalk | Self::WindWall | Self::Wish => Citation(Book::Phb, 288),
+            Self::WitchBolt | Self::WrathfulSmite | Self::ZoneOfTruth => Citation(Book::Phb, 289),
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use std::{collections::BTreeMap, f64::consts::E, fmt};
 
 use dice_roller::{Die, RollCmd};
-use rand::Rng;
+use itertools::Itertools;
+use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, IntoEnumIterator};
 
-use super::proficiencies::Proficiency;
+use super::proficiencies::{Proficiency, ProficiencyLevel};
 
 /// Return modifier based on ability score.
 fn modifier(score: i16) -> i16 {
@@ -50,6 +51,46 @@ pub enum AbilityScoreType {
     Charisma,
 }
 
+/// Highest an ability score can be raised to, unless a racial or class feature raises its maximum
+pub const MAX_SCORE: i16 = 20;
+/// Scores available to assign with the standard array
+const STANDARD_ARRAY: [i16; 6] = [15, 14, 13, 12, 10, 8];
+/// Points available to spend with point buy
+const POINT_BUY_POINTS: i16 = 27;
+/// Times to reroll a full set of scores when trying to reach a minimum total
+const MINIMUM_TOTAL_ATTEMPTS: usize = 1000;
+/// Highest total a full set of scores rolled with 4d6 can have
+pub const MAX_ROLLED_TOTAL: i16 = 6 * 18;
+
+/// Point cost of an ability score with point buy, from 8 to 15
+fn point_buy_cost(score: i16) -> i16 {
+    match score {
+        ..=13 => score - 8,
+        14 => 7,
+        _ => 9,
+    }
+}
+
+/// Ways of generating a set of ability scores
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum AbilityScoreMethod {
+    /// Roll 4d6 and keep the highest 3, for each ability in order
+    #[default]
+    Rolled,
+    /// Roll 4d6 and keep the highest 3, rerolling any 1s, for each ability in order
+    RolledRerollOnes,
+    /// Roll 4d6 and keep the highest 3, rerolling the whole set until the total is at least this much.
+    /// This is best effort: if no set reaches the minimum within 1000 tries, the set with the highest
+    /// total is kept, so minimums close to `MAX_ROLLED_TOTAL` will usually fall short.
+    RolledMinimumTotal(i16),
+    /// Roll 3d6 for each ability in order
+    ThreeD6InOrder,
+    /// Assign 15, 14, 13, 12, 10, and 8
+    StandardArray,
+    /// Spend 27 points on scores between 8 and 15
+    PointBuy,
+}
+
 /// Value of a base ability score or increase
 #[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 pub struct AbilityScore(pub AbilityScoreType, pub i16);
@@ -65,6 +106,25 @@ impl AbilityScore {
         let score = rolls.drain(0..3).fold(0, |acc, d| acc + d.roll);
         score
     }
+
+    /// Generate an ability score by rolling 4d6, rerolling any 1s, and keeping the highest 3
+    fn gen_reroll_ones(rng: &mut impl Rng) -> u8 {
+        let mut rolls = (0..4)
+            .map(|_| loop {
+                let roll = RollCmd(1, Die::D6).roll(rng).0.remove(0).roll;
+                if roll > 1 {
+                    break roll;
+                }
+            })
+            .collect_vec();
+        rolls.sort_unstable_by(|a, b| b.cmp(a));
+        rolls.drain(0..3).sum()
+    }
+
+    /// Generate an ability score by rolling 3d6
+    fn gen_3d6(rng: &mut impl Rng) -> u8 {
+        u8::try_from(RollCmd(3, Die::D6).roll(rng).total()).unwrap()
+    }
 }
 
 /// Full set of ability scores a character could have
@@ -72,27 +132,127 @@ impl AbilityScore {
 pub struct AbilityScores(pub BTreeMap<AbilityScoreType, i16>);
 
 impl AbilityScores {
-    /// Generate a set of ability scores for a character
-    pub fn gen(rng: &mut impl Rng) -> Self {
-        let mut scores = BTreeMap::new();
-        for a in AbilityScoreType::iter() {
-            scores.insert(a, i16::from(AbilityScore::gen(rng)));
+    /// Generate a set of ability scores for a character with the given method.
+    ///
+    /// Rolled scores stay in the order they were rolled. Standard array and point buy scores are assigned
+    /// highest first following `priority`, and any abilities not in `priority` are assigned in a random order.
+    pub fn gen(
+        rng: &mut impl Rng,
+        method: AbilityScoreMethod,
+        priority: &[AbilityScoreType],
+    ) -> Self {
+        match method {
+            AbilityScoreMethod::Rolled => Self::gen_in_order(rng, AbilityScore::gen),
+            AbilityScoreMethod::RolledRerollOnes => {
+                Self::gen_in_order(rng, AbilityScore::gen_reroll_ones)
+            }
+            AbilityScoreMethod::RolledMinimumTotal(minimum) => {
+                let mut best = Self::gen_in_order(rng, AbilityScore::gen);
+                for _ in 1..MINIMUM_TOTAL_ATTEMPTS {
+                    if best.total() >= minimum {
+                        break;
+                    }
+                    let scores = Self::gen_in_order(rng, AbilityScore::gen);
+                    if scores.total() > best.total() {
+                        best = scores;
+                    }
+                }
+                best
+            }
+            AbilityScoreMethod::ThreeD6InOrder => Self::gen_in_order(rng, AbilityScore::gen_3d6),
+            AbilityScoreMethod::StandardArray => {
+                Self::assign(rng, STANDARD_ARRAY.to_vec(), priority)
+            }
+            AbilityScoreMethod::PointBuy => {
+                let scores = Self::gen_point_buy(rng);
+                Self::assign(rng, scores, priority)
+            }
         }
-        Self(scores)
     }
 
-    /// Add list of ability score increases to the totals
-    pub fn increase(&mut self, addl_scores: Vec<AbilityScore>) {
+    /// Generate each ability score in order with the given roll
+    fn gen_in_order<R: Rng>(rng: &mut R, roll: impl Fn(&mut R) -> u8) -> Self {
+        Self(
+            AbilityScoreType::iter()
+                .map(|a| (a, i16::from(roll(rng))))
+                .collect(),
+        )
+    }
+
+    /// Spend point buy points randomly, favoring raising the highest scores further: each score is
+    /// weighted by how far it already is above the minimum of 8. Returns the scores from highest
+    /// to lowest.
+    fn gen_point_buy(rng: &mut impl Rng) -> Vec<i16> {
+        let mut scores = vec![8; AbilityScoreType::iter().count()];
+        let mut points = POINT_BUY_POINTS;
+        loop {
+            let options = (0..scores.len())
+                .filter(|&i| {
+                    scores[i] < 15
+                        && point_buy_cost(scores[i] + 1) - point_buy_cost(scores[i]) <= points
+                })
+                .collect_vec();
+            let Ok(&i) = options.choose_weighted(rng, |&i| scores[i] - 7) else {
+                break;
+            };
+            points -= point_buy_cost(scores[i] + 1) - point_buy_cost(scores[i]);
+            scores[i] += 1;
+        }
+        scores.sort_unstable_by(|a, b| b.cmp(a));
+        scores
+    }
+
+    /// Assign scores to abilities, highest first, in priority order
+    fn assign(rng: &mut impl Rng, mut scores: Vec<i16>, priority: &[AbilityScoreType]) -> Self {
+        scores.sort_unstable_by(|a, b| b.cmp(a));
+        let mut order = priority.iter().copied().unique().collect_vec();
+        let mut rest = AbilityScoreType::iter()
+            .filter(|a| !order.contains(a))
+            .collect_vec();
+        rest.shuffle(rng);
+        order.extend(rest);
+        Self(order.into_iter().zip(scores).collect())
+    }
+
+    /// Total of all ability scores
+    fn total(&self) -> i16 {
+        self.0.values().sum()
+    }
+
+    /// Add list of ability score increases to the totals, never raising a score above the given
+    /// maximum (usually `MAX_SCORE`). Scores already above the maximum are left as they are.
+    pub fn increase(&mut self, addl_scores: Vec<AbilityScore>, max: i16) {
         for AbilityScore(score_type, val) in addl_scores {
-            *self.0.entry(score_type).or_insert(0) += val;
+            let score = self.0.entry(score_type).or_insert(0);
+            *score = (*score + val).min(max.max(*score));
         }
     }
 
+    /// Get the score for a given ability score type
+    pub fn score(&self, ability: AbilityScoreType) -> i16 {
+        *self.0.get(&ability).unwrap_or(&0)
+    }
+
     /// Get modifier for a given ability score type
     pub fn modifier(&self, ability: AbilityScoreType) -> i16 {
         modifier(*self.0.get(&ability).unwrap_or(&0))
     }
 
+    /// Get saving throw modifier for a given ability score type, adding proficiency bonus if applicable
+    pub fn saving_throw(
+        &self,
+        ability: AbilityScoreType,
+        proficiencies: &[Proficiency],
+        proficiency_bonus: i16,
+    ) -> i16 {
+        self.modifier(ability)
+            + if proficiencies.contains(&Proficiency::SavingThrow(ability)) {
+                proficiency_bonus
+            } else {
+                0
+            }
+    }
+
     /// Get the amount to shift modifiers by based on lowest modifier (used for shifting weights)
     pub fn shift_weight_by(&self) -> i16 {
         let min = modifier(*self.0.values().min().unwrap_or(&0));
@@ -173,12 +333,22 @@ impl Skill {
         proficiencies: &[Proficiency],
         proficiency_bonus: i16,
     ) -> i16 {
-        ability_scores.modifier(self.ability_score_type())
-            + if self.proficient(proficiencies) {
-                proficiency_bonus
-            } else {
-                0
-            }
+        let level = if self.proficient(proficiencies) {
+            ProficiencyLevel::Proficient
+        } else {
+            ProficiencyLevel::None
+        };
+        self.modifier_at(ability_scores, level, proficiency_bonus)
+    }
+
+    /// Return the modifier for a skill, adding the part of the proficiency bonus for a given level
+    pub fn modifier_at(
+        self,
+        ability_scores: &AbilityScores,
+        level: ProficiencyLevel,
+        proficiency_bonus: i16,
+    ) -> i16 {
+        ability_scores.modifier(self.ability_score_type()) + level.bonus(proficiency_bonus)
     }
 
     /// Check if the character is proficient in this skill
@@ -250,32 +420,167 @@ mod tests {
     #[test]
     fn test_ability_scores() {
         let mut rng = Pcg64::from_entropy();
-        let scores = AbilityScores::gen(&mut rng).0;
+        let scores = AbilityScores::gen(&mut rng, AbilityScoreMethod::default(), &[]).0;
         for score_type in AbilityScoreType::iter() {
             let score = *scores.get(&score_type).unwrap();
             assert!((3..=18).contains(&score));
         }
     }
 
+    #[test]
+    fn test_ability_scores_reroll_ones() {
+        let mut rng = Pcg64::from_entropy();
+        let scores = AbilityScores::gen(&mut rng, AbilityScoreMethod::RolledRerollOnes, &[]).0;
+        assert!(scores.values().all(|score| (6..=18).contains(score)));
+    }
+
+    #[test]
+    fn test_ability_scores_minimum_total() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let scores = AbilityScores::gen(&mut rng, AbilityScoreMethod::RolledMinimumTotal(80), &[]);
+        assert!(scores.total() >= 80);
+    }
+
+    #[test]
+    fn test_ability_scores_3d6() {
+        let mut rng = Pcg64::from_entropy();
+        let scores = AbilityScores::gen(&mut rng, AbilityScoreMethod::ThreeD6InOrder, &[]).0;
+        assert!(scores.values().all(|score| (3..=18).contains(score)));
+    }
+
+    #[test]
+    fn test_ability_scores_standard_array() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let scores = AbilityScores::gen(
+            &mut rng,
+            AbilityScoreMethod::StandardArray,
+            &[AbilityScoreType::Wisdom, AbilityScoreType::Constitution],
+        )
+        .0;
+        assert_eq!(scores[&AbilityScoreType::Wisdom], 15);
+        assert_eq!(scores[&AbilityScoreType::Constitution], 14);
+        assert_eq!(
+            scores.values().copied().sorted().rev().collect_vec(),
+            STANDARD_ARRAY
+        );
+    }
+
+    #[test]
+    fn test_ability_scores_point_buy() {
+        let mut rng = Pcg64::from_entropy();
+        let scores = AbilityScores::gen(
+            &mut rng,
+            AbilityScoreMethod::PointBuy,
+            &[AbilityScoreType::Intelligence],
+        )
+        .0;
+        assert!(scores.values().all(|score| (8..=15).contains(score)));
+        assert_eq!(
+            scores.values().max(),
+            scores.get(&AbilityScoreType::Intelligence)
+        );
+        // Leftover points can only happen if they can't raise any score
+        let spent: i16 = scores.values().map(|&s| point_buy_cost(s)).sum();
+        assert!(POINT_BUY_POINTS - spent <= 1);
+    }
+
+    #[test]
+    fn test_point_buy_favors_high_scores() {
+        // Raising scores evenly only reaches 15 about half the time
+        let maxed = (0..100)
+            .filter(|&seed| {
+                let mut rng = Pcg64::seed_from_u64(seed);
+                AbilityScores::gen_point_buy(&mut rng)[0] == 15
+            })
+            .count();
+        assert!(maxed >= 80);
+    }
+
     #[test]
     fn test_ability_scores_increase() {
         let mut rng = Pcg64::seed_from_u64(1);
-        let mut scores = AbilityScores::gen(&mut rng);
-        let more_scores = AbilityScores::gen(&mut rng);
+        let mut scores = AbilityScores::gen(&mut rng, AbilityScoreMethod::default(), &[]);
+        let more_scores = AbilityScores::gen(&mut rng, AbilityScoreMethod::default(), &[]);
         scores.increase(
             more_scores
                 .0
                 .into_iter()
                 .map(|(t, v)| AbilityScore(t, v))
                 .collect(),
+            i16::MAX,
         );
         insta::assert_yaml_snapshot!(scores);
     }
 
+    #[test]
+    fn test_ability_scores_increase_max() {
+        let mut scores = AbilityScores(
+            [
+                (AbilityScoreType::Strength, 19),
+                (AbilityScoreType::Dexterity, 22),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        scores.increase(
+            vec![
+                AbilityScore(AbilityScoreType::Strength, 2),
+                AbilityScore(AbilityScoreType::Dexterity, 1),
+                AbilityScore(AbilityScoreType::Wisdom, 2),
+            ],
+            MAX_SCORE,
+        );
+        assert_eq!(scores.score(AbilityScoreType::Strength), MAX_SCORE);
+        // Scores can already be over the maximum, but increases don't lower them
+        assert_eq!(scores.score(AbilityScoreType::Dexterity), 22);
+        assert_eq!(scores.score(AbilityScoreType::Wisdom), 2);
+    }
+
+    #[test]
+    fn test_ability_scores_increase_higher_max() {
+        let mut scores = AbilityScores([(AbilityScoreType::Strength, 20)].into_iter().collect());
+        scores.increase(vec![AbilityScore(AbilityScoreType::Strength, 4)], 24);
+        assert_eq!(scores.score(AbilityScoreType::Strength), 24);
+        scores.increase(vec![AbilityScore(AbilityScoreType::Strength, 1)], 24);
+        assert_eq!(scores.score(AbilityScoreType::Strength), 24);
+    }
+
+    #[test]
+    fn test_saving_throw() {
+        let scores = AbilityScores(
+            [
+                (AbilityScoreType::Strength, 16),
+                (AbilityScoreType::Wisdom, 8),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let proficiencies = [Proficiency::SavingThrow(AbilityScoreType::Wisdom)];
+        assert_eq!(
+            scores.saving_throw(AbilityScoreType::Strength, &proficiencies, 2),
+            3
+        );
+        assert_eq!(
+            scores.saving_throw(AbilityScoreType::Wisdom, &proficiencies, 2),
+            1
+        );
+    }
+
+    #[test]
+    fn test_skill_modifier_at() {
+        let scores = AbilityScores([(AbilityScoreType::Dexterity, 14)].into_iter().collect());
+        let modifier = |level| Skill::Stealth.modifier_at(&scores, level, 3);
+        assert_eq!(modifier(ProficiencyLevel::None), 2);
+        assert_eq!(modifier(ProficiencyLevel::Half), 3);
+        assert_eq!(modifier(ProficiencyLevel::HalfRoundedUp), 4);
+        assert_eq!(modifier(ProficiencyLevel::Proficient), 5);
+        assert_eq!(modifier(ProficiencyLevel::Expertise), 8);
+    }
+
     #[test]
     fn test_ability_scores_snapshot_display() {
         let mut rng = Pcg64::seed_from_u64(1);
-        let scores = AbilityScores::gen(&mut rng);
+        let scores = AbilityScores::gen(&mut rng, AbilityScoreMethod::default(), &[]);
         insta::assert_snapshot!(format!("{}", scores));
     }
 }
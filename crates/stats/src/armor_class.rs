@@ -0,0 +1,36 @@
+use crate::ability::{AbilityScoreType, AbilityScores};
+
+/// A way of calculating Armor Class without wearing armor, such as a Barbarian's Unarmored Defense.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnarmoredDefense {
+    /// Name of the feature providing this calculation
+    pub name: &'static str,
+    /// Armor Class before any ability modifiers are added
+    pub base: i16,
+    /// Ability modifiers added to the base
+    pub abilities: Vec<AbilityScoreType>,
+    /// Whether a shield's bonus still applies
+    pub shield: bool,
+}
+
+impl UnarmoredDefense {
+    /// Armor Class of anyone wearing no armor: 10 + Dexterity modifier
+    pub fn unarmored() -> Self {
+        Self {
+            name: "Unarmored",
+            base: 10,
+            abilities: vec![AbilityScoreType::Dexterity],
+            shield: true,
+        }
+    }
+
+    /// Armor Class using this calculation, not including a shield
+    pub fn armor_class(&self, ability_scores: &AbilityScores) -> i16 {
+        self.base
+            + self
+                .abilities
+                .iter()
+                .map(|&a| ability_scores.modifier(a))
+                .sum::<i16>()
+    }
+}
@@ -48,10 +48,42 @@ impl fmt::Display for Item {
     }
 }
 
+impl Item {
+    /// Price of the item, if it can be bought
+    #[must_use]
+    pub fn cost(&self) -> Option<(Coin, u32)> {
+        match self {
+            Self::Ammunition(i) => Some(i.cost()),
+            Self::Armor(i) => Some(i.cost()),
+            Self::Gear(i) => Some(i.cost()),
+            Self::Tool(i) => Some(i.cost()),
+            Self::Vehicle(i) => Some(i.cost()),
+            Self::Weapon(i) => Some(i.cost()),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Weight of the item in pounds, if it is carried
+    #[must_use]
+    pub fn weight(&self) -> Option<f32> {
+        match self {
+            Self::Ammunition(i) => Some(i.weight()),
+            Self::Armor(i) => Some(i.weight()),
+            Self::Gear(i) => Some(i.weight()),
+            Self::Tool(i) => Some(i.weight()),
+            Self::Weapon(i) => Some(i.weight()),
+            // Vehicles are ridden or pulled rather than carried
+            Self::Vehicle(_) | Self::Other(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Equipment {
-    item: Item,
-    amount: usize,
+    /// The item itself
+    pub item: Item,
+    /// How many of the item there are
+    pub amount: usize,
 }
 
 impl Equipment {
@@ -59,7 +91,17 @@ impl Equipment {
         Self { item, amount }
     }
 
-    fn proficient(&self, proficiencies: &[Proficiency]) -> bool {
+    /// Total weight of all of the items in pounds
+    #[must_use]
+    pub fn weight(&self) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let amount = self.amount as f32;
+        self.item.weight().unwrap_or_default() * amount
+    }
+
+    /// Whether the character is proficient with the item. Items that don't need proficiency always are.
+    #[must_use]
+    pub fn proficient(&self, proficiencies: &[Proficiency]) -> bool {
         match &self.item {
             Item::Ammunition(ammunition) => ammunition
                 .weapons()
@@ -389,6 +431,22 @@ pub enum Pack {
     Scholar,
 }
 
+impl Pack {
+    /// Price of the pack, which is less than buying its contents separately (PHB p151)
+    #[must_use]
+    pub fn cost(self) -> (Coin, u32) {
+        match self {
+            Pack::Burglar => (Coin::Gold, 16),
+            Pack::Diplomat => (Coin::Gold, 39),
+            Pack::Dungeoneer => (Coin::Gold, 12),
+            Pack::Entertainer | Pack::Scholar => (Coin::Gold, 40),
+            Pack::Explorer => (Coin::Gold, 10),
+            Pack::MonsterHunter => (Coin::Gold, 33),
+            Pack::Priest => (Coin::Gold, 19),
+        }
+    }
+}
+
 impl StartingEquipment for Pack {
     fn equipment(&self) -> Vec<Equipment> {
         match self {
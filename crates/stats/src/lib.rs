@@ -1,3 +1,4 @@
 pub mod ability;
+pub mod armor_class;
 pub mod equipment;
 pub mod proficiencies;
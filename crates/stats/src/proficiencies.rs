@@ -11,7 +11,7 @@ use rand::{
 use serde::{Deserialize, Serialize};
 use strum::{Display, IntoEnumIterator};
 
-use super::ability::{AbilityScoreType, AbilityScores, Skill};
+use super::ability::{exp_weight, AbilityScoreType, AbilityScores, Skill};
 
 /// Types of weapons a character is proficient in.
 #[derive(Clone, Debug, Deserialize, Display, Eq, Ord, PartialEq, PartialOrd, Serialize)]
@@ -214,7 +214,11 @@ impl Proficiency {
             Self::Armor(_) => {
                 ProficiencyOption::Armor.gen(rng, ability_scores, proficiencies, proficiency_bonus)
             }
-            Self::SavingThrow(_) => todo!(),
+            Self::SavingThrow(_) => ProficiencyOption::From(
+                AbilityScoreType::iter().map(Self::SavingThrow).collect(),
+                1,
+            )
+            .gen(rng, ability_scores, proficiencies, proficiency_bonus),
             Self::Skill(_) => ProficiencyOption::Skill(None, 1).gen(
                 rng,
                 ability_scores,
@@ -243,6 +247,106 @@ impl Proficiency {
     }
 }
 
+/// How much of the proficiency bonus is added to an ability check
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ProficiencyLevel {
+    /// No proficiency bonus
+    #[default]
+    None,
+    /// Half the proficiency bonus, rounded down
+    Half,
+    /// Half the proficiency bonus, rounded up
+    HalfRoundedUp,
+    /// The full proficiency bonus
+    Proficient,
+    /// Double the proficiency bonus
+    Expertise,
+}
+
+impl ProficiencyLevel {
+    /// Amount added to an ability check for a given proficiency bonus
+    #[must_use]
+    pub fn bonus(self, proficiency_bonus: i16) -> i16 {
+        match self {
+            Self::None => 0,
+            Self::Half => proficiency_bonus / 2,
+            Self::HalfRoundedUp => (proficiency_bonus + 1) / 2,
+            Self::Proficient => proficiency_bonus,
+            Self::Expertise => proficiency_bonus * 2,
+        }
+    }
+}
+
+/// Half of the proficiency bonus added to ability checks that don't already include it
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct HalfProficiency {
+    /// Abilities whose checks it applies to. Applies to every ability check if `None`.
+    pub abilities: Option<Vec<AbilityScoreType>>,
+    /// Whether half the bonus is rounded up rather than down
+    pub round_up: bool,
+}
+
+impl HalfProficiency {
+    /// Level of proficiency for a check with a given ability. Checks without a set ability, such as
+    /// tool checks, only benefit if it applies to every ability check.
+    #[must_use]
+    pub fn level(&self, ability: Option<AbilityScoreType>) -> ProficiencyLevel {
+        let applies = match (&self.abilities, ability) {
+            (None, _) => true,
+            (Some(abilities), Some(ability)) => abilities.contains(&ability),
+            (Some(_), None) => false,
+        };
+        match (applies, self.round_up) {
+            (false, _) => ProficiencyLevel::None,
+            (true, false) => ProficiencyLevel::Half,
+            (true, true) => ProficiencyLevel::HalfRoundedUp,
+        }
+    }
+}
+
+/// A way to encapsulate expertise that needs to be chosen for a character.
+/// Only proficiencies the character already has can be chosen.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ExpertiseOption {
+    /// Choose from a given list of proficiencies.
+    From(Vec<Proficiency>, usize),
+    /// Choose from any skills.
+    Skill(usize),
+}
+
+impl ExpertiseOption {
+    /// Randomly choose expertise from the character's proficiencies, avoiding any existing expertise.
+    /// Skills are weighted towards your highest modifiers.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the weighting logic is wrong
+    pub fn gen(
+        &self,
+        rng: &mut impl Rng,
+        ability_scores: &AbilityScores,
+        proficiencies: &[Proficiency],
+        expertise: &[Proficiency],
+        proficiency_bonus: i16,
+    ) -> Vec<Proficiency> {
+        let (options, amount) = match self {
+            Self::From(list, amount) => (list.clone(), *amount),
+            Self::Skill(amount) => (Skill::iter().map(Proficiency::Skill).collect(), *amount),
+        };
+        options
+            .into_iter()
+            .filter(|p| proficiencies.contains(p) && !expertise.contains(p))
+            .collect::<Vec<_>>()
+            .choose_multiple_weighted(rng, amount, |p| match p {
+                Proficiency::Skill(s) => s.weight(ability_scores, proficiencies, proficiency_bonus),
+                _ => exp_weight(proficiency_bonus, ability_scores.shift_weight_by()),
+            })
+            .unwrap()
+            .cloned()
+            .collect()
+    }
+}
+
 /// Trait to describe proficiencies given by an entity and any additional choices that can be made.
 pub trait Proficiencies {
     /// Proficiencies given by an entity/object
@@ -255,3 +359,68 @@ pub trait Proficiencies {
         vec![]
     }
 }
+
+/// Trait to describe how an entity changes how much of the proficiency bonus is added to ability checks
+pub trait ProficiencyLevels {
+    /// Expertise to choose, doubling the proficiency bonus, available at a given level
+    fn expertise(&self, _level: u8) -> Vec<ExpertiseOption> {
+        vec![]
+    }
+
+    /// Half proficiency added to checks without the proficiency bonus, available at a given level
+    fn half_proficiency(&self, _level: u8) -> Option<HalfProficiency> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gear::tools::Tool;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+
+    #[test]
+    fn test_half_proficiency_level() {
+        let jack_of_all_trades = HalfProficiency {
+            abilities: None,
+            round_up: false,
+        };
+        assert_eq!(
+            jack_of_all_trades.level(Some(AbilityScoreType::Wisdom)),
+            ProficiencyLevel::Half
+        );
+        assert_eq!(jack_of_all_trades.level(None), ProficiencyLevel::Half);
+        let remarkable_athlete = HalfProficiency {
+            abilities: Some(vec![AbilityScoreType::Strength]),
+            round_up: true,
+        };
+        assert_eq!(
+            remarkable_athlete.level(Some(AbilityScoreType::Strength)),
+            ProficiencyLevel::HalfRoundedUp
+        );
+        assert_eq!(
+            remarkable_athlete.level(Some(AbilityScoreType::Wisdom)),
+            ProficiencyLevel::None
+        );
+        assert_eq!(remarkable_athlete.level(None), ProficiencyLevel::None);
+    }
+
+    #[test]
+    fn test_expertise_only_from_proficiencies() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let proficiencies = vec![
+            Proficiency::Skill(Skill::Stealth),
+            Proficiency::Skill(Skill::Perception),
+            Proficiency::Tool(Tool::ThievesTools),
+        ];
+        let expertise = ExpertiseOption::Skill(2).gen(
+            &mut rng,
+            &AbilityScores::default(),
+            &proficiencies,
+            &[Proficiency::Skill(Skill::Stealth)],
+            2,
+        );
+        assert_eq!(expertise, vec![Proficiency::Skill(Skill::Perception)]);
+    }
+}
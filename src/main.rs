@@ -1,15 +1,254 @@
 #![deny(clippy::all)]
 #![warn(clippy::pedantic)]
 
-use std::env;
+use std::{env, fmt, str::FromStr};
 
-use character::Character;
-use rand::SeedableRng;
-use rand_pcg::Pcg64;
+use background::BackgroundType;
+use character::{Character, CharacterBuilder, EquipmentMethod, HitPointMethod, Section, Seed};
+use characteristics::Gender;
+use class::ClassType;
+use deities::Pantheon;
+use race::{RaceType, Subrace};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use tera::Tera;
+use tide::{
+    http::{headers::ACCEPT, mime},
+    utils::After,
+    Body, Request, Response, StatusCode,
+};
 use tide_compress::CompressMiddleware;
 use tide_tera::{context, TideTeraExt};
 
+/// Query parameters for generating a character. Anything left out is chosen randomly.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CharacterQuery {
+    background: Option<String>,
+    class: Option<String>,
+    deity: Option<String>,
+    equipment_method: Option<EquipmentMethod>,
+    gender: Option<String>,
+    hit_point_method: Option<HitPointMethod>,
+    level: Option<u8>,
+    /// Second class to multiclass into
+    multiclass: Option<String>,
+    /// Levels in the second class (1 if not given)
+    multiclass_level: Option<u8>,
+    pantheon: Option<String>,
+    race: Option<String>,
+    /// Sections to reroll after generating the character, separated by commas
+    reroll: Option<String>,
+    /// Seed from a previously generated character, to generate it again
+    seed: Option<String>,
+    subrace: Option<String>,
+    /// Explain the weighted choices made while generating the character
+    trace: bool,
+    variant_encumbrance: bool,
+}
+
+/// Parse a query parameter, responding with a Bad Request if it isn't a valid option
+fn parse<T: FromStr>(name: &str, value: &str) -> tide::Result<T> {
+    value
+        .parse()
+        .map_err(|_| bad_request(format!("Unknown {name}: {value}")))
+}
+
+fn bad_request(message: impl fmt::Display) -> tide::Error {
+    tide::Error::from_str(StatusCode::BadRequest, message.to_string())
+}
+
+impl CharacterQuery {
+    /// Pin all of the choices made in the query
+    fn builder(&self) -> tide::Result<CharacterBuilder> {
+        let mut builder = CharacterBuilder::new()
+            .equipment_method(self.equipment_method.unwrap_or_default())
+            .hit_point_method(self.hit_point_method.unwrap_or_default())
+            .trace(self.trace)
+            .variant_encumbrance(self.variant_encumbrance);
+        if let Some(background) = &self.background {
+            builder = builder.background(parse::<BackgroundType>("background", background)?);
+        }
+        if let Some(class) = &self.class {
+            builder = builder.class(parse::<ClassType>("class", class)?);
+        }
+        if let Some(deity) = &self.deity {
+            builder = builder.deity(deity);
+        }
+        if let Some(gender) = &self.gender {
+            builder = builder.gender(parse::<Gender>("gender", gender)?);
+        }
+        if let Some(level) = self.level {
+            builder = builder.level(level);
+        }
+        if let Some(multiclass) = &self.multiclass {
+            builder = builder.multiclass(
+                parse::<ClassType>("class", multiclass)?,
+                self.multiclass_level.unwrap_or(1),
+            );
+        }
+        if let Some(pantheon) = &self.pantheon {
+            builder = builder.pantheon(parse::<Pantheon>("pantheon", pantheon)?);
+        }
+        if let Some(race) = &self.race {
+            builder = builder.race(parse::<RaceType>("race", race)?);
+        }
+        if let Some(subrace) = &self.subrace {
+            builder = builder.subrace(parse::<Subrace>("subrace", subrace)?);
+        }
+        Ok(builder)
+    }
+
+    /// Sections to reroll, in order
+    fn rerolls(&self) -> tide::Result<Vec<Section>> {
+        self.reroll.as_deref().map_or(Ok(vec![]), |rerolls| {
+            rerolls
+                .split(',')
+                .map(|section| parse("section", section))
+                .collect()
+        })
+    }
+
+    /// Generate the requested character, from the given seed or a new random one, and then reroll any
+    /// sections. Each reroll has its own generator from the seed, so the same query always gives the same
+    /// character.
+    fn character<'a>(&self) -> tide::Result<(Seed, Character<'a>)> {
+        let seed = match &self.seed {
+            Some(seed) => seed.parse().map_err(bad_request)?,
+            None => Seed::gen(&mut rand::thread_rng()),
+        };
+        let mut character = self.builder()?.gen_seeded(seed).map_err(bad_request)?;
+        for (index, section) in self.rerolls()?.into_iter().enumerate() {
+            character.reroll(&mut seed.reroll_rng(index), section);
+        }
+        Ok((seed, character))
+    }
+}
+
+/// Link to reroll a section of the character on the sheet
+#[derive(Serialize)]
+struct RerollLink {
+    href: String,
+    section: String,
+}
+
+/// Links to reroll each section of a character. The character's seed, the other query parameters and any
+/// previous rerolls are kept, so everything else stays the same.
+fn reroll_links(req: &Request<Tera>, seed: Seed) -> Vec<RerollLink> {
+    let query = req
+        .url()
+        .query_pairs()
+        .filter(|(key, _)| key != "seed" && key != "reroll")
+        .collect::<Vec<_>>();
+    let rerolls = req
+        .url()
+        .query_pairs()
+        .find_map(|(key, value)| (key == "reroll").then(|| value.into_owned()));
+    Section::iter()
+        .map(|section| {
+            let mut url = req.url().clone();
+            url.query_pairs_mut()
+                .clear()
+                .extend_pairs(&query)
+                .append_pair("seed", &seed.to_string())
+                .append_pair(
+                    "reroll",
+                    &match &rerolls {
+                        Some(rerolls) => format!("{rerolls},{section}"),
+                        None => section.to_string(),
+                    },
+                );
+            RerollLink {
+                href: format!("{}?{}", url.path(), url.query().unwrap_or_default()),
+                section: section.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Render the character sheet page, with links to reroll each section and any trace of how it was generated
+fn render_sheet(req: &Request<Tera>, seed: Seed, character: &Character) -> tide::Result {
+    req.state().render_response(
+        "index.html",
+        &context! {
+            "character" => format!("{character}"),
+            "rerolls" => reroll_links(req, seed),
+            "trace" => character.trace().map(ToString::to_string).unwrap_or_default()
+        },
+    )
+}
+
+/// Formats a generated character can be returned in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Format {
+    Html,
+    Json,
+    Text,
+}
+
+impl Format {
+    /// Choose a format from an Accept header, trying media types in order of quality.
+    /// JSON is used if there is no header, or if any media type will do.
+    fn negotiate(accept: Option<&str>) -> Option<Self> {
+        let Some(accept) = accept else {
+            return Some(Self::Json);
+        };
+        let mut proposals = accept
+            .split(',')
+            .filter_map(|part| {
+                let mut params = part.split(';').map(str::trim);
+                let media_type = params.next()?.to_ascii_lowercase();
+                let quality = params
+                    .find_map(|p| p.strip_prefix("q="))
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some((media_type, quality))
+            })
+            .collect::<Vec<_>>();
+        // Stable, so equal qualities keep the client's order
+        proposals.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        proposals
+            .into_iter()
+            .filter(|(_, quality)| *quality > 0.0)
+            .find_map(|(media_type, _)| match media_type.as_str() {
+                "application/json" | "application/*" | "*/*" | "*" => Some(Self::Json),
+                "text/html" => Some(Self::Html),
+                "text/plain" | "text/*" => Some(Self::Text),
+                _ => None,
+            })
+    }
+}
+
+/// Generate a character and respond with it as JSON, plain text or an HTML page, depending on the
+/// Accept header. JSON is returned if no Accept header is given.
+async fn api_character(req: Request<Tera>) -> tide::Result {
+    let (seed, character) = req.query::<CharacterQuery>()?.character()?;
+    let accept = req.header(ACCEPT).map(|values| {
+        values
+            .iter()
+            .map(tide::http::headers::HeaderValue::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+    match Format::negotiate(accept.as_deref()) {
+        Some(Format::Html) => render_sheet(&req, seed, &character),
+        Some(Format::Json) => Ok(Response::builder(StatusCode::Ok)
+            .body(Body::from_json(&character)?)
+            .build()),
+        Some(Format::Text) => Ok(Response::builder(StatusCode::Ok)
+            .body(match character.trace() {
+                Some(trace) => format!("{character}\n\nGENERATION TRACE:\n{trace}"),
+                None => format!("{character}"),
+            })
+            .content_type(mime::PLAIN)
+            .build()),
+        None => Err(tide::Error::from_str(
+            StatusCode::NotAcceptable,
+            "Characters are available as application/json, text/plain or text/html",
+        )),
+    }
+}
+
 #[async_std::main]
 async fn main() -> tide::Result<()> {
     tide::log::start();
@@ -27,14 +266,76 @@ async fn main() -> tide::Result<()> {
 
     let mut app = tide::with_state(Tera::new("templates/**/*")?);
     app.with(CompressMiddleware::new());
-    app.at("/").get(|req: tide::Request<Tera>| async move {
-        let mut rng = Pcg64::from_entropy();
-        let tera = req.state();
-        tera.render_response(
-            "index.html",
-            &context! { "character" => format!("{}", Character::gen(&mut rng)) },
-        )
+    app.with(After(|mut res: Response| async move {
+        // Let clients know what was wrong with their request
+        if res.status().is_client_error() {
+            if let Some(message) = res.error().map(ToString::to_string) {
+                res.set_body(message);
+            }
+        }
+        Ok(res)
+    }));
+    app.at("/").get(|req: Request<Tera>| async move {
+        let (seed, character) = req.query::<CharacterQuery>()?.character()?;
+        render_sheet(&req, seed, &character)
     });
+    app.at("/api/character").get(api_character);
     app.listen(format!("0.0.0.0:{}", port)).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(Format::negotiate(None), Some(Format::Json));
+        assert_eq!(
+            Format::negotiate(Some("text/html;q=0.9, application/json;q=0.5")),
+            Some(Format::Html)
+        );
+        assert_eq!(Format::negotiate(Some("*/*")), Some(Format::Json));
+        assert_eq!(Format::negotiate(Some("text/*")), Some(Format::Text));
+        assert_eq!(Format::negotiate(Some("text/plain;q=0")), None);
+        assert_eq!(Format::negotiate(Some("text/plain;q=0, image/png")), None);
+    }
+
+    #[test]
+    fn test_negotiate_ties_keep_order() {
+        assert_eq!(
+            Format::negotiate(Some("text/plain, application/json")),
+            Some(Format::Text)
+        );
+        assert_eq!(
+            Format::negotiate(Some("image/png, TEXT/HTML;q=0.5, application/json;q=0.5")),
+            Some(Format::Html)
+        );
+    }
+
+    #[test]
+    fn test_bad_query() {
+        let query = CharacterQuery {
+            class: Some("wizzard".to_string()),
+            ..CharacterQuery::default()
+        };
+        let error = query.builder().err().unwrap();
+        assert_eq!(error.status(), StatusCode::BadRequest);
+        assert_eq!(error.to_string(), "Unknown class: wizzard");
+
+        let query = CharacterQuery {
+            race: Some("ent".to_string()),
+            ..CharacterQuery::default()
+        };
+        let error = query.builder().err().unwrap();
+        assert_eq!(error.status(), StatusCode::BadRequest);
+        assert_eq!(error.to_string(), "Unknown race: ent");
+
+        let query = CharacterQuery {
+            race: Some("dwarf".to_string()),
+            subrace: Some("hill".to_string()),
+            ..CharacterQuery::default()
+        };
+        assert!(query.builder().is_ok());
+    }
+}